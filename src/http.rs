@@ -4,7 +4,6 @@
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::HashMap;
-use std::slice;
 
 /// HTTP method
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,11 +54,13 @@ impl HttpResponse {
 }
 
 // Import the host function
+#[cfg(not(test))]
 extern "C" {
     fn http_request(req_ptr: i32, req_len: i32) -> u64;
 }
 
 /// Execute an HTTP request via host function
+#[cfg(not(test))]
 pub fn execute(request: HttpRequest) -> HttpResponse {
     let req_bytes = serde_json::to_vec(&request).expect("Failed to serialize request");
     let req_ptr = req_bytes.as_ptr() as i32;
@@ -82,7 +83,7 @@ pub fn execute(request: HttpRequest) -> HttpResponse {
     }
 
     // Read response from memory
-    let res_slice = unsafe { slice::from_raw_parts(res_ptr as *const u8, res_len as usize) };
+    let res_slice = unsafe { std::slice::from_raw_parts(res_ptr as *const u8, res_len as usize) };
 
     serde_json::from_slice(res_slice).unwrap_or_else(|e| HttpResponse {
         status: 0,
@@ -184,3 +185,56 @@ impl HttpClient {
         })
     }
 }
+
+/// Execute an HTTP request against the test transport
+#[cfg(test)]
+pub fn execute(request: HttpRequest) -> HttpResponse {
+    mock::reply(request)
+}
+
+/// Per-thread canned responses standing in for the host function in unit tests
+#[cfg(test)]
+pub mod mock {
+    use super::{HttpRequest, HttpResponse};
+    use std::cell::RefCell;
+    use std::collections::{HashMap, VecDeque};
+
+    thread_local! {
+        static RESPONSES: RefCell<VecDeque<HttpResponse>> = const { RefCell::new(VecDeque::new()) };
+        static REQUESTS: RefCell<Vec<HttpRequest>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Queue a 200 response
+    pub fn respond(body: &str) {
+        respond_with(200, HashMap::new(), body);
+    }
+
+    /// Queue a response with status and headers
+    pub fn respond_with(status: u16, headers: HashMap<String, String>, body: &str) {
+        RESPONSES.with(|r| {
+            r.borrow_mut().push_back(HttpResponse {
+                status,
+                headers,
+                body: body.to_string(),
+                error: None,
+            })
+        });
+    }
+
+    /// Requests sent so far on this thread
+    pub fn requests() -> Vec<HttpRequest> {
+        REQUESTS.with(|r| r.borrow().clone())
+    }
+
+    pub(super) fn reply(request: HttpRequest) -> HttpResponse {
+        REQUESTS.with(|r| r.borrow_mut().push(request));
+        RESPONSES
+            .with(|r| r.borrow_mut().pop_front())
+            .unwrap_or_else(|| HttpResponse {
+                status: 0,
+                headers: HashMap::new(),
+                body: String::new(),
+                error: Some("No mock response queued".to_string()),
+            })
+    }
+}
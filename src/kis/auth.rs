@@ -159,12 +159,17 @@ pub fn get_websocket_key(http: &HttpClient, config: &KisConfig) -> KisResult<Str
 
 /// Get current Unix timestamp in seconds
 fn current_timestamp() -> u64 {
+    now_millis() / 1000
+}
+
+/// Current Unix time in milliseconds (token expiry and the response cache share this clock)
+pub fn now_millis() -> u64 {
     // In WASM environment, we don't have access to system time easily
     // This is a simplified version - in production, you might want to
     // get time from the host or use a monotonic counter
     use std::time::{SystemTime, UNIX_EPOCH};
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_secs())
+        .map(|d| d.as_millis() as u64)
         .unwrap_or(0)
 }
@@ -7,8 +7,10 @@
 //! - 호가 조회
 //! - 현재가 조회
 
-use super::types::{KisApiResponse, KisError, KisResult};
+use super::field::{impl_field_report, KisField};
+use super::types::{Amount, KisApiResponse, KisError, KisResult, Price, Quantity, Rate};
 use super::KisClient;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 // ============================================================================
@@ -95,6 +97,41 @@ pub struct BondOrderResponse {
     pub ord_tmd: Option<String>,
 }
 
+/// 채권 정정취소가능주문 항목
+#[derive(Debug, Clone, Deserialize)]
+pub struct BondOpenOrder {
+    /// 주문채번지점번호 (한국거래소전송주문조직번호)
+    #[serde(rename = "ORD_GNO_BRNO")]
+    pub ord_gno_brno: Option<String>,
+    /// 주문번호
+    #[serde(rename = "ODNO")]
+    pub odno: Option<String>,
+    /// 상품번호
+    #[serde(rename = "PDNO")]
+    pub pdno: Option<String>,
+    /// 상품명
+    #[serde(rename = "PRDT_NAME")]
+    pub prdt_name: Option<String>,
+    /// 주문수량
+    #[serde(rename = "ORD_QTY", default)]
+    pub ord_qty: KisField<Quantity>,
+    /// 총체결수량
+    #[serde(rename = "TOT_CCLD_QTY", default)]
+    pub tot_ccld_qty: KisField<Quantity>,
+}
+
+impl_field_report!(BondOpenOrder {
+    ord_qty,
+    tot_ccld_qty,
+});
+
+impl BondOpenOrder {
+    /// 미체결 잔량
+    pub fn remaining_quantity(&self) -> Quantity {
+        self.ord_qty.or_zero() - self.tot_ccld_qty.or_zero()
+    }
+}
+
 /// 채권 잔고 조회 응답
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BondBalance {
@@ -105,31 +142,42 @@ pub struct BondBalance {
     #[serde(rename = "PRDT_NAME")]
     pub prdt_name: Option<String>,
     /// 보유수량
-    #[serde(rename = "HLDG_QTY")]
-    pub hldg_qty: Option<String>,
+    #[serde(rename = "HLDG_QTY", default)]
+    pub hldg_qty: KisField<Quantity>,
     /// 매입평균가액
-    #[serde(rename = "PCHS_AVG_PRIC")]
-    pub pchs_avg_pric: Option<String>,
+    #[serde(rename = "PCHS_AVG_PRIC", default)]
+    pub pchs_avg_pric: KisField<Price>,
     /// 매입금액
-    #[serde(rename = "PCHS_AMT")]
-    pub pchs_amt: Option<String>,
+    #[serde(rename = "PCHS_AMT", default)]
+    pub pchs_amt: KisField<Amount>,
     /// 현재가
-    #[serde(rename = "PRPR")]
-    pub prpr: Option<String>,
+    #[serde(rename = "PRPR", default)]
+    pub prpr: KisField<Price>,
     /// 평가금액
-    #[serde(rename = "EVLU_AMT")]
-    pub evlu_amt: Option<String>,
+    #[serde(rename = "EVLU_AMT", default)]
+    pub evlu_amt: KisField<Amount>,
     /// 평가손익금액
-    #[serde(rename = "EVLU_PFLS_AMT")]
-    pub evlu_pfls_amt: Option<String>,
+    #[serde(rename = "EVLU_PFLS_AMT", default)]
+    pub evlu_pfls_amt: KisField<Amount>,
     /// 평가손익율
-    #[serde(rename = "EVLU_PFLS_RT")]
-    pub evlu_pfls_rt: Option<String>,
+    #[serde(rename = "EVLU_PFLS_RT", default)]
+    pub evlu_pfls_rt: KisField<Rate>,
     /// 만기일자
-    #[serde(rename = "EXPR_DT")]
-    pub expr_dt: Option<String>,
+    #[serde(rename = "EXPR_DT", default)]
+    pub expr_dt: KisField<NaiveDate>,
 }
 
+impl_field_report!(BondBalance {
+    hldg_qty,
+    pchs_avg_pric,
+    pchs_amt,
+    prpr,
+    evlu_amt,
+    evlu_pfls_amt,
+    evlu_pfls_rt,
+    expr_dt,
+});
+
 /// 채권 호가 조회 응답
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BondQuote {
@@ -445,6 +493,37 @@ impl KisClient {
             .ok_or_else(|| KisError::Parse("No output in response".to_string()))
     }
 
+    /// 장내채권 정정취소가능주문 조회 (실전 전용)
+    ///
+    /// # Returns
+    /// * `KisResult<Vec<BondOpenOrder>>` - 미체결 잔량이 있는 주문 목록
+    pub fn bond_open_orders(&self) -> KisResult<Vec<BondOpenOrder>> {
+        if self.config.is_paper {
+            return Err(KisError::Validation(
+                "Bond open order inquiry is not supported in paper trading".to_string(),
+            ));
+        }
+
+        let query = format!(
+            "CANO={}&ACNT_PRDT_CD={}&ORD_DT=&ODNO=&CTX_AREA_FK200=&CTX_AREA_NK200=",
+            self.cano(),
+            self.acnt_prdt_cd()
+        );
+
+        let orders = self.get_list(
+            "/uapi/domestic-bond/v1/trading/inquire-psbl-rvsecncl",
+            "CTSC8035R",
+            &query,
+            "response",
+            |r: KisApiResponse<Vec<BondOpenOrder>>| r.output,
+        )?;
+
+        Ok(orders
+            .into_iter()
+            .filter(|order| order.remaining_quantity() > Quantity::ZERO)
+            .collect())
+    }
+
     /// 장내채권 잔고 조회
     ///
     /// # Returns
@@ -0,0 +1,280 @@
+//! TTL cache for KIS GET responses
+//!
+//! 잔고/시세/기준정보 조회 응답을 카테고리별 TTL로 캐시합니다. 호스트는 호출마다
+//! [`Freshness`] 힌트로 허용 가능한 데이터 나이를 지정할 수 있고, 주문 등 POST 요청을
+//! 보내면 계좌 카테고리는 자동으로 무효화됩니다.
+
+use crate::http::HttpResponse;
+use std::collections::HashMap;
+
+/// 캐시 최대 항목 수 (초과 시 만료 항목부터 정리)
+pub const MAX_CACHE_ENTRIES: usize = 512;
+
+/// Cached data category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheCategory {
+    /// 잔고, 주문가능금액, 체결/예약 내역
+    Account,
+    /// 현재가, 호가
+    Quote,
+    /// 휴장일, 종목정보 등 기준정보
+    Reference,
+}
+
+impl CacheCategory {
+    /// Classify a GET path (`None` = not cacheable)
+    pub fn classify(path: &str) -> Option<CacheCategory> {
+        let path = path.split('?').next().unwrap_or(path);
+        let endpoint = path.rsplit('/').next().unwrap_or(path);
+
+        if matches!(
+            endpoint,
+            "chk-holiday" | "search-info" | "search-stock-info"
+        ) {
+            Some(CacheCategory::Reference)
+        } else if path.contains("/quotations/") {
+            Some(CacheCategory::Quote)
+        } else if path.contains("/trading/inquire-") || path.contains("/trading/order-resv-") {
+            Some(CacheCategory::Account)
+        } else {
+            None
+        }
+    }
+
+    /// Default time-to-live in milliseconds
+    pub fn default_ttl_ms(&self) -> u64 {
+        match self {
+            CacheCategory::Account => 3_000,
+            CacheCategory::Quote => 1_000,
+            CacheCategory::Reference => 6 * 60 * 60 * 1_000,
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<CacheCategory> {
+        match code.to_ascii_lowercase().as_str() {
+            "account" => Some(CacheCategory::Account),
+            "quote" => Some(CacheCategory::Quote),
+            "reference" => Some(CacheCategory::Reference),
+            _ => None,
+        }
+    }
+}
+
+/// Per-call freshness hint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Freshness {
+    /// 카테고리 TTL 이내의 캐시 사용
+    #[default]
+    Default,
+    /// 지정한 나이(ms) 이내의 캐시만 사용 (TTL보다 길게는 허용하지 않음)
+    MaxAge(u64),
+    /// 캐시를 무시하고 새로 조회 (결과는 캐시에 저장)
+    Refresh,
+}
+
+impl Freshness {
+    /// Build from host hint fields (`refresh` takes precedence over `max_age_ms`)
+    pub fn from_hint(max_age_ms: Option<u64>, refresh: bool) -> Freshness {
+        match (refresh, max_age_ms) {
+            (true, _) | (false, Some(0)) => Freshness::Refresh,
+            (false, Some(ms)) => Freshness::MaxAge(ms),
+            (false, None) => Freshness::Default,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    category: CacheCategory,
+    stored_at_ms: u64,
+    response: HttpResponse,
+}
+
+/// TTL cache keyed by TR ID + URL
+#[derive(Debug, Default)]
+pub struct ResponseCache {
+    entries: HashMap<String, CacheEntry>,
+    ttl_overrides: HashMap<CacheCategory, u64>,
+}
+
+impl ResponseCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cache key for a request
+    pub fn key(tr_id: &str, url: &str) -> String {
+        format!("{}|{}", tr_id, url)
+    }
+
+    /// Effective TTL for a category
+    pub fn ttl_ms(&self, category: CacheCategory) -> u64 {
+        self.ttl_overrides
+            .get(&category)
+            .copied()
+            .unwrap_or_else(|| category.default_ttl_ms())
+    }
+
+    /// Override the TTL for a category (0 disables caching)
+    pub fn set_ttl_ms(&mut self, category: CacheCategory, ttl_ms: u64) {
+        self.ttl_overrides.insert(category, ttl_ms);
+    }
+
+    /// Cached response that satisfies the freshness hint
+    pub fn lookup(&self, key: &str, freshness: Freshness, now_ms: u64) -> Option<HttpResponse> {
+        let entry = self.entries.get(key)?;
+        let max_age = match freshness {
+            Freshness::Refresh => return None,
+            Freshness::Default => self.ttl_ms(entry.category),
+            Freshness::MaxAge(ms) => ms.min(self.ttl_ms(entry.category)),
+        };
+        let age = now_ms.saturating_sub(entry.stored_at_ms);
+        (age < max_age).then(|| entry.response.clone())
+    }
+
+    pub fn store(
+        &mut self,
+        key: String,
+        category: CacheCategory,
+        response: HttpResponse,
+        now_ms: u64,
+    ) {
+        if self.ttl_ms(category) == 0 {
+            return;
+        }
+        if self.entries.len() >= MAX_CACHE_ENTRIES && !self.entries.contains_key(&key) {
+            self.prune(now_ms);
+            if self.entries.len() >= MAX_CACHE_ENTRIES {
+                self.entries.clear();
+            }
+        }
+        self.entries.insert(
+            key,
+            CacheEntry {
+                category,
+                stored_at_ms: now_ms,
+                response,
+            },
+        );
+    }
+
+    /// Drop expired entries
+    pub fn prune(&mut self, now_ms: u64) {
+        let ttl: HashMap<CacheCategory, u64> = [
+            CacheCategory::Account,
+            CacheCategory::Quote,
+            CacheCategory::Reference,
+        ]
+        .into_iter()
+        .map(|c| (c, self.ttl_ms(c)))
+        .collect();
+        self.entries
+            .retain(|_, e| now_ms.saturating_sub(e.stored_at_ms) < ttl[&e.category]);
+    }
+
+    /// Drop all entries of a category
+    pub fn invalidate(&mut self, category: CacheCategory) {
+        self.entries.retain(|_, e| e.category != category);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Whether a successful HTTP response is also a KIS success (`rt_cd` "0" or absent)
+pub fn is_cacheable(response: &HttpResponse) -> bool {
+    match serde_json::from_str::<serde_json::Value>(&response.body) {
+        Ok(body) => body
+            .get("rt_cd")
+            .and_then(|v| v.as_str())
+            .is_none_or(|rt_cd| rt_cd == "0"),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(body: &str) -> HttpResponse {
+        HttpResponse {
+            status: 200,
+            headers: HashMap::new(),
+            body: body.to_string(),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_cache_category_classify() {
+        assert_eq!(
+            CacheCategory::classify("/uapi/domestic-stock/v1/trading/inquire-balance?CANO=1"),
+            Some(CacheCategory::Account)
+        );
+        assert_eq!(
+            CacheCategory::classify("/uapi/overseas-stock/v1/quotations/price"),
+            Some(CacheCategory::Quote)
+        );
+        assert_eq!(
+            CacheCategory::classify("/uapi/domestic-stock/v1/quotations/chk-holiday"),
+            Some(CacheCategory::Reference)
+        );
+        assert_eq!(
+            CacheCategory::classify("/uapi/domestic-stock/v1/trading/order-cash"),
+            None
+        );
+        assert_eq!(Freshness::from_hint(Some(0), false), Freshness::Refresh);
+        assert_eq!(Freshness::from_hint(Some(500), true), Freshness::Refresh);
+    }
+
+    #[test]
+    fn test_response_cache_ttl_and_invalidation() {
+        let mut cache = ResponseCache::new();
+        let balance = ResponseCache::key("TTTC8434R", "/trading/inquire-balance");
+        let quote = ResponseCache::key("FHKST01010100", "/quotations/inquire-price");
+        let body = r#"{"rt_cd":"0"}"#;
+
+        cache.store(
+            balance.clone(),
+            CacheCategory::Account,
+            response(body),
+            1_000,
+        );
+        cache.store(quote.clone(), CacheCategory::Quote, response(body), 1_000);
+
+        assert!(cache.lookup(&balance, Freshness::Default, 3_999).is_some());
+        assert!(cache.lookup(&balance, Freshness::Default, 4_000).is_none());
+        assert!(cache
+            .lookup(&balance, Freshness::MaxAge(500), 1_600)
+            .is_none());
+        assert!(cache
+            .lookup(&balance, Freshness::MaxAge(60_000), 3_500)
+            .is_some());
+        assert!(cache
+            .lookup(&balance, Freshness::MaxAge(60_000), 4_500)
+            .is_none());
+        assert!(cache.lookup(&balance, Freshness::Refresh, 1_000).is_none());
+
+        cache.invalidate(CacheCategory::Account);
+        assert!(cache.lookup(&balance, Freshness::Default, 1_000).is_none());
+        assert!(cache.lookup(&quote, Freshness::Default, 1_500).is_some());
+
+        cache.set_ttl_ms(CacheCategory::Quote, 0);
+        cache.clear();
+        cache.store(quote.clone(), CacheCategory::Quote, response(body), 1_000);
+        assert!(cache.is_empty());
+
+        assert!(is_cacheable(&response(body)));
+        assert!(is_cacheable(&response(r#"{"output":[]}"#)));
+        assert!(!is_cacheable(&response(r#"{"rt_cd":"1","msg1":"err"}"#)));
+    }
+}
@@ -0,0 +1,442 @@
+//! KRX trading calendar (국내 휴장일 조회)
+//!
+//! 국내휴장일조회(`chk-holiday`, 실전 전용)로 받은 영업일/개장일 정보를 로컬 달력에 보관하고,
+//! KST 시각별 KRX/NXT 세션을 판별합니다. KIS 권고에 따라 조회는 성공 기준 하루 1회로 제한하고
+//! 실패하면 일정 간격을 두고 다시 시도하며, 달력에 없는 날짜(모의투자 포함)는 주말과 양력 고정
+//! 공휴일만 반영한 대체 규칙으로 판단합니다.
+
+use super::field::{impl_field_report, KisField};
+use super::types::{KisApiResponse, KisError, KisResult, KrxSession, NxtSession};
+use super::KisClient;
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// 다음 개장일 탐색 한도 (일)
+const MAX_LOOKAHEAD_DAYS: i64 = 31;
+
+/// 조회 실패 후 재시도 간격 (분)
+const RETRY_INTERVAL_MINUTES: i64 = 10;
+
+/// 양력 고정 휴장일 (월, 일): 신정, 삼일절, 근로자의날, 어린이날, 현충일, 광복절, 개천절, 한글날,
+/// 성탄절, 연말휴장일. 설/추석 연휴와 대체공휴일은 KIS 달력으로만 반영됩니다.
+const FIXED_HOLIDAYS: [(u32, u32); 10] = [
+    (1, 1),
+    (3, 1),
+    (5, 1),
+    (5, 5),
+    (6, 6),
+    (8, 15),
+    (10, 3),
+    (10, 9),
+    (12, 25),
+    (12, 31),
+];
+
+/// 국내휴장일조회 응답 항목
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct HolidayItem {
+    /// 기준일자
+    #[serde(rename = "bass_dt", default)]
+    pub bass_dt: KisField<NaiveDate>,
+    /// 요일구분코드 (01 일요일 ~ 07 토요일)
+    #[serde(rename = "wday_dvsn_cd")]
+    pub wday_dvsn_cd: Option<String>,
+    /// 영업일여부 (금융기관 업무일)
+    #[serde(rename = "bzdy_yn")]
+    pub bzdy_yn: Option<String>,
+    /// 거래일여부 (증권 업무 가능일)
+    #[serde(rename = "tr_day_yn")]
+    pub tr_day_yn: Option<String>,
+    /// 개장일여부 (주문 가능일)
+    #[serde(rename = "opnd_yn")]
+    pub opnd_yn: Option<String>,
+    /// 결제일여부
+    #[serde(rename = "sttl_day_yn")]
+    pub sttl_day_yn: Option<String>,
+}
+
+impl_field_report!(HolidayItem { bass_dt });
+
+/// One calendar day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TradingDay {
+    pub business_day: bool,
+    pub trading_day: bool,
+    /// 개장일 (주문 가능)
+    pub open: bool,
+    pub settlement_day: bool,
+}
+
+impl TradingDay {
+    fn from_item(item: &HolidayItem) -> Self {
+        let yes = |flag: &Option<String>| flag.as_deref() == Some("Y");
+        TradingDay {
+            business_day: yes(&item.bzdy_yn),
+            trading_day: yes(&item.tr_day_yn),
+            open: yes(&item.opnd_yn),
+            settlement_day: yes(&item.sttl_day_yn),
+        }
+    }
+}
+
+/// Where a calendar answer came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarSource {
+    /// KIS 국내휴장일조회
+    Kis,
+    /// 주말 + 양력 고정 휴장일 규칙
+    Fallback,
+}
+
+impl CalendarSource {
+    pub fn code(&self) -> &'static str {
+        match self {
+            CalendarSource::Kis => "kis",
+            CalendarSource::Fallback => "fallback",
+        }
+    }
+}
+
+/// Whether the fallback rule considers the date a market day
+pub fn fallback_is_open(date: NaiveDate) -> bool {
+    date.weekday().number_from_monday() <= 5
+        && !FIXED_HOLIDAYS.contains(&(date.month(), date.day()))
+}
+
+/// Domestic market phase at a point in time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarketPhase {
+    /// 휴장일
+    Holiday,
+    /// 개장 전
+    PreOpen,
+    /// NXT 프리마켓 (KRX 장 시작 전)
+    NxtPreMarket,
+    /// KRX 세션
+    Krx(KrxSession),
+    /// NXT 애프터마켓 (KRX 시간외 종료 후 포함)
+    NxtAfterMarket,
+    /// 장 종료
+    Closed,
+}
+
+impl MarketPhase {
+    pub fn code(&self) -> &'static str {
+        match self {
+            MarketPhase::Holiday => "holiday",
+            MarketPhase::PreOpen => "pre_open",
+            MarketPhase::NxtPreMarket => "nxt_pre_market",
+            MarketPhase::Krx(session) => session.code(),
+            MarketPhase::NxtAfterMarket => "nxt_after_market",
+            MarketPhase::Closed => "closed",
+        }
+    }
+}
+
+/// Domestic market status at a KST time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarketStatus {
+    pub kst: NaiveDateTime,
+    /// 오늘이 개장일인지
+    pub is_open_day: bool,
+    pub source: CalendarSource,
+    pub krx_session: Option<KrxSession>,
+    pub nxt_session: Option<NxtSession>,
+    pub phase: MarketPhase,
+    /// 오늘 이후(오늘 제외) 첫 개장일
+    pub next_open_day: Option<NaiveDate>,
+}
+
+impl MarketStatus {
+    /// Whether KRX accepts orders now (동시호가, 시간외 포함)
+    pub fn is_krx_open(&self) -> bool {
+        self.krx_session.is_some()
+    }
+
+    pub fn is_nxt_open(&self) -> bool {
+        self.nxt_session.is_some()
+    }
+}
+
+/// Local KRX calendar merged from chk-holiday responses
+#[derive(Debug, Clone, Default)]
+pub struct TradingCalendar {
+    days: BTreeMap<NaiveDate, TradingDay>,
+    /// 마지막 조회 성공일 (KST)
+    fetched_on: Option<NaiveDate>,
+    /// 마지막 조회 시도 시각 (KST)
+    attempted_at: Option<NaiveDateTime>,
+}
+
+impl TradingCalendar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merge chk-holiday rows; returns the number of days stored
+    pub fn merge(&mut self, items: &[HolidayItem]) -> usize {
+        let mut merged = 0;
+        for item in items {
+            if let Some(date) = item.bass_dt.get() {
+                self.days.insert(date, TradingDay::from_item(item));
+                merged += 1;
+            }
+        }
+        merged
+    }
+
+    /// Whether a refresh should be attempted now
+    ///
+    /// 오늘 이미 성공했으면 다시 조회하지 않고, 실패한 뒤에는 재시도 간격이 지나야 조회합니다.
+    pub fn needs_refresh(&self, now: NaiveDateTime) -> bool {
+        self.fetched_on != Some(now.date())
+            && self
+                .attempted_at
+                .is_none_or(|at| now - at >= Duration::minutes(RETRY_INTERVAL_MINUTES))
+    }
+
+    pub fn mark_attempted(&mut self, now: NaiveDateTime) {
+        self.attempted_at = Some(now);
+    }
+
+    pub fn mark_fetched(&mut self, today: NaiveDate) {
+        self.fetched_on = Some(today);
+    }
+
+    pub fn day(&self, date: NaiveDate) -> Option<TradingDay> {
+        self.days.get(&date).copied()
+    }
+
+    pub fn source(&self, date: NaiveDate) -> CalendarSource {
+        if self.days.contains_key(&date) {
+            CalendarSource::Kis
+        } else {
+            CalendarSource::Fallback
+        }
+    }
+
+    /// Whether the market is open on the date (KIS 달력 우선, 없으면 대체 규칙)
+    pub fn is_open_day(&self, date: NaiveDate) -> bool {
+        self.day(date)
+            .map_or_else(|| fallback_is_open(date), |day| day.open)
+    }
+
+    /// First open day strictly after `date`
+    pub fn next_open_day(&self, date: NaiveDate) -> Option<NaiveDate> {
+        (1..=MAX_LOOKAHEAD_DAYS)
+            .map(|offset| date + Duration::days(offset))
+            .find(|&d| self.is_open_day(d))
+    }
+
+    /// Market status at a KST time
+    pub fn status_at(&self, kst: NaiveDateTime) -> MarketStatus {
+        let date = kst.date();
+        let is_open_day = self.is_open_day(date);
+        let (krx_session, nxt_session) = if is_open_day {
+            (KrxSession::at(kst), NxtSession::at(kst))
+        } else {
+            (None, None)
+        };
+
+        let phase = match (is_open_day, krx_session, nxt_session) {
+            (false, _, _) => MarketPhase::Holiday,
+            (true, Some(session), _) => MarketPhase::Krx(session),
+            (true, None, Some(NxtSession::PreMarket)) => MarketPhase::NxtPreMarket,
+            (true, None, Some(_)) => MarketPhase::NxtAfterMarket,
+            (true, None, None) if kst.hour() < 9 => MarketPhase::PreOpen,
+            (true, None, None) => MarketPhase::Closed,
+        };
+
+        MarketStatus {
+            kst,
+            is_open_day,
+            source: self.source(date),
+            krx_session,
+            nxt_session,
+            phase,
+            next_open_day: self.next_open_day(date),
+        }
+    }
+
+    /// Reject orders on a known holiday
+    pub fn check_open_day(&self, date: NaiveDate) -> KisResult<()> {
+        if self.is_open_day(date) {
+            return Ok(());
+        }
+        let next = self
+            .next_open_day(date)
+            .map(|d| format!(", next open day {}", d.format("%Y-%m-%d")))
+            .unwrap_or_default();
+        Err(KisError::Validation(format!(
+            "KRX is closed on {} ({} calendar){}",
+            date.format("%Y-%m-%d"),
+            self.source(date).code(),
+            next
+        )))
+    }
+}
+
+impl KisClient {
+    /// 국내휴장일조회 (실전투자 전용)
+    ///
+    /// 기준일자부터 이후 영업일 정보를 반환합니다. KIS 권고에 따라 하루 1회만 호출하세요.
+    pub fn domestic_holidays(&mut self, base_date: NaiveDate) -> KisResult<Vec<HolidayItem>> {
+        if self.config.is_paper {
+            return Err(KisError::Validation(
+                "Holiday inquiry is not supported in paper trading".to_string(),
+            ));
+        }
+        self.ensure_auth()?;
+
+        let query = format!(
+            "BASS_DT={}&CTX_AREA_NK=&CTX_AREA_FK=",
+            base_date.format("%Y%m%d")
+        );
+        let response = self.get(
+            "/uapi/domestic-stock/v1/quotations/chk-holiday",
+            "CTCA0903R",
+            Some(&query),
+        )?;
+
+        let api_response: KisApiResponse<Vec<HolidayItem>> = response
+            .json()
+            .map_err(|e| KisError::Parse(format!("Failed to parse holiday response: {}", e)))?;
+
+        if !api_response.is_success() {
+            return Err(KisError::Api {
+                code: api_response.msg_cd,
+                message: api_response.msg1,
+            });
+        }
+
+        Ok(api_response.output.unwrap_or_default())
+    }
+
+    /// Trading calendar, refreshed from chk-holiday once per KST day
+    ///
+    /// 조회에 실패하면 기존 달력과 대체 규칙으로 계속 동작하고, 재시도 간격 후 다시 조회합니다.
+    ///
+    /// # Arguments
+    /// * `now` - 현재 KST 시각
+    pub fn trading_calendar(&mut self, now: NaiveDateTime) -> &TradingCalendar {
+        if !self.config.is_paper && self.calendar.needs_refresh(now) {
+            self.calendar.mark_attempted(now);
+            match self.domestic_holidays(now.date()) {
+                Ok(items) => {
+                    self.calendar.merge(&items);
+                    self.calendar.mark_fetched(now.date());
+                }
+                Err(e) => eprintln!("[broker-korea] Holiday calendar refresh failed: {}", e),
+            }
+        }
+        &self.calendar
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(date: &str, open: bool) -> HolidayItem {
+        let flag = if open { "Y" } else { "N" };
+        HolidayItem {
+            bass_dt: KisField::parse(date),
+            opnd_yn: Some(flag.to_string()),
+            tr_day_yn: Some(flag.to_string()),
+            bzdy_yn: Some(flag.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn kst(date: &str, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::parse_from_str(date, "%Y%m%d")
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_calendar_fallback_and_merge() {
+        let mut calendar = TradingCalendar::new();
+        let chuseok = NaiveDate::from_ymd_opt(2025, 10, 6).unwrap();
+
+        // Fallback: weekends and fixed-date holidays only
+        assert!(calendar.is_open_day(chuseok));
+        assert!(!calendar.is_open_day(NaiveDate::from_ymd_opt(2025, 10, 3).unwrap()));
+        assert!(!calendar.is_open_day(NaiveDate::from_ymd_opt(2025, 10, 4).unwrap()));
+        assert_eq!(calendar.source(chuseok), CalendarSource::Fallback);
+
+        let merged = calendar.merge(&[
+            item("20251006", false),
+            item("20251007", false),
+            item("20251008", false),
+            item("20251010", true),
+            HolidayItem::default(),
+        ]);
+        assert_eq!(merged, 4);
+        assert!(!calendar.is_open_day(chuseok));
+        assert_eq!(calendar.source(chuseok), CalendarSource::Kis);
+        assert_eq!(
+            calendar.next_open_day(chuseok),
+            NaiveDate::from_ymd_opt(2025, 10, 10)
+        );
+        assert!(matches!(
+            calendar.check_open_day(chuseok),
+            Err(KisError::Validation(msg)) if msg.contains("2025-10-10")
+        ));
+    }
+
+    #[test]
+    fn test_refresh_marks_fetched_only_on_success() {
+        use crate::http::mock;
+
+        let mut client = crate::kis::tests::test_client(false);
+        let now = kst("20251001", 8, 0);
+
+        // 실패하면 재시도 간격 전에는 다시 조회하지 않음
+        client.trading_calendar(now);
+        client.trading_calendar(now + Duration::minutes(5));
+        assert_eq!(mock::requests().len(), 1);
+
+        // 간격이 지나면 재시도, 성공하면 그날은 더 조회하지 않음
+        mock::respond(
+            r#"{"rt_cd":"0","msg_cd":"","msg1":"","output":[{"bass_dt":"20251006","opnd_yn":"N"}]}"#,
+        );
+        let later = now + Duration::minutes(RETRY_INTERVAL_MINUTES);
+        assert!(!client
+            .trading_calendar(later)
+            .is_open_day(NaiveDate::from_ymd_opt(2025, 10, 6).unwrap()));
+        client.trading_calendar(later + Duration::hours(3));
+        assert_eq!(mock::requests().len(), 2);
+
+        // 다음 날 다시 조회
+        client.trading_calendar(kst("20251002", 8, 0));
+        assert_eq!(mock::requests().len(), 3);
+    }
+
+    #[test]
+    fn test_market_status_phases() {
+        let mut calendar = TradingCalendar::new();
+        calendar.merge(&[item("20251006", false)]);
+
+        let status = calendar.status_at(kst("20251006", 10, 0));
+        assert_eq!(status.phase, MarketPhase::Holiday);
+        assert!(!status.is_krx_open() && !status.is_nxt_open());
+
+        let phase = |h, m| calendar.status_at(kst("20251002", h, m)).phase.code();
+        assert_eq!(phase(7, 30), "pre_open");
+        assert_eq!(phase(8, 10), "nxt_pre_market");
+        assert_eq!(phase(8, 45), "opening_auction");
+        assert_eq!(phase(10, 0), "continuous");
+        assert_eq!(phase(15, 25), "closing_auction");
+        assert_eq!(phase(15, 35), "nxt_after_market");
+        assert_eq!(phase(16, 30), "after_hours_single_price");
+        assert_eq!(phase(19, 0), "nxt_after_market");
+        assert_eq!(phase(21, 0), "closed");
+
+        let status = calendar.status_at(kst("20251002", 16, 30));
+        assert!(status.is_krx_open() && status.is_nxt_open());
+        assert_eq!(status.source, CalendarSource::Fallback);
+    }
+}
@@ -3,9 +3,11 @@
 //! This module provides APIs for domestic futures and options trading through KIS.
 
 use super::{
-    types::{KisApiResponse, KisError, KisResult},
+    field::{impl_field_report, KisField},
+    types::{kst_now, Amount, KisApiResponse, KisError, KisResult, Price, Quantity, Rate},
     KisClient,
 };
+use chrono::Duration;
 use serde::{Deserialize, Serialize};
 
 /// Futures/Options order action type
@@ -143,22 +145,30 @@ pub struct FutureBalance {
     #[serde(rename = "SLL_BUY_DVSN_CD")]
     pub sll_buy_dvsn_cd: Option<String>,
     /// 잔고수량 (Balance quantity)
-    #[serde(rename = "CBLC_QTY")]
-    pub cblc_qty: Option<String>,
+    #[serde(rename = "CBLC_QTY", default)]
+    pub cblc_qty: KisField<Quantity>,
     /// 평균단가 (Average price)
-    #[serde(rename = "AVG_UNPR")]
-    pub avg_unpr: Option<String>,
+    #[serde(rename = "AVG_UNPR", default)]
+    pub avg_unpr: KisField<Price>,
     /// 현재가 (Current price)
-    #[serde(rename = "PRPR")]
-    pub prpr: Option<String>,
+    #[serde(rename = "PRPR", default)]
+    pub prpr: KisField<Price>,
     /// 평가손익 (Profit/Loss)
-    #[serde(rename = "EVLU_PFLS_AMT")]
-    pub evlu_pfls_amt: Option<String>,
+    #[serde(rename = "EVLU_PFLS_AMT", default)]
+    pub evlu_pfls_amt: KisField<Amount>,
     /// 수익률 (Profit/Loss rate)
-    #[serde(rename = "PFLS_RT")]
-    pub pfls_rt: Option<String>,
+    #[serde(rename = "PFLS_RT", default)]
+    pub pfls_rt: KisField<Rate>,
 }
 
+impl_field_report!(FutureBalance {
+    cblc_qty,
+    avg_unpr,
+    prpr,
+    evlu_pfls_amt,
+    pfls_rt,
+});
+
 /// Futures/Options deposit information
 #[derive(Debug, Clone, Deserialize)]
 pub struct FutureDeposit {
@@ -208,6 +218,41 @@ pub struct FutureExecution {
     pub ccld_amt: Option<String>,
 }
 
+/// Night-session (야간) futures/options order item
+#[derive(Debug, Clone, Deserialize)]
+pub struct FutureNightOrder {
+    /// 주문번호
+    #[serde(rename = "odno")]
+    pub odno: Option<String>,
+    /// 종목코드
+    #[serde(rename = "pdno")]
+    pub pdno: Option<String>,
+    /// 종목명
+    #[serde(rename = "prdt_name")]
+    pub prdt_name: Option<String>,
+    /// 매도매수구분코드 (01:매도, 02:매수)
+    #[serde(rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: Option<String>,
+    /// 주문수량
+    #[serde(rename = "ord_qty", default)]
+    pub ord_qty: KisField<Quantity>,
+    /// 총체결수량
+    #[serde(rename = "tot_ccld_qty", default)]
+    pub tot_ccld_qty: KisField<Quantity>,
+}
+
+impl_field_report!(FutureNightOrder {
+    ord_qty,
+    tot_ccld_qty,
+});
+
+impl FutureNightOrder {
+    /// 미체결 잔량
+    pub fn remaining_quantity(&self) -> Quantity {
+        self.ord_qty.or_zero() - self.tot_ccld_qty.or_zero()
+    }
+}
+
 impl KisClient {
     /// Get TR_ID based on environment (production or paper trading)
     fn get_future_tr_id<'a>(&self, prod_id: &'a str, paper_id: &'a str) -> &'a str {
@@ -251,26 +296,11 @@ impl KisClient {
             nmpr_type_cd: price_type.code().to_string(),
         };
 
-        // Generate hashkey for order request
-        let hashkey = self.get_hashkey(&request)?;
-
-        // Build headers with hashkey
-        let mut headers = self.build_headers(tr_id)?;
-        headers.insert("hashkey".to_string(), hashkey);
-
-        // POST request
-        let response = self.http.post_json(
+        let response = self.post_order(
             "/uapi/domestic-futureoption/v1/trading/order",
+            tr_id,
             &request,
-            Some(headers),
-        );
-
-        if !response.is_success() {
-            return Err(KisError::Api {
-                code: response.status.to_string(),
-                message: response.error.unwrap_or_else(|| response.body.clone()),
-            });
-        }
+        )?;
 
         let api_response: KisApiResponse<FutureOrderResponse> = response
             .json()
@@ -316,26 +346,11 @@ impl KisClient {
             unit_price: Some(price.to_string()),
         };
 
-        // Generate hashkey for modify request
-        let hashkey = self.get_hashkey(&request)?;
-
-        // Build headers with hashkey
-        let mut headers = self.build_headers(tr_id)?;
-        headers.insert("hashkey".to_string(), hashkey);
-
-        // POST request
-        let response = self.http.post_json(
+        let response = self.post_order(
             "/uapi/domestic-futureoption/v1/trading/order-rvsecncl",
+            tr_id,
             &request,
-            Some(headers),
-        );
-
-        if !response.is_success() {
-            return Err(KisError::Api {
-                code: response.status.to_string(),
-                message: response.error.unwrap_or_else(|| response.body.clone()),
-            });
-        }
+        )?;
 
         let api_response: KisApiResponse<serde_json::Value> = response
             .json()
@@ -365,26 +380,11 @@ impl KisClient {
             unit_price: None,
         };
 
-        // Generate hashkey for cancel request
-        let hashkey = self.get_hashkey(&request)?;
-
-        // Build headers with hashkey
-        let mut headers = self.build_headers(tr_id)?;
-        headers.insert("hashkey".to_string(), hashkey);
-
-        // POST request
-        let response = self.http.post_json(
+        let response = self.post_order(
             "/uapi/domestic-futureoption/v1/trading/order-rvsecncl",
+            tr_id,
             &request,
-            Some(headers),
-        );
-
-        if !response.is_success() {
-            return Err(KisError::Api {
-                code: response.status.to_string(),
-                message: response.error.unwrap_or_else(|| response.body.clone()),
-            });
-        }
+        )?;
 
         let api_response: KisApiResponse<serde_json::Value> = response
             .json()
@@ -506,6 +506,98 @@ impl KisClient {
         Ok(api_response.output1.unwrap_or_default())
     }
 
+    /// Get today's unfilled futures/options orders
+    ///
+    /// # Returns
+    /// Orders with a remaining (미체결) quantity
+    pub fn future_open_orders(&mut self) -> KisResult<Vec<FutureExecution>> {
+        self.ensure_auth()?;
+
+        let tr_id = self.get_future_tr_id("TTTO5107R", "VTTO5107R");
+
+        let today = kst_now().format("%Y%m%d").to_string();
+        let query = format!(
+            "CANO={}&ACNT_PRDT_CD={}&INQR_STRT_DT={}&INQR_END_DT={}&SLL_BUY_DVSN_CD=00&INQR_DVSN=00&PDNO=&CCLD_DVSN=02&ORD_GNO_BRNO=&ODNO=&INQR_DVSN_3=00&INQR_DVSN_1=&CTX_AREA_FK100=&CTX_AREA_NK100=",
+            self.cano(),
+            self.acnt_prdt_cd(),
+            today,
+            today
+        );
+
+        self.get_list(
+            "/uapi/domestic-futureoption/v1/trading/inquire-ccnl",
+            tr_id,
+            &query,
+            "open orders",
+            |r: KisApiResponse<FutureExecution>| r.output1,
+        )
+    }
+
+    /// Get unfilled night-session (야간) futures/options orders (실전 전용)
+    ///
+    /// 야간 세션(18:00~익일 05:00)은 자정을 넘기므로 전일과 당일 주문을 함께 조회합니다.
+    pub fn future_night_open_orders(&mut self) -> KisResult<Vec<FutureNightOrder>> {
+        if self.config.is_paper {
+            return Err(KisError::Validation(
+                "Night session futures/options are not supported in paper trading".to_string(),
+            ));
+        }
+        self.ensure_auth()?;
+
+        let today = kst_now().date();
+        let query = format!(
+            "CANO={}&ACNT_PRDT_CD={}&STRT_ORD_DT={}&END_ORD_DT={}&SLL_BUY_DVSN_CD=00&CCLD_NCCS_DVSN=02&SORT_SQN=DS&STRT_ODNO=&PDNO=&MKET_ID_CD=&FUOP_DVSN_CD=&SCRN_DVSN=02&CTX_AREA_FK200=&CTX_AREA_NK200=",
+            self.cano(),
+            self.acnt_prdt_cd(),
+            (today - Duration::days(1)).format("%Y%m%d"),
+            today.format("%Y%m%d")
+        );
+
+        let orders = self.get_list(
+            "/uapi/domestic-futureoption/v1/trading/inquire-ngt-ccnl",
+            "STTN5201R",
+            &query,
+            "night open orders",
+            |r: KisApiResponse<FutureNightOrder>| r.output1,
+        )?;
+
+        Ok(orders
+            .into_iter()
+            .filter(|order| order.remaining_quantity() > Quantity::ZERO)
+            .collect())
+    }
+
+    /// Cancel a night-session (야간) futures/options order (실전 전용)
+    pub fn future_night_cancel_order(&mut self, order_no: &str) -> KisResult<bool> {
+        if self.config.is_paper {
+            return Err(KisError::Validation(
+                "Night session futures/options are not supported in paper trading".to_string(),
+            ));
+        }
+        self.ensure_auth()?;
+
+        let request = FutureModifyCancelRequest {
+            cano: self.cano().to_string(),
+            acnt_prdt_cd: self.acnt_prdt_cd().to_string(),
+            orgn_ord_no: order_no.to_string(),
+            rvse_cncl_dvsn_cd: "02".to_string(), // 취소
+            ord_qty: None,
+            unit_price: None,
+        };
+
+        let response = self.post_order(
+            "/uapi/domestic-futureoption/v1/trading/order-rvsecncl",
+            "STTN1103U",
+            &request,
+        )?;
+
+        let api_response: KisApiResponse<serde_json::Value> = response
+            .json()
+            .map_err(|e| KisError::Parse(format!("Failed to parse cancel response: {}", e)))?;
+
+        Ok(api_response.is_success())
+    }
+
     /// Place a new buy order for futures/options
     pub fn future_new_buy(
         &mut self,
@@ -2,14 +2,26 @@
 //!
 //! Provides functions for:
 //! - Cash buy/sell orders (현금 매수/매도)
+//! - Credit buy/repayment orders (신용 매수/상환)
 //! - Order modification/cancellation (정정/취소)
+//! - Reservation orders (주식예약주문)
 //! - Balance inquiry (잔고조회)
+//! - Daily orders and executions (주식일별주문체결조회)
+//! - Buying power / sellable quantity (매수가능조회, 매도가능수량조회)
+//! - Tick size / price limit checks (호가단위, 상하한가)
 //! - Current price inquiry (현재가 조회)
+//! - Multi-symbol price inquiry (관심종목 복수시세)
+//! - Product/stock info lookup (상품기본조회, 주식기본조회)
 
 use super::{
-    types::{KisApiResponse, KisError, KisResult, OrderType},
+    field::{impl_field_report, KisField},
+    types::{
+        kst_now, Amount, DomesticExchange, KisApiResponse, KisError, KisResult, KrxMarket,
+        OrderSide, OrderType, Price, Quantity, Rate,
+    },
     KisClient,
 };
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 /// 현금 매수/매도 주문 요청
@@ -33,6 +45,9 @@ pub struct CashOrderRequest {
     /// 주문단가 (시장가일 경우 0)
     #[serde(rename = "ORD_UNPR")]
     pub ord_unpr: String,
+    /// 거래소ID구분코드 (KRX, NXT, SOR)
+    #[serde(rename = "EXCG_ID_DVSN_CD")]
+    pub excg_id_dvsn_cd: String,
 }
 
 /// 현금 매수/매도 주문 응답
@@ -46,6 +61,101 @@ pub struct CashOrderResponse {
     pub ord_tmd: Option<String>,
 }
 
+/// 신용거래구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreditType {
+    /// 21: 자기융자신규 (매수)
+    OwnLoanNew,
+    /// 23: 유통융자신규 (매수)
+    MarginLoanNew,
+    /// 25: 자기융자상환 (매도)
+    OwnLoanRepay,
+    /// 27: 유통융자상환 (매도)
+    MarginLoanRepay,
+}
+
+impl CreditType {
+    /// CRDT_TYPE 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            CreditType::OwnLoanNew => "21",
+            CreditType::MarginLoanNew => "23",
+            CreditType::OwnLoanRepay => "25",
+            CreditType::MarginLoanRepay => "27",
+        }
+    }
+
+    /// Parse from CRDT_TYPE code
+    pub fn from_code(code: &str) -> Option<CreditType> {
+        match code {
+            "21" => Some(CreditType::OwnLoanNew),
+            "23" => Some(CreditType::MarginLoanNew),
+            "25" => Some(CreditType::OwnLoanRepay),
+            "27" => Some(CreditType::MarginLoanRepay),
+            _ => None,
+        }
+    }
+
+    /// 신규는 매수, 상환은 매도
+    pub fn side(&self) -> OrderSide {
+        match self {
+            CreditType::OwnLoanNew | CreditType::MarginLoanNew => OrderSide::Buy,
+            CreditType::OwnLoanRepay | CreditType::MarginLoanRepay => OrderSide::Sell,
+        }
+    }
+
+    /// 상환 주문 여부 (대출일자 필요)
+    pub fn is_repayment(&self) -> bool {
+        self.side() == OrderSide::Sell
+    }
+
+    /// Default credit type for a side (자기융자)
+    pub fn default_for(side: OrderSide) -> CreditType {
+        match side {
+            OrderSide::Buy => CreditType::OwnLoanNew,
+            OrderSide::Sell => CreditType::OwnLoanRepay,
+        }
+    }
+}
+
+/// 신용 매수/매도 주문 요청
+#[derive(Debug, Clone, Serialize)]
+pub struct CreditOrderRequest {
+    /// 계좌번호 앞 8자리
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌번호 뒤 2자리
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 종목코드 (예: 005930)
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 매도유형 (매수: 공백, 매도: 01 일반매도)
+    #[serde(rename = "SLL_TYPE")]
+    pub sll_type: String,
+    /// 신용유형 (21:자기융자신규, 23:유통융자신규, 25:자기융자상환, 27:유통융자상환)
+    #[serde(rename = "CRDT_TYPE")]
+    pub crdt_type: String,
+    /// 대출일자 (신규: 주문일자, 상환: 상환할 대출일자, YYYYMMDD)
+    #[serde(rename = "LOAN_DT")]
+    pub loan_dt: String,
+    /// 주문구분
+    #[serde(rename = "ORD_DVSN")]
+    pub ord_dvsn: String,
+    /// 주문수량
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 주문단가 (시장가일 경우 0)
+    #[serde(rename = "ORD_UNPR")]
+    pub ord_unpr: String,
+    /// 예약주문여부 (Y/N)
+    #[serde(rename = "RSVN_ORD_YN")]
+    pub rsvn_ord_yn: String,
+    /// 비상주문여부 (Y/N)
+    #[serde(rename = "EMGC_ORD_YN")]
+    pub emgc_ord_yn: String,
+}
+
 /// 정정/취소 주문 요청
 #[derive(Debug, Clone, Serialize)]
 pub struct OrderReviseRequest {
@@ -76,6 +186,9 @@ pub struct OrderReviseRequest {
     /// 잔량전부주문여부 (Y/N)
     #[serde(rename = "QTY_ALL_ORD_YN")]
     pub qty_all_ord_yn: String,
+    /// 거래소구분 (KRX/NXT/SOR, 공백이면 생략)
+    #[serde(rename = "EXCG_ID_DVSN_CD", skip_serializing_if = "String::is_empty")]
+    pub excg_id_dvsn_cd: String,
 }
 
 /// 정정/취소 주문 응답
@@ -89,6 +202,293 @@ pub struct OrderReviseResponse {
     pub ord_tmd: Option<String>,
 }
 
+/// 예약주문 기간
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReservationPeriod {
+    /// 일반예약: 다음 영업일 장 개시 시 1회 전송
+    NextDay,
+    /// 기간예약: 종료일자(YYYYMMDD)까지 매 영업일 전송 (최대 30일)
+    Until(String),
+}
+
+impl ReservationPeriod {
+    /// RSVN_ORD_END_DT 값 (일반예약은 공백)
+    pub fn end_date(&self) -> &str {
+        match self {
+            ReservationPeriod::NextDay => "",
+            ReservationPeriod::Until(date) => date.as_str(),
+        }
+    }
+
+    /// Validate the end date format (YYYYMMDD)
+    pub fn validate(&self) -> KisResult<()> {
+        if let ReservationPeriod::Until(date) = self {
+            if date.len() != 8 || !date.chars().all(|c| c.is_ascii_digit()) {
+                return Err(KisError::Validation(format!(
+                    "Reservation end date must be YYYYMMDD: {}",
+                    date
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 주식예약주문 요청
+#[derive(Debug, Clone, Serialize)]
+pub struct ReservationOrderRequest {
+    /// 계좌번호 앞 8자리
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌번호 뒤 2자리
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 종목코드
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 주문수량
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 주문단가 (시장가일 경우 0)
+    #[serde(rename = "ORD_UNPR")]
+    pub ord_unpr: String,
+    /// 매도매수구분코드 (01:매도, 02:매수)
+    #[serde(rename = "SLL_BUY_DVSN_CD")]
+    pub sll_buy_dvsn_cd: String,
+    /// 주문구분코드 (00:지정가, 01:시장가, 02:조건부지정가, 05:장전시간외)
+    #[serde(rename = "ORD_DVSN_CD")]
+    pub ord_dvsn_cd: String,
+    /// 주문대상잔고구분코드 (10:현금)
+    #[serde(rename = "ORD_OBJT_CBLC_DVSN_CD")]
+    pub ord_objt_cblc_dvsn_cd: String,
+    /// 대출일자
+    #[serde(rename = "LOAN_DT")]
+    pub loan_dt: String,
+    /// 예약주문종료일자 (기간예약시 YYYYMMDD, 일반예약은 공백)
+    #[serde(rename = "RSVN_ORD_END_DT")]
+    pub rsvn_ord_end_dt: String,
+}
+
+/// 주식예약주문 응답
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReservationOrderResponse {
+    /// 예약주문순번
+    #[serde(rename = "RSVN_ORD_SEQ")]
+    pub rsvn_ord_seq: Option<String>,
+}
+
+/// 주식예약주문 취소 요청
+#[derive(Debug, Clone, Serialize)]
+pub struct ReservationCancelRequest {
+    /// 계좌번호 앞 8자리
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌번호 뒤 2자리
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 예약주문순번
+    #[serde(rename = "RSVN_ORD_SEQ")]
+    pub rsvn_ord_seq: String,
+    /// 예약주문조직번호 (공백)
+    #[serde(rename = "RSVN_ORD_ORGNO")]
+    pub rsvn_ord_orgno: String,
+    /// 예약주문주문일자 (공백)
+    #[serde(rename = "RSVN_ORD_ORD_DT")]
+    pub rsvn_ord_ord_dt: String,
+}
+
+/// 주식예약주문 조회 항목
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReservationOrderItem {
+    /// 예약주문순번
+    #[serde(rename = "rsvn_ord_seq")]
+    pub rsvn_ord_seq: Option<String>,
+    /// 예약주문주문일자
+    #[serde(rename = "rsvn_ord_ord_dt", default)]
+    pub rsvn_ord_ord_dt: KisField<NaiveDate>,
+    /// 예약주문접수일자
+    #[serde(rename = "rsvn_ord_rcit_dt", default)]
+    pub rsvn_ord_rcit_dt: KisField<NaiveDate>,
+    /// 종목코드
+    #[serde(rename = "pdno")]
+    pub pdno: Option<String>,
+    /// 종목명
+    #[serde(rename = "kor_item_shtn_name")]
+    pub kor_item_shtn_name: Option<String>,
+    /// 매도매수구분코드 (01:매도, 02:매수)
+    #[serde(rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: Option<String>,
+    /// 주문구분코드
+    #[serde(rename = "ord_dvsn_cd")]
+    pub ord_dvsn_cd: Option<String>,
+    /// 주문예약수량
+    #[serde(rename = "ord_rsvn_qty", default)]
+    pub ord_rsvn_qty: KisField<Quantity>,
+    /// 주문예약단가
+    #[serde(rename = "ord_rsvn_unpr", default)]
+    pub ord_rsvn_unpr: KisField<Price>,
+    /// 총체결수량
+    #[serde(rename = "tot_ccld_qty", default)]
+    pub tot_ccld_qty: KisField<Quantity>,
+    /// 주문번호 (전송 후)
+    #[serde(rename = "odno")]
+    pub odno: Option<String>,
+    /// 처리결과
+    #[serde(rename = "prcs_rslt")]
+    pub prcs_rslt: Option<String>,
+    /// 거부사유
+    #[serde(rename = "rjct_rson2")]
+    pub rjct_rson2: Option<String>,
+    /// 취소주문일자
+    #[serde(rename = "cncl_ord_dt", default)]
+    pub cncl_ord_dt: KisField<NaiveDate>,
+    /// 예약종료일자
+    #[serde(rename = "rsvn_end_dt", default)]
+    pub rsvn_end_dt: KisField<NaiveDate>,
+}
+
+impl_field_report!(ReservationOrderItem {
+    rsvn_ord_ord_dt,
+    rsvn_ord_rcit_dt,
+    ord_rsvn_qty,
+    ord_rsvn_unpr,
+    tot_ccld_qty,
+    cncl_ord_dt,
+    rsvn_end_dt,
+});
+
+impl ReservationOrderItem {
+    /// 예약주문의 `Order.id` (`rsvn_<예약주문순번>`, submit_order와 같은 형식)
+    pub fn reservation_id(&self) -> Option<String> {
+        self.rsvn_ord_seq
+            .as_deref()
+            .map(str::trim)
+            .filter(|seq| !seq.is_empty())
+            .map(|seq| format!("rsvn_{}", seq))
+    }
+
+    /// 취소된 예약인지 여부
+    pub fn is_cancelled(&self) -> bool {
+        !self.cncl_ord_dt.is_blank()
+    }
+
+    /// 아직 전송되지 않아 취소할 수 있는 예약인지 여부
+    pub fn is_pending(&self) -> bool {
+        !self.is_cancelled() && self.odno.as_deref().unwrap_or("").trim().is_empty()
+    }
+}
+
+/// 미체결 주문 항목 (정정취소가능주문조회 / 일별주문체결조회)
+#[derive(Debug, Clone, Deserialize)]
+pub struct OpenOrderItem {
+    /// 주문채번지점번호 (한국거래소전송주문조직번호)
+    #[serde(rename = "ord_gno_brno")]
+    pub ord_gno_brno: Option<String>,
+    /// 주문번호
+    #[serde(rename = "odno")]
+    pub odno: Option<String>,
+    /// 종목코드
+    #[serde(rename = "pdno")]
+    pub pdno: Option<String>,
+    /// 종목명
+    #[serde(rename = "prdt_name")]
+    pub prdt_name: Option<String>,
+    /// 매도매수구분코드 (01:매도, 02:매수)
+    #[serde(rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: Option<String>,
+    /// 주문수량
+    #[serde(rename = "ord_qty", default)]
+    pub ord_qty: KisField<Quantity>,
+    /// 총체결수량
+    #[serde(rename = "tot_ccld_qty", default)]
+    pub tot_ccld_qty: KisField<Quantity>,
+    /// 정정취소가능수량 (정정취소가능주문조회)
+    #[serde(rename = "psbl_qty", default)]
+    pub psbl_qty: KisField<Quantity>,
+    /// 잔여수량 (일별주문체결조회)
+    #[serde(rename = "rmn_qty", default)]
+    pub rmn_qty: KisField<Quantity>,
+    /// 거래소구분 (KRX/NXT/SOR)
+    #[serde(rename = "excg_id_dvsn_cd")]
+    pub excg_id_dvsn_cd: Option<String>,
+}
+
+impl_field_report!(OpenOrderItem {
+    ord_qty,
+    tot_ccld_qty,
+    psbl_qty,
+    rmn_qty,
+});
+
+impl OpenOrderItem {
+    /// 취소 가능한 잔량
+    pub fn remaining_quantity(&self) -> Quantity {
+        self.psbl_qty
+            .get()
+            .or_else(|| self.rmn_qty.get())
+            .unwrap_or_else(|| self.ord_qty.or_zero() - self.tot_ccld_qty.or_zero())
+    }
+
+    /// 원주문 거래소 (없으면 KRX)
+    pub fn exchange(&self) -> DomesticExchange {
+        self.excg_id_dvsn_cd
+            .as_deref()
+            .and_then(DomesticExchange::from_code)
+            .unwrap_or(DomesticExchange::Krx)
+    }
+}
+
+/// 체결 내역 항목 (주식일별주문체결조회)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExecutionItem {
+    /// 주문일자
+    #[serde(rename = "ord_dt", default)]
+    pub ord_dt: KisField<NaiveDate>,
+    /// 주문번호
+    #[serde(rename = "odno")]
+    pub odno: Option<String>,
+    /// 종목코드
+    #[serde(rename = "pdno")]
+    pub pdno: Option<String>,
+    /// 매도매수구분코드 (01:매도, 02:매수)
+    #[serde(rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: Option<String>,
+    /// 주문시각 (HHMMSS)
+    #[serde(rename = "ord_tmd")]
+    pub ord_tmd: Option<String>,
+    /// 주문수량
+    #[serde(rename = "ord_qty", default)]
+    pub ord_qty: KisField<Quantity>,
+    /// 주문단가 (시장가는 0)
+    #[serde(rename = "ord_unpr", default)]
+    pub ord_unpr: KisField<Price>,
+    /// 총체결수량
+    #[serde(rename = "tot_ccld_qty", default)]
+    pub tot_ccld_qty: KisField<Quantity>,
+    /// 체결평균가
+    #[serde(rename = "avg_prvs", default)]
+    pub avg_prvs: KisField<Price>,
+}
+
+impl_field_report!(ExecutionItem {
+    ord_dt,
+    ord_qty,
+    ord_unpr,
+    tot_ccld_qty,
+    avg_prvs,
+});
+
+impl ExecutionItem {
+    /// 매도/매수 구분
+    pub fn side(&self) -> Option<OrderSide> {
+        match self.sll_buy_dvsn_cd.as_deref() {
+            Some("01") => Some(OrderSide::Sell),
+            Some("02") => Some(OrderSide::Buy),
+            _ => None,
+        }
+    }
+}
+
 /// 잔고 조회 응답 (output1 항목)
 #[derive(Debug, Clone, Deserialize)]
 pub struct BalanceItem {
@@ -99,45 +499,97 @@ pub struct BalanceItem {
     #[serde(rename = "prdt_name")]
     pub prdt_name: Option<String>,
     /// 보유수량
-    #[serde(rename = "hldg_qty")]
-    pub hldg_qty: Option<String>,
+    #[serde(rename = "hldg_qty", default)]
+    pub hldg_qty: KisField<Quantity>,
     /// 매입평균가격
-    #[serde(rename = "pchs_avg_pric")]
-    pub pchs_avg_pric: Option<String>,
+    #[serde(rename = "pchs_avg_pric", default)]
+    pub pchs_avg_pric: KisField<Price>,
     /// 현재가
-    #[serde(rename = "prpr")]
-    pub prpr: Option<String>,
+    #[serde(rename = "prpr", default)]
+    pub prpr: KisField<Price>,
+    /// 전일대비증감
+    #[serde(rename = "bfdy_cprs_icdc", default)]
+    pub bfdy_cprs_icdc: KisField<Price>,
     /// 평가손익
-    #[serde(rename = "evlu_pfls_amt")]
-    pub evlu_pfls_amt: Option<String>,
+    #[serde(rename = "evlu_pfls_amt", default)]
+    pub evlu_pfls_amt: KisField<Amount>,
     /// 평가손익율
-    #[serde(rename = "evlu_pfls_rt")]
-    pub evlu_pfls_rt: Option<String>,
+    #[serde(rename = "evlu_pfls_rt", default)]
+    pub evlu_pfls_rt: KisField<Rate>,
     /// 평가금액
-    #[serde(rename = "evlu_amt")]
-    pub evlu_amt: Option<String>,
+    #[serde(rename = "evlu_amt", default)]
+    pub evlu_amt: KisField<Amount>,
+    /// 주문가능수량
+    #[serde(rename = "ord_psbl_qty", default)]
+    pub ord_psbl_qty: KisField<Quantity>,
+    /// 매매구분명 (현금, 자기융자, 유통융자 등)
+    #[serde(rename = "trad_dvsn_name")]
+    pub trad_dvsn_name: Option<String>,
+    /// 대출일자
+    #[serde(rename = "loan_dt", default)]
+    pub loan_dt: KisField<NaiveDate>,
+    /// 대출금액
+    #[serde(rename = "loan_amt", default)]
+    pub loan_amt: KisField<Amount>,
+    /// 만기일자
+    #[serde(rename = "expd_dt", default)]
+    pub expd_dt: KisField<NaiveDate>,
+}
+
+impl_field_report!(BalanceItem {
+    hldg_qty,
+    pchs_avg_pric,
+    prpr,
+    bfdy_cprs_icdc,
+    evlu_pfls_amt,
+    evlu_pfls_rt,
+    evlu_amt,
+    ord_psbl_qty,
+    loan_dt,
+    loan_amt,
+    expd_dt,
+});
+
+impl BalanceItem {
+    /// 신용(융자) 잔고 여부
+    pub fn is_credit(&self) -> bool {
+        self.loan_dt.get().is_some()
+    }
+
+    /// 전일종가 (현재가 - 전일대비증감)
+    pub fn prev_close(&self) -> Option<Price> {
+        Some(self.prpr.get()? - self.bfdy_cprs_icdc.get()?)
+    }
 }
 
 /// 잔고 조회 응답 (output2 합계)
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct BalanceSummary {
     /// 총평가금액
-    #[serde(rename = "tot_evlu_amt")]
-    pub tot_evlu_amt: Option<String>,
+    #[serde(rename = "tot_evlu_amt", default)]
+    pub tot_evlu_amt: KisField<Amount>,
     /// 예수금총액
-    #[serde(rename = "dnca_tot_amt")]
-    pub dnca_tot_amt: Option<String>,
+    #[serde(rename = "dnca_tot_amt", default)]
+    pub dnca_tot_amt: KisField<Amount>,
     /// 주문가능현금
-    #[serde(rename = "ord_psbl_cash")]
-    pub ord_psbl_cash: Option<String>,
+    #[serde(rename = "ord_psbl_cash", default)]
+    pub ord_psbl_cash: KisField<Amount>,
     /// 총평가손익
-    #[serde(rename = "evlu_pfls_smtl_amt")]
-    pub evlu_pfls_smtl_amt: Option<String>,
+    #[serde(rename = "evlu_pfls_smtl_amt", default)]
+    pub evlu_pfls_smtl_amt: KisField<Amount>,
     /// 총평가손익율
-    #[serde(rename = "evlu_pfls_rt")]
-    pub evlu_pfls_rt: Option<String>,
+    #[serde(rename = "evlu_pfls_rt", default)]
+    pub evlu_pfls_rt: KisField<Rate>,
 }
 
+impl_field_report!(BalanceSummary {
+    tot_evlu_amt,
+    dnca_tot_amt,
+    ord_psbl_cash,
+    evlu_pfls_smtl_amt,
+    evlu_pfls_rt,
+});
+
 /// 잔고 조회 전체 응답
 #[derive(Debug, Clone, Deserialize)]
 pub struct BalanceResponse {
@@ -147,6 +599,83 @@ pub struct BalanceResponse {
     pub output2: Option<BalanceSummary>,
 }
 
+/// 매수가능조회 응답
+#[derive(Debug, Clone, Deserialize)]
+pub struct BuyingPowerResponse {
+    /// 주문가능현금
+    #[serde(rename = "ord_psbl_cash", default)]
+    pub ord_psbl_cash: KisField<Amount>,
+    /// 재사용가능금액
+    #[serde(rename = "ruse_psbl_amt", default)]
+    pub ruse_psbl_amt: KisField<Amount>,
+    /// 가능수량계산단가
+    #[serde(rename = "psbl_qty_calc_unpr", default)]
+    pub psbl_qty_calc_unpr: KisField<Price>,
+    /// 미수없는매수금액
+    #[serde(rename = "nrcvb_buy_amt", default)]
+    pub nrcvb_buy_amt: KisField<Amount>,
+    /// 미수없는매수수량
+    #[serde(rename = "nrcvb_buy_qty", default)]
+    pub nrcvb_buy_qty: KisField<Quantity>,
+    /// 최대매수금액 (미수 포함)
+    #[serde(rename = "max_buy_amt", default)]
+    pub max_buy_amt: KisField<Amount>,
+    /// 최대매수수량 (미수 포함)
+    #[serde(rename = "max_buy_qty", default)]
+    pub max_buy_qty: KisField<Quantity>,
+}
+
+impl_field_report!(BuyingPowerResponse {
+    ord_psbl_cash,
+    ruse_psbl_amt,
+    psbl_qty_calc_unpr,
+    nrcvb_buy_amt,
+    nrcvb_buy_qty,
+    max_buy_amt,
+    max_buy_qty,
+});
+
+impl BuyingPowerResponse {
+    /// 미수 없이 현금으로 매수 가능한 수량
+    pub fn cash_quantity(&self) -> Option<u64> {
+        whole_quantity(&self.nrcvb_buy_qty)
+    }
+}
+
+/// 매도가능수량조회 응답
+#[derive(Debug, Clone, Deserialize)]
+pub struct SellableQuantityResponse {
+    /// 종목코드
+    #[serde(rename = "pdno")]
+    pub pdno: Option<String>,
+    /// 종목명
+    #[serde(rename = "prdt_name")]
+    pub prdt_name: Option<String>,
+    /// 잔고수량
+    #[serde(rename = "cblc_qty", default)]
+    pub cblc_qty: KisField<Quantity>,
+    /// 주문가능수량
+    #[serde(rename = "ord_psbl_qty", default)]
+    pub ord_psbl_qty: KisField<Quantity>,
+}
+
+impl_field_report!(SellableQuantityResponse {
+    cblc_qty,
+    ord_psbl_qty,
+});
+
+impl SellableQuantityResponse {
+    /// 매도 주문가능수량
+    pub fn quantity(&self) -> Option<u64> {
+        whole_quantity(&self.ord_psbl_qty)
+    }
+}
+
+/// Whole-share count of a KIS quantity field ("000123", "12.0000")
+fn whole_quantity(field: &KisField<Quantity>) -> Option<u64> {
+    field.get().map(|q| q.trunc().max(0) as u64)
+}
+
 /// 현재가 조회 응답
 #[derive(Debug, Clone, Deserialize)]
 pub struct CurrentPriceResponse {
@@ -154,33 +683,210 @@ pub struct CurrentPriceResponse {
     #[serde(rename = "stck_shrn_iscd")]
     pub stck_shrn_iscd: Option<String>,
     /// 현재가
-    #[serde(rename = "stck_prpr")]
-    pub stck_prpr: Option<String>,
+    #[serde(rename = "stck_prpr", default)]
+    pub stck_prpr: KisField<Price>,
     /// 전일대비
-    #[serde(rename = "prdy_vrss")]
-    pub prdy_vrss: Option<String>,
+    #[serde(rename = "prdy_vrss", default)]
+    pub prdy_vrss: KisField<Price>,
     /// 전일대비율
-    #[serde(rename = "prdy_ctrt")]
-    pub prdy_ctrt: Option<String>,
+    #[serde(rename = "prdy_ctrt", default)]
+    pub prdy_ctrt: KisField<Rate>,
     /// 누적거래량
-    #[serde(rename = "acml_vol")]
-    pub acml_vol: Option<String>,
+    #[serde(rename = "acml_vol", default)]
+    pub acml_vol: KisField<Quantity>,
     /// 거래대금
-    #[serde(rename = "acml_tr_pbmn")]
-    pub acml_tr_pbmn: Option<String>,
+    #[serde(rename = "acml_tr_pbmn", default)]
+    pub acml_tr_pbmn: KisField<Amount>,
+    /// 시가
+    #[serde(rename = "stck_oprc", default)]
+    pub stck_oprc: KisField<Price>,
+    /// 고가
+    #[serde(rename = "stck_hgpr", default)]
+    pub stck_hgpr: KisField<Price>,
+    /// 저가
+    #[serde(rename = "stck_lwpr", default)]
+    pub stck_lwpr: KisField<Price>,
+    /// 상한가
+    #[serde(rename = "stck_mxpr", default)]
+    pub stck_mxpr: KisField<Price>,
+    /// 하한가
+    #[serde(rename = "stck_llam", default)]
+    pub stck_llam: KisField<Price>,
+    /// 기준가
+    #[serde(rename = "stck_sdpr", default)]
+    pub stck_sdpr: KisField<Price>,
+    /// 대표시장한글명 (KOSPI200, KSQ150, ETF 등)
+    #[serde(rename = "rprs_mrkt_kor_name")]
+    pub rprs_mrkt_kor_name: Option<String>,
+}
+
+impl_field_report!(CurrentPriceResponse {
+    stck_prpr,
+    prdy_vrss,
+    prdy_ctrt,
+    acml_vol,
+    acml_tr_pbmn,
+    stck_oprc,
+    stck_hgpr,
+    stck_lwpr,
+    stck_mxpr,
+    stck_llam,
+    stck_sdpr,
+});
+
+impl CurrentPriceResponse {
+    /// Guess the tick-size market from 대표시장한글명 (기본: 유가증권)
+    pub fn market(&self) -> KrxMarket {
+        let name = self
+            .rprs_mrkt_kor_name
+            .as_deref()
+            .unwrap_or("")
+            .to_ascii_uppercase();
+        if name.contains("ETF") || name.contains("ETN") {
+            KrxMarket::EtfEtn
+        } else if name.contains("ELW") {
+            KrxMarket::Elw
+        } else if name.contains("KOSDAQ") || name.contains("KSQ") {
+            KrxMarket::Kosdaq
+        } else if name.contains("KONEX") {
+            KrxMarket::Konex
+        } else {
+            KrxMarket::Kospi
+        }
+    }
+
+    /// (하한가, 상한가); 응답에 없으면 기준가로 계산
+    pub fn price_limits(&self, market: KrxMarket) -> Option<(u32, u32)> {
+        let krw = |field: &KisField<Price>| field.get().and_then(|p| p.to_krw()).filter(|&p| p > 0);
+        match (krw(&self.stck_llam), krw(&self.stck_mxpr)) {
+            (Some(lower), Some(upper)) => Some((lower, upper)),
+            _ => krw(&self.stck_sdpr).map(|base| market.price_limits(base)),
+        }
+    }
+}
+
+/// 관심종목 복수시세 1회 조회 최대 종목 수
+pub const MULTI_PRICE_MAX_SYMBOLS: usize = 30;
+
+/// 관심종목 복수시세 조회 응답 항목
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MultiPriceItem {
+    /// 종목코드
+    #[serde(rename = "inter_shrn_iscd")]
+    pub inter_shrn_iscd: Option<String>,
+    /// 종목명
+    #[serde(rename = "inter_kor_isnm")]
+    pub inter_kor_isnm: Option<String>,
+    /// 현재가
+    #[serde(rename = "inter2_prpr", default)]
+    pub inter2_prpr: KisField<Price>,
+    /// 전일대비
+    #[serde(rename = "inter2_prdy_vrss", default)]
+    pub inter2_prdy_vrss: KisField<Price>,
+    /// 전일대비율
+    #[serde(rename = "prdy_ctrt", default)]
+    pub prdy_ctrt: KisField<Rate>,
+    /// 누적거래량
+    #[serde(rename = "acml_vol", default)]
+    pub acml_vol: KisField<Quantity>,
     /// 시가
-    #[serde(rename = "stck_oprc")]
-    pub stck_oprc: Option<String>,
+    #[serde(rename = "inter2_oprc", default)]
+    pub inter2_oprc: KisField<Price>,
     /// 고가
-    #[serde(rename = "stck_hgpr")]
-    pub stck_hgpr: Option<String>,
+    #[serde(rename = "inter2_hgpr", default)]
+    pub inter2_hgpr: KisField<Price>,
     /// 저가
-    #[serde(rename = "stck_lwpr")]
-    pub stck_lwpr: Option<String>,
+    #[serde(rename = "inter2_lwpr", default)]
+    pub inter2_lwpr: KisField<Price>,
+}
+
+impl_field_report!(MultiPriceItem {
+    inter2_prpr,
+    inter2_prdy_vrss,
+    prdy_ctrt,
+    acml_vol,
+    inter2_oprc,
+    inter2_hgpr,
+    inter2_lwpr,
+});
+
+/// 주식기본조회 응답 (search-stock-info)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StockInfo {
+    /// 상품번호 (단축코드)
+    #[serde(rename = "pdno")]
+    pub pdno: Option<String>,
+    /// 표준상품번호 (ISIN)
+    #[serde(rename = "std_pdno")]
+    pub std_pdno: Option<String>,
+    /// 상품약어명
+    #[serde(rename = "prdt_abrv_name")]
+    pub prdt_abrv_name: Option<String>,
+    /// 상품영문명
+    #[serde(rename = "prdt_eng_name")]
+    pub prdt_eng_name: Option<String>,
+    /// 시장ID코드 (STK 유가증권, KSQ 코스닥, KNX 코넥스)
+    #[serde(rename = "mket_id_cd")]
+    pub mket_id_cd: Option<String>,
+    /// 증권그룹ID코드 (ST 주권, EF ETF, EN ETN, RT 리츠 등)
+    #[serde(rename = "scty_grp_id_cd")]
+    pub scty_grp_id_cd: Option<String>,
+    /// 거래정지여부
+    #[serde(rename = "tr_stop_yn")]
+    pub tr_stop_yn: Option<String>,
+    /// 관리종목여부
+    #[serde(rename = "admn_item_yn")]
+    pub admn_item_yn: Option<String>,
+    /// 상장폐지일자
+    #[serde(rename = "lstg_abol_dt")]
+    pub lstg_abol_dt: Option<String>,
+}
+
+/// 상품기본조회 응답 (search-info)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ProductInfo {
+    /// 상품번호
+    #[serde(rename = "pdno")]
+    pub pdno: Option<String>,
+    /// 상품유형코드
+    #[serde(rename = "prdt_type_cd")]
+    pub prdt_type_cd: Option<String>,
+    /// 상품명
+    #[serde(rename = "prdt_name")]
+    pub prdt_name: Option<String>,
+    /// 상품약어명
+    #[serde(rename = "prdt_abrv_name")]
+    pub prdt_abrv_name: Option<String>,
+    /// 상품영문명
+    #[serde(rename = "prdt_eng_name")]
+    pub prdt_eng_name: Option<String>,
+    /// 표준상품번호
+    #[serde(rename = "std_pdno")]
+    pub std_pdno: Option<String>,
+    /// 상품분류명
+    #[serde(rename = "prdt_clsf_name")]
+    pub prdt_clsf_name: Option<String>,
+}
+
+impl MultiPriceItem {
+    /// Build from a single-symbol 현재가 response (모의투자 대체 조회용)
+    fn from_current_price(symbol: &str, quote: &CurrentPriceResponse) -> Self {
+        MultiPriceItem {
+            inter_shrn_iscd: Some(symbol.to_string()),
+            inter_kor_isnm: None,
+            inter2_prpr: quote.stck_prpr.clone(),
+            inter2_prdy_vrss: quote.prdy_vrss.clone(),
+            prdy_ctrt: quote.prdy_ctrt.clone(),
+            acml_vol: quote.acml_vol.clone(),
+            inter2_oprc: quote.stck_oprc.clone(),
+            inter2_hgpr: quote.stck_hgpr.clone(),
+            inter2_lwpr: quote.stck_lwpr.clone(),
+        }
+    }
 }
 
 impl KisClient {
-    /// 현금 매수 주문
+    /// 현금 매수 주문 (KRX)
     ///
     /// # Arguments
     /// * `symbol` - 종목코드 (예: "005930")
@@ -194,6 +900,69 @@ impl KisClient {
         price: u32,
         order_type: OrderType,
     ) -> KisResult<CashOrderResponse> {
+        self.domestic_order(
+            OrderSide::Buy,
+            symbol,
+            quantity,
+            price,
+            order_type,
+            DomesticExchange::Krx,
+        )
+    }
+
+    /// 현금 매도 주문 (KRX)
+    ///
+    /// # Arguments
+    /// * `symbol` - 종목코드 (예: "005930")
+    /// * `quantity` - 주문수량
+    /// * `price` - 주문단가 (시장가일 경우 0)
+    /// * `order_type` - 주문구분
+    pub fn domestic_sell(
+        &mut self,
+        symbol: &str,
+        quantity: u32,
+        price: u32,
+        order_type: OrderType,
+    ) -> KisResult<CashOrderResponse> {
+        self.domestic_order(
+            OrderSide::Sell,
+            symbol,
+            quantity,
+            price,
+            order_type,
+            DomesticExchange::Krx,
+        )
+    }
+
+    /// 현금 매수/매도 주문 (거래소 지정)
+    ///
+    /// NXT/SOR 주문은 현재 KST 시각의 넥스트레이드 세션에 맞춰 검증하며,
+    /// 모의투자는 KRX만 지원합니다.
+    ///
+    /// # Arguments
+    /// * `side` - 매수/매도
+    /// * `symbol` - 종목코드 (예: "005930")
+    /// * `quantity` - 주문수량
+    /// * `price` - 주문단가 (시장가일 경우 0)
+    /// * `order_type` - 주문구분
+    /// * `exchange` - 거래소구분 (KRX, NXT, SOR)
+    pub fn domestic_order(
+        &mut self,
+        side: OrderSide,
+        symbol: &str,
+        quantity: u32,
+        price: u32,
+        order_type: OrderType,
+        exchange: DomesticExchange,
+    ) -> KisResult<CashOrderResponse> {
+        if self.config.is_paper && exchange != DomesticExchange::Krx {
+            return Err(KisError::Validation(format!(
+                "{} routing is not available in paper trading",
+                exchange.code()
+            )));
+        }
+        exchange.validate_order(order_type, kst_now())?;
+
         self.ensure_auth()?;
 
         let request = CashOrderRequest {
@@ -203,32 +972,21 @@ impl KisClient {
             ord_dvsn: order_type.code().to_string(),
             ord_qty: quantity.to_string(),
             ord_unpr: price.to_string(),
+            excg_id_dvsn_cd: exchange.code().to_string(),
         };
 
-        let tr_id = if self.config.is_paper {
-            "VTTC0802U" // 모의투자 매수
-        } else {
-            "TTTC0802U" // 실전투자 매수
+        let tr_id = match (side, self.config.is_paper) {
+            (OrderSide::Buy, true) => "VTTC0802U",   // 모의투자 매수
+            (OrderSide::Buy, false) => "TTTC0802U",  // 실전투자 매수
+            (OrderSide::Sell, true) => "VTTC0801U",  // 모의투자 매도
+            (OrderSide::Sell, false) => "TTTC0801U", // 실전투자 매도
         };
 
-        // Get hashkey for POST order
-        let hashkey = self.get_hashkey(&request)?;
-
-        let mut headers = self.build_headers(tr_id)?;
-        headers.insert("hashkey".to_string(), hashkey);
-
-        let response = self.http.post_json(
+        let response = self.post_order(
             "/uapi/domestic-stock/v1/trading/order-cash",
+            tr_id,
             &request,
-            Some(headers),
-        );
-
-        if !response.is_success() {
-            return Err(KisError::Api {
-                code: response.status.to_string(),
-                message: response.error.unwrap_or_else(|| response.body.clone()),
-            });
-        }
+        )?;
 
         let api_response: KisApiResponse<CashOrderResponse> = response
             .json()
@@ -246,59 +1004,84 @@ impl KisClient {
             .ok_or_else(|| KisError::Parse("No output in order response".to_string()))
     }
 
-    /// 현금 매도 주문
+    /// 신용 매수/상환 주문 (실전 전용)
     ///
     /// # Arguments
+    /// * `credit_type` - 신용유형 (신규는 매수, 상환은 매도)
     /// * `symbol` - 종목코드 (예: "005930")
     /// * `quantity` - 주문수량
     /// * `price` - 주문단가 (시장가일 경우 0)
     /// * `order_type` - 주문구분
-    pub fn domestic_sell(
+    /// * `loan_date` - 대출일자 (YYYYMMDD). 상환 주문은 필수, 신규 주문은 당일
+    pub fn domestic_credit_order(
         &mut self,
+        credit_type: CreditType,
         symbol: &str,
         quantity: u32,
         price: u32,
         order_type: OrderType,
+        loan_date: Option<&str>,
     ) -> KisResult<CashOrderResponse> {
+        if self.config.is_paper {
+            return Err(KisError::Validation(
+                "Credit orders are not available in paper trading".to_string(),
+            ));
+        }
+
+        order_type.validate_krx_session(kst_now())?;
+
+        let loan_dt = if credit_type.is_repayment() {
+            let date = loan_date.ok_or_else(|| {
+                KisError::Validation(format!(
+                    "Loan date is required for credit repayment ({})",
+                    credit_type.code()
+                ))
+            })?;
+            if date.len() != 8 || !date.chars().all(|c| c.is_ascii_digit()) {
+                return Err(KisError::Validation(format!(
+                    "Loan date must be YYYYMMDD: {}",
+                    date
+                )));
+            }
+            date.to_string()
+        } else {
+            kst_now().format("%Y%m%d").to_string()
+        };
+
         self.ensure_auth()?;
 
-        let request = CashOrderRequest {
+        let side = credit_type.side();
+        let request = CreditOrderRequest {
             cano: self.cano().to_string(),
             acnt_prdt_cd: self.acnt_prdt_cd().to_string(),
             pdno: symbol.to_string(),
+            sll_type: match side {
+                OrderSide::Buy => "".to_string(),
+                OrderSide::Sell => "01".to_string(),
+            },
+            crdt_type: credit_type.code().to_string(),
+            loan_dt,
             ord_dvsn: order_type.code().to_string(),
             ord_qty: quantity.to_string(),
             ord_unpr: price.to_string(),
+            rsvn_ord_yn: "N".to_string(),
+            emgc_ord_yn: "N".to_string(),
         };
 
-        let tr_id = if self.config.is_paper {
-            "VTTC0801U" // 모의투자 매도
-        } else {
-            "TTTC0801U" // 실전투자 매도
+        let tr_id = match side {
+            OrderSide::Buy => "TTTC0852U",  // 신용 매수
+            OrderSide::Sell => "TTTC0851U", // 신용 매도
         };
 
-        // Get hashkey for POST order
-        let hashkey = self.get_hashkey(&request)?;
-
-        let mut headers = self.build_headers(tr_id)?;
-        headers.insert("hashkey".to_string(), hashkey);
-
-        let response = self.http.post_json(
-            "/uapi/domestic-stock/v1/trading/order-cash",
+        let response = self.post_order(
+            "/uapi/domestic-stock/v1/trading/order-credit",
+            tr_id,
             &request,
-            Some(headers),
-        );
-
-        if !response.is_success() {
-            return Err(KisError::Api {
-                code: response.status.to_string(),
-                message: response.error.unwrap_or_else(|| response.body.clone()),
-            });
-        }
+        )?;
 
-        let api_response: KisApiResponse<CashOrderResponse> = response
-            .json()
-            .map_err(|e| KisError::Parse(format!("Failed to parse order response: {}", e)))?;
+        let api_response: KisApiResponse<CashOrderResponse> = response.json().map_err(|e| {
+            KisError::Parse(format!("Failed to parse credit order response: {}", e))
+        })?;
 
         if !api_response.is_success() {
             return Err(KisError::Api {
@@ -309,7 +1092,7 @@ impl KisClient {
 
         api_response
             .output
-            .ok_or_else(|| KisError::Parse("No output in order response".to_string()))
+            .ok_or_else(|| KisError::Parse("No output in credit order response".to_string()))
     }
 
     /// 주문 정정
@@ -338,28 +1121,16 @@ impl KisClient {
             ord_qty: quantity.to_string(),
             ord_unpr: price.to_string(),
             qty_all_ord_yn: "N".to_string(),
+            excg_id_dvsn_cd: String::new(),
         };
 
         let tr_id = "TTTC0803U"; // 정정취소 (실전/모의 동일)
 
-        // Get hashkey for POST order
-        let hashkey = self.get_hashkey(&request)?;
-
-        let mut headers = self.build_headers(tr_id)?;
-        headers.insert("hashkey".to_string(), hashkey);
-
-        let response = self.http.post_json(
+        let response = self.post_order(
             "/uapi/domestic-stock/v1/trading/order-rvsecncl",
+            tr_id,
             &request,
-            Some(headers),
-        );
-
-        if !response.is_success() {
-            return Err(KisError::Api {
-                code: response.status.to_string(),
-                message: response.error.unwrap_or_else(|| response.body.clone()),
-            });
-        }
+        )?;
 
         let api_response: KisApiResponse<OrderReviseResponse> = response
             .json()
@@ -381,10 +1152,14 @@ impl KisClient {
     ///
     /// # Arguments
     /// * `order_no` - 원주문번호
+    /// * `org_no` - 한국거래소전송주문조직번호 (미체결 조회의 주문채번지점번호, 모르면 공백)
+    /// * `exchange` - 원주문 거래소 (KRX/NXT/SOR)
     /// * `quantity` - 취소 수량 (0이면 전량 취소)
     pub fn domestic_cancel_order(
         &mut self,
         order_no: &str,
+        org_no: &str,
+        exchange: DomesticExchange,
         quantity: u32,
     ) -> KisResult<OrderReviseResponse> {
         self.ensure_auth()?;
@@ -399,39 +1174,205 @@ impl KisClient {
         let request = OrderReviseRequest {
             cano: self.cano().to_string(),
             acnt_prdt_cd: self.acnt_prdt_cd().to_string(),
-            krx_fwdg_ord_orgno: "".to_string(),
+            krx_fwdg_ord_orgno: org_no.to_string(),
             orgn_odno: order_no.to_string(),
             ord_dvsn: "00".to_string(),          // 지정가
             rvse_cncl_dvsn_cd: "02".to_string(), // 취소
             ord_qty,
             ord_unpr: "0".to_string(),
             qty_all_ord_yn: qty_all_ord_yn.to_string(),
+            excg_id_dvsn_cd: exchange.code().to_string(),
         };
 
         let tr_id = "TTTC0803U"; // 정정취소 (실전/모의 동일)
 
-        // Get hashkey for POST order
-        let hashkey = self.get_hashkey(&request)?;
-
-        let mut headers = self.build_headers(tr_id)?;
-        headers.insert("hashkey".to_string(), hashkey);
-
-        let response = self.http.post_json(
+        let response = self.post_order(
             "/uapi/domestic-stock/v1/trading/order-rvsecncl",
+            tr_id,
             &request,
-            Some(headers),
-        );
+        )?;
 
-        if !response.is_success() {
+        let api_response: KisApiResponse<OrderReviseResponse> = response
+            .json()
+            .map_err(|e| KisError::Parse(format!("Failed to parse cancel response: {}", e)))?;
+
+        if !api_response.is_success() {
             return Err(KisError::Api {
-                code: response.status.to_string(),
-                message: response.error.unwrap_or_else(|| response.body.clone()),
+                code: api_response.msg_cd,
+                message: api_response.msg1,
             });
         }
 
-        let api_response: KisApiResponse<OrderReviseResponse> = response
+        api_response
+            .output
+            .ok_or_else(|| KisError::Parse("No output in cancel response".to_string()))
+    }
+
+    /// 미체결 주문 조회
+    ///
+    /// 실전투자는 주식정정취소가능주문조회를 사용하고, 이를 지원하지 않는 모의투자는 당일
+    /// 주식일별주문체결조회(미체결 구분)를 사용합니다.
+    pub fn domestic_open_orders(&mut self) -> KisResult<Vec<OpenOrderItem>> {
+        self.ensure_auth()?;
+
+        let items = if self.config.is_paper {
+            let today = kst_now().format("%Y%m%d").to_string();
+            let query = format!(
+                "CANO={}&ACNT_PRDT_CD={}&INQR_STRT_DT={}&INQR_END_DT={}&SLL_BUY_DVSN_CD=00&INQR_DVSN=00&PDNO=&CCLD_DVSN=02&ORD_GNO_BRNO=&ODNO=&INQR_DVSN_3=00&INQR_DVSN_1=&CTX_AREA_FK100=&CTX_AREA_NK100=",
+                self.cano(),
+                self.acnt_prdt_cd(),
+                today,
+                today
+            );
+            self.get_list(
+                "/uapi/domestic-stock/v1/trading/inquire-daily-ccld",
+                "VTTC8001R",
+                &query,
+                "open orders",
+                |r: KisApiResponse<OpenOrderItem>| r.output1,
+            )?
+        } else {
+            let query = format!(
+                "CANO={}&ACNT_PRDT_CD={}&CTX_AREA_FK100=&CTX_AREA_NK100=&INQR_DVSN_1=0&INQR_DVSN_2=0",
+                self.cano(),
+                self.acnt_prdt_cd()
+            );
+            self.get_list(
+                "/uapi/domestic-stock/v1/trading/inquire-psbl-rvsecncl",
+                "TTTC8036R",
+                &query,
+                "open orders",
+                |r: KisApiResponse<Vec<OpenOrderItem>>| r.output,
+            )?
+        };
+
+        Ok(items
+            .into_iter()
+            .filter(|item| item.remaining_quantity() > Quantity::ZERO)
+            .collect())
+    }
+
+    /// 일자별 체결 내역 조회 (체결수량이 있는 주문만)
+    ///
+    /// # Arguments
+    /// * `date` - 주문일자 (3개월 이내)
+    pub fn domestic_executions(&mut self, date: NaiveDate) -> KisResult<Vec<ExecutionItem>> {
+        Ok(self
+            .domestic_daily_ccld(date, "01")?
+            .into_iter()
+            .filter(|item| item.tot_ccld_qty.or_zero() > Quantity::ZERO)
+            .collect())
+    }
+
+    /// 일자별 전체 주문 조회 (체결/미체결 포함)
+    ///
+    /// # Arguments
+    /// * `date` - 주문일자 (3개월 이내)
+    pub fn domestic_orders_on(&mut self, date: NaiveDate) -> KisResult<Vec<ExecutionItem>> {
+        self.domestic_daily_ccld(date, "00")
+    }
+
+    /// 주식일별주문체결조회 (`ccld_dvsn` 00:전체, 01:체결, 02:미체결)
+    fn domestic_daily_ccld(
+        &mut self,
+        date: NaiveDate,
+        ccld_dvsn: &str,
+    ) -> KisResult<Vec<ExecutionItem>> {
+        self.ensure_auth()?;
+
+        let tr_id = if self.config.is_paper {
+            "VTTC8001R"
+        } else {
+            "TTTC8001R"
+        };
+        let date = date.format("%Y%m%d").to_string();
+        let query = format!(
+            "CANO={}&ACNT_PRDT_CD={}&INQR_STRT_DT={}&INQR_END_DT={}&SLL_BUY_DVSN_CD=00&INQR_DVSN=00&PDNO=&CCLD_DVSN={}&ORD_GNO_BRNO=&ODNO=&INQR_DVSN_3=00&INQR_DVSN_1=&CTX_AREA_FK100=&CTX_AREA_NK100=",
+            self.cano(),
+            self.acnt_prdt_cd(),
+            date,
+            date,
+            ccld_dvsn
+        );
+
+        self.get_list(
+            "/uapi/domestic-stock/v1/trading/inquire-daily-ccld",
+            tr_id,
+            &query,
+            "executions",
+            |r: KisApiResponse<ExecutionItem>| r.output1,
+        )
+    }
+
+    /// 주식예약주문 (실전 전용)
+    ///
+    /// 장 종료 후 접수하여 다음 영업일(기간예약은 종료일까지) 장 개시 시 전송됩니다.
+    ///
+    /// # Arguments
+    /// * `symbol` - 종목코드 (예: "005930")
+    /// * `side` - 매수/매도
+    /// * `quantity` - 주문수량
+    /// * `price` - 주문단가 (시장가일 경우 0)
+    /// * `order_type` - 주문구분 (지정가/시장가/조건부지정가/장전시간외)
+    /// * `period` - 예약기간
+    pub fn domestic_reserve_order(
+        &mut self,
+        symbol: &str,
+        side: OrderSide,
+        quantity: u32,
+        price: u32,
+        order_type: OrderType,
+        period: &ReservationPeriod,
+    ) -> KisResult<ReservationOrderResponse> {
+        if self.config.is_paper {
+            return Err(KisError::Validation(
+                "Reservation orders are not available in paper trading".to_string(),
+            ));
+        }
+        if !matches!(
+            order_type,
+            OrderType::Limit
+                | OrderType::Market
+                | OrderType::ConditionalLimit
+                | OrderType::PreMarket
+        ) {
+            return Err(KisError::Validation(format!(
+                "Order type {:?} ({}) is not allowed for reservation orders",
+                order_type,
+                order_type.code()
+            )));
+        }
+        period.validate()?;
+
+        self.ensure_auth()?;
+
+        let request = ReservationOrderRequest {
+            cano: self.cano().to_string(),
+            acnt_prdt_cd: self.acnt_prdt_cd().to_string(),
+            pdno: symbol.to_string(),
+            ord_qty: quantity.to_string(),
+            ord_unpr: price.to_string(),
+            sll_buy_dvsn_cd: match side {
+                OrderSide::Sell => "01".to_string(),
+                OrderSide::Buy => "02".to_string(),
+            },
+            ord_dvsn_cd: order_type.code().to_string(),
+            ord_objt_cblc_dvsn_cd: "10".to_string(), // 현금
+            loan_dt: "".to_string(),
+            rsvn_ord_end_dt: period.end_date().to_string(),
+        };
+
+        let tr_id = "CTSC0008U";
+
+        let response = self.post_order(
+            "/uapi/domestic-stock/v1/trading/order-resv",
+            tr_id,
+            &request,
+        )?;
+
+        let api_response: KisApiResponse<ReservationOrderResponse> = response
             .json()
-            .map_err(|e| KisError::Parse(format!("Failed to parse cancel response: {}", e)))?;
+            .map_err(|e| KisError::Parse(format!("Failed to parse reservation response: {}", e)))?;
 
         if !api_response.is_success() {
             return Err(KisError::Api {
@@ -442,7 +1383,94 @@ impl KisClient {
 
         api_response
             .output
-            .ok_or_else(|| KisError::Parse("No output in cancel response".to_string()))
+            .ok_or_else(|| KisError::Parse("No output in reservation response".to_string()))
+    }
+
+    /// 주식예약주문 취소 (실전 전용)
+    ///
+    /// # Arguments
+    /// * `reservation_seq` - 예약주문순번
+    pub fn domestic_cancel_reservation(
+        &mut self,
+        reservation_seq: &str,
+    ) -> KisResult<ReservationOrderResponse> {
+        if self.config.is_paper {
+            return Err(KisError::Validation(
+                "Reservation orders are not available in paper trading".to_string(),
+            ));
+        }
+        self.ensure_auth()?;
+
+        let request = ReservationCancelRequest {
+            cano: self.cano().to_string(),
+            acnt_prdt_cd: self.acnt_prdt_cd().to_string(),
+            rsvn_ord_seq: reservation_seq.to_string(),
+            rsvn_ord_orgno: "".to_string(),
+            rsvn_ord_ord_dt: "".to_string(),
+        };
+
+        let tr_id = "CTSC0009U"; // 예약주문 취소
+
+        let response = self.post_order(
+            "/uapi/domestic-stock/v1/trading/order-resv-rvsecncl",
+            tr_id,
+            &request,
+        )?;
+
+        let api_response: KisApiResponse<ReservationOrderResponse> =
+            response.json().map_err(|e| {
+                KisError::Parse(format!(
+                    "Failed to parse reservation cancel response: {}",
+                    e
+                ))
+            })?;
+
+        if !api_response.is_success() {
+            return Err(KisError::Api {
+                code: api_response.msg_cd,
+                message: api_response.msg1,
+            });
+        }
+
+        api_response
+            .output
+            .ok_or_else(|| KisError::Parse("No output in reservation cancel response".to_string()))
+    }
+
+    /// 주식예약주문 조회 (실전 전용)
+    ///
+    /// # Arguments
+    /// * `start_date` - 조회시작일자 (YYYYMMDD)
+    /// * `end_date` - 조회종료일자 (YYYYMMDD)
+    pub fn domestic_reservation_list(
+        &mut self,
+        start_date: &str,
+        end_date: &str,
+    ) -> KisResult<Vec<ReservationOrderItem>> {
+        if self.config.is_paper {
+            return Err(KisError::Validation(
+                "Reservation orders are not available in paper trading".to_string(),
+            ));
+        }
+        self.ensure_auth()?;
+
+        let tr_id = "CTSC0004R";
+
+        let query = format!(
+            "RSVN_ORD_ORD_DT={}&RSVN_ORD_END_DT={}&RSVN_ORD_SEQ=&TMNL_MDIA_KIND_CD=00&CANO={}&ACNT_PRDT_CD={}&PRCS_DVSN_CD=0&CNCL_YN=Y&PDNO=&SLL_BUY_DVSN_CD=&CTX_AREA_FK200=&CTX_AREA_NK200=",
+            start_date,
+            end_date,
+            self.cano(),
+            self.acnt_prdt_cd()
+        );
+
+        self.get_list(
+            "/uapi/domestic-stock/v1/trading/order-resv-ccnl",
+            tr_id,
+            &query,
+            "reservation list response",
+            |r: KisApiResponse<Vec<ReservationOrderItem>>| r.output,
+        )
     }
 
     /// 잔고 조회
@@ -493,25 +1521,227 @@ impl KisClient {
         let items = api_response.output1.unwrap_or_default();
         let summary = api_response
             .output2
-            .and_then(|mut v| {
-                if v.is_empty() {
-                    None
-                } else {
-                    Some(v.remove(0))
-                }
-            })
-            .unwrap_or_else(|| BalanceSummary {
-                tot_evlu_amt: None,
-                dnca_tot_amt: None,
-                ord_psbl_cash: None,
-                evlu_pfls_smtl_amt: None,
-                evlu_pfls_rt: None,
-            });
+            .and_then(|v| v.into_iter().next())
+            .unwrap_or_default();
 
         Ok((items, summary))
     }
 
-    /// 현재가 조회
+    /// 신용잔고 조회
+    ///
+    /// 잔고조회 결과 중 대출일자가 있는 융자 잔고만 반환합니다.
+    /// 상환 주문 시 `loan_dt`를 대출일자로 사용합니다.
+    pub fn domestic_credit_balance(&mut self) -> KisResult<Vec<BalanceItem>> {
+        let (items, _summary) = self.domestic_balance()?;
+        Ok(items.into_iter().filter(|item| item.is_credit()).collect())
+    }
+
+    /// 매수가능조회
+    ///
+    /// # Arguments
+    /// * `symbol` - 종목코드 (예: "005930")
+    /// * `price` - 주문단가 (시장가일 경우 0)
+    /// * `order_type` - 주문구분
+    pub fn domestic_buying_power(
+        &mut self,
+        symbol: &str,
+        price: u32,
+        order_type: OrderType,
+    ) -> KisResult<BuyingPowerResponse> {
+        self.ensure_auth()?;
+
+        let tr_id = if self.config.is_paper {
+            "VTTC8908R" // 모의투자
+        } else {
+            "TTTC8908R" // 실전투자
+        };
+
+        let query = format!(
+            "CANO={}&ACNT_PRDT_CD={}&PDNO={}&ORD_UNPR={}&ORD_DVSN={}&CMA_EVLU_AMT_ICLD_YN=N&OVRS_ICLD_YN=N",
+            self.cano(),
+            self.acnt_prdt_cd(),
+            symbol,
+            price,
+            order_type.code()
+        );
+
+        let response = self.get(
+            "/uapi/domestic-stock/v1/trading/inquire-psbl-order",
+            tr_id,
+            Some(&query),
+        )?;
+
+        let api_response: KisApiResponse<BuyingPowerResponse> = response.json().map_err(|e| {
+            KisError::Parse(format!("Failed to parse buying power response: {}", e))
+        })?;
+
+        if !api_response.is_success() {
+            return Err(KisError::Api {
+                code: api_response.msg_cd,
+                message: api_response.msg1,
+            });
+        }
+
+        api_response
+            .output
+            .ok_or_else(|| KisError::Parse("No output in buying power response".to_string()))
+    }
+
+    /// 매도가능수량조회
+    ///
+    /// 모의투자는 해당 API를 지원하지 않으므로 잔고조회의 주문가능수량을 사용합니다.
+    ///
+    /// # Arguments
+    /// * `symbol` - 종목코드 (예: "005930")
+    pub fn domestic_sellable_quantity(&mut self, symbol: &str) -> KisResult<u64> {
+        self.ensure_auth()?;
+
+        if self.config.is_paper {
+            let (items, _summary) = self.domestic_balance()?;
+            return Ok(items
+                .iter()
+                .find(|item| item.pdno.as_deref() == Some(symbol))
+                .and_then(|item| item.ord_psbl_qty.get().or(item.hldg_qty.get()))
+                .map(|q| q.trunc().max(0) as u64)
+                .unwrap_or(0));
+        }
+
+        let query = format!(
+            "CANO={}&ACNT_PRDT_CD={}&PDNO={}",
+            self.cano(),
+            self.acnt_prdt_cd(),
+            symbol
+        );
+
+        let response = self.get(
+            "/uapi/domestic-stock/v1/trading/inquire-psbl-sell",
+            "TTTC8408R",
+            Some(&query),
+        )?;
+
+        let api_response: KisApiResponse<SellableQuantityResponse> =
+            response.json().map_err(|e| {
+                KisError::Parse(format!("Failed to parse sellable quantity response: {}", e))
+            })?;
+
+        if !api_response.is_success() {
+            return Err(KisError::Api {
+                code: api_response.msg_cd,
+                message: api_response.msg1,
+            });
+        }
+
+        Ok(api_response.output.and_then(|o| o.quantity()).unwrap_or(0))
+    }
+
+    /// 주문 전 수량 검증
+    ///
+    /// 매수는 미수없는매수수량, 매도는 매도가능수량을 초과하면
+    /// `KisError::Validation`을 반환합니다.
+    pub fn domestic_check_order_quantity(
+        &mut self,
+        symbol: &str,
+        side: OrderSide,
+        quantity: u32,
+        price: u32,
+        order_type: OrderType,
+    ) -> KisResult<()> {
+        match side {
+            OrderSide::Buy => {
+                let buying_power = self.domestic_buying_power(symbol, price, order_type)?;
+                let max_quantity = buying_power.cash_quantity().ok_or_else(|| {
+                    KisError::Parse("No orderable quantity in buying power response".to_string())
+                })?;
+
+                if u64::from(quantity) > max_quantity {
+                    return Err(KisError::Validation(format!(
+                        "Insufficient buying power for {}: requested {}, max orderable {} ({} KRW available)",
+                        symbol,
+                        quantity,
+                        max_quantity,
+                        buying_power.ord_psbl_cash.or_zero()
+                    )));
+                }
+            }
+            OrderSide::Sell => {
+                let sellable = self.domestic_sellable_quantity(symbol)?;
+
+                if u64::from(quantity) > sellable {
+                    return Err(KisError::Validation(format!(
+                        "Insufficient sellable quantity for {}: requested {}, sellable {}",
+                        symbol, quantity, sellable
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 주문가격 검증 (호가단위, 상/하한가)
+    ///
+    /// `round`가 true이면 호가단위에 맞지 않는 가격을 가장 가까운 호가로 보정하고,
+    /// false이면 `KisError::Validation`을 반환합니다. 상/하한가를 벗어나면 항상 거부합니다.
+    ///
+    /// # Arguments
+    /// * `symbol` - 종목코드 (예: "005930")
+    /// * `price` - 주문단가
+    /// * `market` - 호가단위 시장구분 (None이면 현재가 응답의 대표시장으로 추정)
+    /// * `round` - 호가단위 자동 보정 여부
+    ///
+    /// # Returns
+    /// 전송할 주문단가
+    pub fn domestic_check_price(
+        &mut self,
+        symbol: &str,
+        price: u32,
+        market: Option<KrxMarket>,
+        round: bool,
+    ) -> KisResult<u32> {
+        let quote = self.domestic_current_price(symbol)?;
+        let market = market.unwrap_or_else(|| quote.market());
+        let price = snap_to_tick(symbol, price, market, round)?;
+
+        if let Some((lower, upper)) = quote.price_limits(market) {
+            if price < lower || price > upper {
+                return Err(KisError::Validation(format!(
+                    "Price {} for {} is outside the daily limit ({} ~ {})",
+                    price, symbol, lower, upper
+                )));
+            }
+        }
+
+        Ok(price)
+    }
+
+    /// 주문가격 호가단위 검증 (상/하한가 미검사)
+    ///
+    /// 예약주문은 다음 영업일 기준가로 가격제한폭이 정해지므로 오늘 상/하한가로 검사하지 않습니다.
+    /// `market`이 None일 때만 현재가를 조회해 대표시장을 추정합니다.
+    ///
+    /// # Arguments
+    /// * `symbol` - 종목코드 (예: "005930")
+    /// * `price` - 주문단가
+    /// * `market` - 호가단위 시장구분
+    /// * `round` - 호가단위 자동 보정 여부
+    ///
+    /// # Returns
+    /// 전송할 주문단가
+    pub fn domestic_check_tick(
+        &mut self,
+        symbol: &str,
+        price: u32,
+        market: Option<KrxMarket>,
+        round: bool,
+    ) -> KisResult<u32> {
+        let market = match market {
+            Some(market) => market,
+            None => self.domestic_current_price(symbol)?.market(),
+        };
+        snap_to_tick(symbol, price, market, round)
+    }
+
+    /// 현재가 조회 (KRX)
     ///
     /// # Arguments
     /// * `symbol` - 종목코드 (예: "005930")
@@ -519,11 +1749,28 @@ impl KisClient {
     /// # Returns
     /// 현재가 정보
     pub fn domestic_current_price(&mut self, symbol: &str) -> KisResult<CurrentPriceResponse> {
+        self.domestic_current_price_on(symbol, DomesticExchange::Krx)
+    }
+
+    /// 현재가 조회 (거래소 지정)
+    ///
+    /// # Arguments
+    /// * `symbol` - 종목코드 (예: "005930")
+    /// * `exchange` - KRX, NXT 또는 SOR(통합시세)
+    pub fn domestic_current_price_on(
+        &mut self,
+        symbol: &str,
+        exchange: DomesticExchange,
+    ) -> KisResult<CurrentPriceResponse> {
         self.ensure_auth()?;
 
         let tr_id = "FHKST01010100";
 
-        let query = format!("FID_COND_MRKT_DIV_CODE=J&FID_INPUT_ISCD={}", symbol);
+        let query = format!(
+            "FID_COND_MRKT_DIV_CODE={}&FID_INPUT_ISCD={}",
+            exchange.market_div_code(),
+            symbol
+        );
 
         let response = self.get(
             "/uapi/domestic-stock/v1/quotations/inquire-price",
@@ -546,11 +1793,181 @@ impl KisClient {
             .output
             .ok_or_else(|| KisError::Parse("No output in price response".to_string()))
     }
+
+    /// 관심종목 복수시세 조회
+    ///
+    /// 30종목씩 나누어 조회합니다. 모의투자는 해당 API를 지원하지 않으므로
+    /// 종목별 현재가 조회로 대체합니다.
+    ///
+    /// # Arguments
+    /// * `symbols` - (종목코드, 시세 시장) 목록
+    pub fn domestic_multi_price(
+        &mut self,
+        symbols: &[(&str, DomesticExchange)],
+    ) -> KisResult<Vec<MultiPriceItem>> {
+        self.ensure_auth()?;
+
+        if self.config.is_paper {
+            let mut items = Vec::with_capacity(symbols.len());
+            for (symbol, exchange) in symbols {
+                let quote = self.domestic_current_price_on(symbol, *exchange)?;
+                items.push(MultiPriceItem::from_current_price(symbol, &quote));
+            }
+            return Ok(items);
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct MultiPriceApiResponse {
+            rt_cd: String,
+            msg_cd: String,
+            msg1: String,
+            output: Option<Vec<MultiPriceItem>>,
+        }
+
+        let mut items = Vec::with_capacity(symbols.len());
+        for chunk in symbols.chunks(MULTI_PRICE_MAX_SYMBOLS) {
+            let query = multi_price_query(chunk);
+
+            let response = self.get(
+                "/uapi/domestic-stock/v1/quotations/intstock-multprice",
+                "FHKST11300006",
+                Some(&query),
+            )?;
+
+            let api_response: MultiPriceApiResponse = response.json().map_err(|e| {
+                KisError::Parse(format!("Failed to parse multi price response: {}", e))
+            })?;
+
+            if api_response.rt_cd != "0" {
+                return Err(KisError::Api {
+                    code: api_response.msg_cd,
+                    message: api_response.msg1,
+                });
+            }
+
+            items.extend(api_response.output.unwrap_or_default());
+        }
+
+        Ok(items)
+    }
+
+    /// 주식기본조회 (실전투자 전용)
+    ///
+    /// 종목코드로 시장, 증권그룹, 거래정지 여부를 조회합니다. 없는 종목이면 `Ok(None)`입니다.
+    pub fn domestic_search_stock_info(&mut self, symbol: &str) -> KisResult<Option<StockInfo>> {
+        if self.config.is_paper {
+            return Err(KisError::Validation(
+                "Stock info lookup is not supported in paper trading".to_string(),
+            ));
+        }
+        self.ensure_auth()?;
+
+        let query = format!("PRDT_TYPE_CD=300&PDNO={}", symbol);
+        let response = self.get(
+            "/uapi/domestic-stock/v1/quotations/search-stock-info",
+            "CTPF1002R",
+            Some(&query),
+        )?;
+
+        let api_response: KisApiResponse<StockInfo> = response
+            .json()
+            .map_err(|e| KisError::Parse(format!("Failed to parse stock info response: {}", e)))?;
+
+        if !api_response.is_success() {
+            return Err(KisError::Api {
+                code: api_response.msg_cd,
+                message: api_response.msg1,
+            });
+        }
+
+        Ok(api_response.output.filter(|info| {
+            info.prdt_abrv_name
+                .as_deref()
+                .is_some_and(|n| !n.is_empty())
+        }))
+    }
+
+    /// 상품기본조회 (실전투자 전용)
+    ///
+    /// # Arguments
+    /// * `pdno` - 상품번호 (종목코드, 티커)
+    /// * `prdt_type_cd` - 상품유형코드 (300 국내주식, 512 나스닥 등 [`Exchange::product_type_code`])
+    ///
+    /// [`Exchange::product_type_code`]: super::types::Exchange::product_type_code
+    pub fn search_info(
+        &mut self,
+        pdno: &str,
+        prdt_type_cd: &str,
+    ) -> KisResult<Option<ProductInfo>> {
+        if self.config.is_paper {
+            return Err(KisError::Validation(
+                "Product info lookup is not supported in paper trading".to_string(),
+            ));
+        }
+        self.ensure_auth()?;
+
+        let query = format!("PDNO={}&PRDT_TYPE_CD={}", pdno, prdt_type_cd);
+        let response = self.get(
+            "/uapi/domestic-stock/v1/quotations/search-info",
+            "CTPF1604R",
+            Some(&query),
+        )?;
+
+        let api_response: KisApiResponse<ProductInfo> = response.json().map_err(|e| {
+            KisError::Parse(format!("Failed to parse product info response: {}", e))
+        })?;
+
+        if !api_response.is_success() {
+            return Err(KisError::Api {
+                code: api_response.msg_cd,
+                message: api_response.msg1,
+            });
+        }
+
+        Ok(api_response
+            .output
+            .filter(|info| info.prdt_name.as_deref().is_some_and(|n| !n.is_empty())))
+    }
+}
+
+/// Build the intstock-multprice query for one chunk of symbols
+fn multi_price_query(symbols: &[(&str, DomesticExchange)]) -> String {
+    symbols
+        .iter()
+        .enumerate()
+        .map(|(i, (symbol, exchange))| {
+            format!(
+                "FID_COND_MRKT_DIV_CODE_{n}={}&FID_INPUT_ISCD_{n}={}",
+                exchange.market_div_code(),
+                symbol,
+                n = i + 1
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Keep an on-tick price, snap it to the nearest tick when `round` is set, or reject it
+fn snap_to_tick(symbol: &str, price: u32, market: KrxMarket, round: bool) -> KisResult<u32> {
+    if market.is_on_tick(price) {
+        Ok(price)
+    } else if round {
+        Ok(market.round_to_tick(price))
+    } else {
+        Err(KisError::Validation(format!(
+            "Price {} for {} is not on a valid tick ({} KRW for {:?})",
+            price,
+            symbol,
+            market.tick_size(price),
+            market
+        )))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::kis::types::{KrxSession, NxtSession, TimeInForce};
 
     #[test]
     fn test_cash_order_request_serialization() {
@@ -561,11 +1978,266 @@ mod tests {
             ord_dvsn: "00".to_string(),
             ord_qty: "10".to_string(),
             ord_unpr: "70000".to_string(),
+            excg_id_dvsn_cd: DomesticExchange::Nxt.code().to_string(),
         };
 
         let json = serde_json::to_string(&request).unwrap();
         assert!(json.contains("\"CANO\":\"12345678\""));
         assert!(json.contains("\"PDNO\":\"005930\""));
+        assert!(json.contains("\"EXCG_ID_DVSN_CD\":\"NXT\""));
+    }
+
+    #[test]
+    fn test_domestic_exchange_sessions() {
+        use chrono::NaiveDate;
+
+        let at = |h, m| {
+            NaiveDate::from_ymd_opt(2025, 3, 5) // 수요일
+                .unwrap()
+                .and_hms_opt(h, m, 0)
+                .unwrap()
+        };
+
+        assert_eq!(
+            DomesticExchange::from_code("nxt"),
+            Some(DomesticExchange::Nxt)
+        );
+        assert_eq!(DomesticExchange::Sor.market_div_code(), "UN");
+
+        assert_eq!(NxtSession::at(at(8, 10)), Some(NxtSession::PreMarket));
+        assert_eq!(NxtSession::at(at(8, 55)), None);
+        assert_eq!(NxtSession::at(at(15, 25)), None);
+        assert_eq!(NxtSession::at(at(19, 59)), Some(NxtSession::AfterMarket));
+
+        // SOR falls back to NXT outside the main market
+        assert_eq!(
+            DomesticExchange::Sor.resolve_at(at(16, 0)),
+            DomesticExchange::Nxt
+        );
+        assert_eq!(
+            DomesticExchange::Sor.resolve_at(at(10, 0)),
+            DomesticExchange::Sor
+        );
+
+        let nxt = DomesticExchange::Nxt;
+        assert!(nxt.validate_order(OrderType::Market, at(10, 0)).is_ok());
+        assert!(nxt.validate_order(OrderType::Limit, at(16, 0)).is_ok());
+        assert!(nxt.validate_order(OrderType::Market, at(16, 0)).is_err());
+        assert!(nxt
+            .validate_order(OrderType::AfterMarket, at(16, 0))
+            .is_err());
+        assert!(nxt.validate_order(OrderType::Limit, at(21, 0)).is_err());
+        assert!(nxt
+            .validate_order(OrderType::AfterHoursSinglePrice, at(16, 30))
+            .is_err());
+        assert!(DomesticExchange::Krx
+            .validate_order(OrderType::AfterMarket, at(15, 45))
+            .is_ok());
+        assert!(DomesticExchange::Krx
+            .validate_order(OrderType::AfterMarket, at(16, 0))
+            .is_err());
+    }
+
+    #[test]
+    fn test_order_quantity_parsing() {
+        use crate::kis::field::FieldReport;
+
+        let json = r#"{
+            "ord_psbl_cash": "1500000",
+            "nrcvb_buy_qty": "000021",
+            "max_buy_qty": "000070"
+        }"#;
+        let buying_power: BuyingPowerResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(buying_power.cash_quantity(), Some(21));
+
+        let json = r#"{ "pdno": "005930", "ord_psbl_qty": "10.0000" }"#;
+        let sellable: SellableQuantityResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(sellable.quantity(), Some(10));
+
+        assert_eq!(whole_quantity(&KisField::parse("")), None);
+        assert!(sellable.invalid_fields().is_empty());
+
+        // 파싱 실패는 None으로 남고 필드명과 원본값이 기록됨
+        let json = r#"{ "ord_psbl_cash": "1500000", "nrcvb_buy_qty": "2l" }"#;
+        let buying_power: BuyingPowerResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(buying_power.cash_quantity(), None);
+        assert_eq!(
+            buying_power.ord_psbl_cash.get(),
+            Some(Amount::from_int(1_500_000))
+        );
+        assert_eq!(
+            buying_power.invalid_fields(),
+            vec![("nrcvb_buy_qty", "2l".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_credit_type() {
+        assert_eq!(CreditType::OwnLoanNew.code(), "21");
+        assert_eq!(
+            CreditType::from_code("27"),
+            Some(CreditType::MarginLoanRepay)
+        );
+        assert_eq!(CreditType::from_code("22"), None);
+        assert_eq!(CreditType::MarginLoanNew.side(), OrderSide::Buy);
+        assert!(CreditType::OwnLoanRepay.is_repayment());
+        assert_eq!(
+            CreditType::default_for(OrderSide::Sell),
+            CreditType::OwnLoanRepay
+        );
+
+        let json = r#"{ "pdno": "005930", "trad_dvsn_name": "자기융자", "loan_dt": "20250102" }"#;
+        let item: BalanceItem = serde_json::from_str(json).unwrap();
+        assert!(item.is_credit());
+
+        let json = r#"{ "pdno": "005930", "trad_dvsn_name": "현금", "loan_dt": "" }"#;
+        let item: BalanceItem = serde_json::from_str(json).unwrap();
+        assert!(!item.is_credit());
+    }
+
+    #[test]
+    fn test_balance_item_lenient_parsing() {
+        use crate::kis::field::FieldReport;
+
+        let json = r#"{
+            "pdno": "005930",
+            "hldg_qty": "0000000010",
+            "pchs_avg_pric": "71250.5000",
+            "prpr": "",
+            "evlu_pfls_amt": "-12500",
+            "evlu_pfls_rt": "-1.75",
+            "evlu_amt": "7O0000"
+        }"#;
+        let item: BalanceItem = serde_json::from_str(json).unwrap();
+        assert_eq!(item.hldg_qty.get(), Some(Quantity::from_int(10)));
+        assert_eq!(item.pchs_avg_pric.get(), "71250.5".parse().ok());
+        assert!(item.prpr.is_blank());
+        assert_eq!(item.evlu_pfls_amt.get(), Some(Amount::from_int(-12500)));
+        assert!(item.ord_psbl_qty.is_blank());
+
+        assert_eq!(
+            item.invalid_fields(),
+            vec![("evlu_amt", "7O0000".to_string())]
+        );
+        assert!(item.check_strict().is_err());
+    }
+
+    #[test]
+    fn test_multi_price_query_and_item() {
+        let symbols: Vec<(&str, DomesticExchange)> = vec![
+            ("005930", DomesticExchange::Krx),
+            ("000660", DomesticExchange::Nxt),
+        ];
+        assert_eq!(
+            multi_price_query(&symbols),
+            "FID_COND_MRKT_DIV_CODE_1=J&FID_INPUT_ISCD_1=005930&FID_COND_MRKT_DIV_CODE_2=NX&FID_INPUT_ISCD_2=000660"
+        );
+
+        let many: Vec<(&str, DomesticExchange)> = vec![("005930", DomesticExchange::Krx); 65];
+        let chunks: Vec<usize> = many
+            .chunks(MULTI_PRICE_MAX_SYMBOLS)
+            .map(|c| c.len())
+            .collect();
+        assert_eq!(chunks, vec![30, 30, 5]);
+
+        let json = r#"{
+            "inter_shrn_iscd": "005930",
+            "inter_kor_isnm": "삼성전자",
+            "inter2_prpr": "71500",
+            "inter2_prdy_vrss": "-500",
+            "prdy_ctrt": "-0.69",
+            "acml_vol": "12345678"
+        }"#;
+        let item: MultiPriceItem = serde_json::from_str(json).unwrap();
+        assert_eq!(item.inter2_prpr.get(), Some(Price::from_int(71500)));
+        assert_eq!(item.inter2_prdy_vrss.get(), Some(Price::from_int(-500)));
+        assert!(item.inter2_oprc.is_blank());
+    }
+
+    #[test]
+    fn test_reservation_period() {
+        assert_eq!(ReservationPeriod::NextDay.end_date(), "");
+        assert!(ReservationPeriod::NextDay.validate().is_ok());
+
+        let period = ReservationPeriod::Until("20250131".to_string());
+        assert_eq!(period.end_date(), "20250131");
+        assert!(period.validate().is_ok());
+
+        assert!(ReservationPeriod::Until("2025-01-31".to_string())
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_reservation_item_state() {
+        let items: Vec<ReservationOrderItem> = serde_json::from_str(
+            r#"[
+                {"rsvn_ord_seq":"123","pdno":"005930","odno":"","cncl_ord_dt":""},
+                {"rsvn_ord_seq":"124","pdno":"000660","odno":"","cncl_ord_dt":"20250304"},
+                {"rsvn_ord_seq":" ","pdno":"035720","odno":"0000123456"}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(items[0].reservation_id().as_deref(), Some("rsvn_123"));
+        assert!(items[0].is_pending());
+        assert!(items[1].is_cancelled() && !items[1].is_pending());
+        assert_eq!(items[2].reservation_id(), None);
+        assert!(!items[2].is_cancelled() && !items[2].is_pending());
+    }
+
+    #[test]
+    fn test_reservation_list_and_cancel() {
+        use crate::http::mock;
+        use crate::kis::field::FieldReport;
+
+        let mut client = crate::kis::tests::test_client(false);
+        mock::respond(
+            r#"{"rt_cd":"0","msg_cd":"","msg1":"","output":[
+                {"rsvn_ord_seq":"123","rsvn_ord_ord_dt":"20250305","pdno":"005930",
+                 "sll_buy_dvsn_cd":"02","ord_dvsn_cd":"00","ord_rsvn_qty":"10",
+                 "ord_rsvn_unpr":"70000","tot_ccld_qty":"0","odno":"","cncl_ord_dt":"",
+                 "rsvn_end_dt":"20250307"},
+                {"rsvn_ord_seq":"124","pdno":"000660","ord_rsvn_qty":"1O",
+                 "odno":"","cncl_ord_dt":"20250304"}
+            ]}"#,
+        );
+        let items = client
+            .domestic_reservation_list("20250301", "20250331")
+            .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].reservation_id().as_deref(), Some("rsvn_123"));
+        assert_eq!(items[0].ord_rsvn_qty.get(), Some(Quantity::from_int(10)));
+        assert_eq!(items[0].ord_rsvn_unpr.get(), Some(Price::from_int(70_000)));
+        assert_eq!(
+            items[0].rsvn_end_dt.get(),
+            NaiveDate::from_ymd_opt(2025, 3, 7)
+        );
+        assert!(items[0].is_pending());
+        assert!(items[1].is_cancelled() && !items[1].is_pending());
+        assert_eq!(items[1].invalid_fields().len(), 1);
+
+        mock::respond(r#"{"HASH":"hash"}"#);
+        mock::respond(r#"{"rt_cd":"0","msg_cd":"","msg1":"","output":{"RSVN_ORD_SEQ":"123"}}"#);
+        client.domestic_cancel_reservation("123").unwrap();
+
+        let requests = mock::requests();
+        assert!(requests[0].url.contains("RSVN_ORD_ORD_DT=20250301"));
+        assert_eq!(requests[0].headers["tr_id"], "CTSC0004R");
+        assert!(requests[2].url.ends_with("/trading/order-resv-rvsecncl"));
+        assert_eq!(requests[2].headers["tr_id"], "CTSC0009U");
+        assert!(requests[2]
+            .body
+            .as_deref()
+            .unwrap()
+            .contains(r#""RSVN_ORD_SEQ":"123""#));
+
+        let mut paper = crate::kis::tests::test_client(true);
+        assert!(matches!(
+            paper.domestic_reservation_list("20250301", "20250331"),
+            Err(KisError::Validation(_))
+        ));
+        assert!(paper.domestic_cancel_reservation("123").is_err());
+        assert_eq!(mock::requests().len(), 3);
     }
 
     #[test]
@@ -577,5 +2249,112 @@ mod tests {
         assert_eq!(OrderType::PriorityLimit.code(), "04");
         assert_eq!(OrderType::PreMarket.code(), "05");
         assert_eq!(OrderType::AfterMarket.code(), "06");
+        assert_eq!(OrderType::AfterHoursSinglePrice.code(), "07");
+        assert_eq!(OrderType::IocLimit.code(), "11");
+        assert_eq!(OrderType::FokBestLimit.code(), "16");
+        assert_eq!(OrderType::from_code("14"), Some(OrderType::FokMarket));
+        assert_eq!(OrderType::from_code("08"), None);
+    }
+
+    #[test]
+    fn test_tick_size_and_price_limits() {
+        let kospi = KrxMarket::Kospi;
+        assert_eq!(kospi.tick_size(1_999), 1);
+        assert_eq!(kospi.tick_size(2_000), 5);
+        assert_eq!(kospi.tick_size(70_000), 100);
+        assert_eq!(kospi.tick_size(500_000), 1_000);
+        assert_eq!(KrxMarket::EtfEtn.tick_size(1_999), 1);
+        assert_eq!(KrxMarket::EtfEtn.tick_size(2_000), 5);
+        assert_eq!(KrxMarket::EtfEtn.tick_size(35_000), 5);
+        assert_eq!(KrxMarket::EtfEtn.round_to_tick(1_997), 1_997);
+        assert_eq!(KrxMarket::EtfEtn.round_to_tick(35_003), 35_005);
+        assert_eq!(KrxMarket::Elw.tick_size(35), 5);
+        assert_eq!(KrxMarket::Elw.round_to_tick(1_998), 2_000);
+
+        assert!(kospi.is_on_tick(70_100));
+        assert!(!kospi.is_on_tick(70_150));
+        assert_eq!(kospi.round_to_tick(70_150), 70_200);
+        assert_eq!(kospi.round_to_tick(70_149), 70_100);
+        assert_eq!(kospi.round_to_tick(4_998), 5_000);
+        assert_eq!(snap_to_tick("005930", 70_150, kospi, true).unwrap(), 70_200);
+        assert!(matches!(
+            snap_to_tick("005930", 70_150, kospi, false),
+            Err(KisError::Validation(_))
+        ));
+
+        // 기준가 10,000원: 상한 13,000 / 하한 7,000
+        assert_eq!(kospi.price_limits(10_000), (7_000, 13_000));
+        // 기준가 71,500원: 상한 92,950 → 92,900, 하한 50,050 → 50,100
+        assert_eq!(kospi.price_limits(71_500), (50_100, 92_900));
+        assert_eq!(KrxMarket::Konex.price_limits(10_000), (8_500, 11_500));
+
+        let json = r#"{
+            "stck_prpr": "71500",
+            "stck_mxpr": "92900",
+            "stck_llam": "50100",
+            "rprs_mrkt_kor_name": "KOSPI200"
+        }"#;
+        let quote: CurrentPriceResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(quote.market(), KrxMarket::Kospi);
+        assert_eq!(quote.price_limits(quote.market()), Some((50_100, 92_900)));
+
+        let json = r#"{ "stck_sdpr": "10000", "rprs_mrkt_kor_name": "KSQ150" }"#;
+        let quote: CurrentPriceResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(quote.market(), KrxMarket::Kosdaq);
+        assert_eq!(quote.price_limits(quote.market()), Some((7_000, 13_000)));
+    }
+
+    #[test]
+    fn test_time_in_force_and_sessions() {
+        use chrono::NaiveDate;
+
+        let at = |h, m| {
+            NaiveDate::from_ymd_opt(2025, 3, 5)
+                .unwrap()
+                .and_hms_opt(h, m, 0)
+                .unwrap()
+        };
+
+        assert_eq!(
+            OrderType::Limit
+                .with_time_in_force(TimeInForce::Ioc)
+                .unwrap(),
+            OrderType::IocLimit
+        );
+        assert_eq!(
+            OrderType::Market
+                .with_time_in_force(TimeInForce::Fok)
+                .unwrap(),
+            OrderType::FokMarket
+        );
+        assert_eq!(
+            OrderType::PreMarket
+                .with_time_in_force(TimeInForce::Day)
+                .unwrap(),
+            OrderType::PreMarket
+        );
+        assert!(OrderType::AfterMarket
+            .with_time_in_force(TimeInForce::Ioc)
+            .is_err());
+        assert_eq!(TimeInForce::from_code("FOK"), Some(TimeInForce::Fok));
+
+        assert_eq!(KrxSession::at(at(8, 35)), Some(KrxSession::PreMarketClose));
+        assert_eq!(KrxSession::at(at(15, 35)), None);
+        assert_eq!(
+            KrxSession::at(at(17, 0)),
+            Some(KrxSession::AfterHoursSinglePrice)
+        );
+
+        assert!(OrderType::PreMarket.validate_krx_session(at(8, 35)).is_ok());
+        assert!(OrderType::Limit.validate_krx_session(at(8, 50)).is_ok());
+        assert!(OrderType::IocLimit.validate_krx_session(at(8, 50)).is_err());
+        assert!(OrderType::IocLimit.validate_krx_session(at(10, 0)).is_ok());
+        assert!(OrderType::BestLimit
+            .validate_krx_session(at(15, 25))
+            .is_err());
+        assert!(OrderType::AfterHoursSinglePrice
+            .validate_krx_session(at(16, 10))
+            .is_ok());
+        assert!(OrderType::Limit.validate_krx_session(at(16, 10)).is_err());
     }
 }
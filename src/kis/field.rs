@@ -0,0 +1,255 @@
+//! Typed parsing of KIS numeric/date string fields
+//!
+//! KIS 응답은 숫자와 날짜를 문자열로 내려주며, 공백("")이나 앞자리 0("000021"),
+//! 부호("+1.50", "-300")가 섞여 있습니다. [`KisField`]는 이런 값을 타입이 있는 값으로
+//! 파싱하되, 실패해도 역직렬화를 중단하지 않고 원본 문자열을 보존합니다(lenient).
+//! 실패한 필드는 [`FieldReport::invalid_fields`]로 확인하고, 엄격하게 처리하려면
+//! [`FieldReport::check_strict`]를 사용합니다.
+
+use super::types::{Amount, KisError, KisResult, Price, Quantity, Rate};
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Value types that can be parsed from a KIS string field
+pub trait KisParse: Sized {
+    /// Parse a trimmed, non-blank KIS value
+    fn parse_kis(value: &str) -> Option<Self>;
+
+    /// Format back to the KIS wire representation
+    fn to_kis(&self) -> String;
+
+    /// Values KIS sends in place of "no value" (e.g. "00000000" for dates)
+    fn is_placeholder(_value: &str) -> bool {
+        false
+    }
+}
+
+macro_rules! kis_parse_fromstr {
+    ($($ty:ty),*) => {
+        $(
+            impl KisParse for $ty {
+                fn parse_kis(value: &str) -> Option<Self> {
+                    value.parse().ok()
+                }
+
+                fn to_kis(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+kis_parse_fromstr!(Price, Quantity, Amount, Rate);
+
+impl KisParse for i64 {
+    fn parse_kis(value: &str) -> Option<Self> {
+        value.strip_prefix('+').unwrap_or(value).parse().ok()
+    }
+
+    fn to_kis(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl KisParse for NaiveDate {
+    /// YYYYMMDD (또는 YYYY-MM-DD)
+    fn parse_kis(value: &str) -> Option<Self> {
+        NaiveDate::parse_from_str(value, "%Y%m%d")
+            .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d"))
+            .ok()
+    }
+
+    fn to_kis(&self) -> String {
+        self.format("%Y%m%d").to_string()
+    }
+
+    fn is_placeholder(value: &str) -> bool {
+        value.bytes().all(|b| b == b'0')
+    }
+}
+
+/// KIS 문자열 필드의 파싱 결과
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum KisField<T> {
+    /// 누락, null 또는 공백 (날짜는 "00000000" 포함)
+    #[default]
+    Blank,
+    /// 파싱 성공
+    Value(T),
+    /// 파싱 실패 (원본 문자열 보존)
+    Invalid(String),
+}
+
+impl<T: KisParse> KisField<T> {
+    /// Parse a raw KIS string
+    pub fn parse(raw: &str) -> Self {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() || T::is_placeholder(trimmed) {
+            return KisField::Blank;
+        }
+        match T::parse_kis(trimmed) {
+            Some(value) => KisField::Value(value),
+            None => KisField::Invalid(raw.to_string()),
+        }
+    }
+}
+
+impl<T> KisField<T> {
+    /// Parsed value, if any
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            KisField::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn is_blank(&self) -> bool {
+        matches!(self, KisField::Blank)
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, KisField::Invalid(_))
+    }
+
+    /// Original string of a field that failed to parse
+    pub fn invalid_raw(&self) -> Option<&str> {
+        match self {
+            KisField::Invalid(raw) => Some(raw.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Strict access: blank is `None`, an unparsable value is an error
+    pub fn strict(&self, name: &str) -> KisResult<Option<&T>> {
+        match self {
+            KisField::Blank => Ok(None),
+            KisField::Value(value) => Ok(Some(value)),
+            KisField::Invalid(raw) => Err(KisError::Parse(format!(
+                "Invalid value for {}: {:?}",
+                name, raw
+            ))),
+        }
+    }
+}
+
+impl<T: Copy> KisField<T> {
+    /// Parsed value (copied), if any
+    pub fn get(&self) -> Option<T> {
+        self.value().copied()
+    }
+}
+
+impl<T: Copy + Default> KisField<T> {
+    /// Parsed value or zero/default for blank and invalid fields
+    pub fn or_zero(&self) -> T {
+        self.get().unwrap_or_default()
+    }
+}
+
+impl<'de, T: KisParse> Deserialize<'de> for KisField<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Str(String),
+            Int(i64),
+            Float(f64),
+        }
+
+        Ok(match Option::<Raw>::deserialize(deserializer)? {
+            None => KisField::Blank,
+            Some(Raw::Str(s)) => KisField::parse(&s),
+            Some(Raw::Int(n)) => KisField::parse(&n.to_string()),
+            Some(Raw::Float(n)) => KisField::parse(&n.to_string()),
+        })
+    }
+}
+
+impl<T: KisParse> Serialize for KisField<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            KisField::Blank => serializer.serialize_str(""),
+            KisField::Value(value) => serializer.serialize_str(&value.to_kis()),
+            KisField::Invalid(raw) => serializer.serialize_str(raw),
+        }
+    }
+}
+
+/// Per-row report of fields that failed to parse
+pub trait FieldReport {
+    /// (필드명, 원본값) 목록
+    fn invalid_fields(&self) -> Vec<(&'static str, String)>;
+
+    /// Strict mode: error if any field failed to parse
+    fn check_strict(&self) -> KisResult<()> {
+        let invalid = self.invalid_fields();
+        if invalid.is_empty() {
+            return Ok(());
+        }
+        Err(KisError::Parse(format!(
+            "Invalid fields: {}",
+            invalid
+                .iter()
+                .map(|(name, raw)| format!("{}={:?}", name, raw))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )))
+    }
+}
+
+/// Implement [`FieldReport`] by listing the struct's `KisField` members
+macro_rules! impl_field_report {
+    ($ty:ty { $($field:ident),* $(,)? }) => {
+        impl $crate::kis::field::FieldReport for $ty {
+            fn invalid_fields(&self) -> Vec<(&'static str, String)> {
+                let mut invalid = Vec::new();
+                $(
+                    if let Some(raw) = self.$field.invalid_raw() {
+                        invalid.push((stringify!($field), raw.to_string()));
+                    }
+                )*
+                invalid
+            }
+        }
+    };
+}
+
+pub(crate) use impl_field_report;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kis_field_parsing() {
+        assert_eq!(
+            KisField::<Quantity>::parse("000021"),
+            KisField::Value(Quantity::from_int(21))
+        );
+        assert_eq!(
+            KisField::<Amount>::parse("-300").get(),
+            Some(Amount::from_int(-300))
+        );
+        assert_eq!(KisField::<Rate>::parse("+1.50").get(), "1.5".parse().ok());
+        assert!(KisField::<Price>::parse("   ").is_blank());
+        assert_eq!(
+            KisField::<Price>::parse("N/A"),
+            KisField::Invalid("N/A".to_string())
+        );
+        assert_eq!(
+            KisField::<NaiveDate>::parse("20250102").get(),
+            NaiveDate::from_ymd_opt(2025, 1, 2)
+        );
+        assert!(KisField::<NaiveDate>::parse("00000000").is_blank());
+
+        let field: KisField<Price> = serde_json::from_str("null").unwrap();
+        assert!(field.is_blank());
+        let field: KisField<Price> = serde_json::from_str("1.25").unwrap();
+        assert_eq!(field.get(), "1.25".parse().ok());
+        assert_eq!(serde_json::to_string(&field).unwrap(), "\"1.25\"");
+
+        assert!(KisField::<Price>::parse("abc").strict("prpr").is_err());
+        assert_eq!(KisField::<Price>::parse("").strict("prpr").unwrap(), None);
+    }
+}
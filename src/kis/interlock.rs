@@ -0,0 +1,261 @@
+//! Live-trading safety interlock
+//!
+//! 실전투자(`is_paper: false`)는 계좌번호에 묶인 arming token이 있어야 초기화되며, 초기화(세션)
+//! 직후 처음 N건의 주문은 통화별 누적 주문금액 상한 안에서만 접수합니다. Arming token은 앱 설정만으로
+//! 만들 수 없도록 app secret과 별도로 보관하는 arming secret을 키로 한 계좌번호의 HMAC-SHA256입니다.
+
+use super::risk::{RiskRule, RiskViolation};
+use super::types::{Amount, KisError, KisResult};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Deserializer};
+use sha2::Sha256;
+use std::collections::HashMap;
+
+/// Arming token for an account (소문자 hex, 64자)
+///
+/// `printf '%s' <계좌번호> | openssl dgst -sha256 -hmac '<secret>'`의 출력과 같습니다.
+pub fn arming_token(account_no: &str, secret: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(account_no.as_bytes());
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Verify the arming token supplied for a live session
+///
+/// # Arguments
+/// * `secret` - arming secret (호스트가 app secret과 별도로 보관, 비어 있으면 거부)
+pub fn verify_arming_token(account_no: &str, secret: &str, token: Option<&str>) -> KisResult<()> {
+    if secret.is_empty() {
+        return Err(KisError::Validation(
+            "Live trading requires arming_secret".to_string(),
+        ));
+    }
+    match token {
+        Some(token) if token_matches(token, &arming_token(account_no, secret)) => Ok(()),
+        Some(_) => Err(KisError::Validation(
+            "arming_token does not match this account".to_string(),
+        )),
+        None => Err(KisError::Validation(
+            "Live trading requires arming_token (HMAC-SHA256 of account_no keyed with the arming secret)"
+                .to_string(),
+        )),
+    }
+}
+
+/// Compare without exiting at the first differing byte
+fn token_matches(token: &str, expected: &str) -> bool {
+    let token = token.trim().to_ascii_lowercase();
+    token.len() == expected.len()
+        && token
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Warm-up limits applied to the first orders of a live session
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LiveGuard {
+    /// 금액 상한을 적용할 세션 초기 주문 수
+    pub first_orders: u32,
+    /// 통화별 누적 주문금액 상한 (설정되지 않은 통화는 초기 주문 동안 거부)
+    #[serde(deserialize_with = "deserialize_amounts")]
+    pub max_notional: HashMap<String, Amount>,
+}
+
+impl Default for LiveGuard {
+    fn default() -> Self {
+        LiveGuard {
+            first_orders: 5,
+            max_notional: HashMap::from([
+                ("KRW".to_string(), Amount::from_int(1_000_000)),
+                ("USD".to_string(), Amount::from_int(1_000)),
+            ]),
+        }
+    }
+}
+
+/// Read `{"KRW": 1000000, "USD": 1000.5}` into fixed-point amounts
+fn deserialize_amounts<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<HashMap<String, Amount>, D::Error> {
+    HashMap::<String, f64>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(currency, max)| match Amount::from_f64(max) {
+            Some(max) => Ok((currency, max)),
+            None => Err(serde::de::Error::custom(format!(
+                "invalid max_notional for {}: {}",
+                currency, max
+            ))),
+        })
+        .collect()
+}
+
+/// Per-session state of the live interlock
+#[derive(Debug, Clone, Default)]
+pub struct LiveInterlock {
+    guard: LiveGuard,
+    /// 세션 중 접수된 주문 수
+    orders: u32,
+    /// 초기 주문 구간의 통화별 누적 주문금액
+    notional: HashMap<String, Amount>,
+}
+
+impl LiveInterlock {
+    pub fn new(guard: LiveGuard) -> Self {
+        LiveInterlock {
+            guard,
+            ..Default::default()
+        }
+    }
+
+    /// Whether the next order is still within the warm-up window
+    pub fn in_warmup(&self) -> bool {
+        self.orders < self.guard.first_orders
+    }
+
+    /// Orders accepted in this session
+    pub fn orders(&self) -> u32 {
+        self.orders
+    }
+
+    fn cap(&self, currency: &str) -> Option<Amount> {
+        self.guard
+            .max_notional
+            .iter()
+            .find(|(c, _)| c.eq_ignore_ascii_case(currency))
+            .map(|(_, max)| *max)
+    }
+
+    fn used(&self, currency: &str) -> Amount {
+        self.notional
+            .get(&currency.to_ascii_uppercase())
+            .copied()
+            .unwrap_or(Amount::ZERO)
+    }
+
+    /// Check an order against the warm-up cap
+    ///
+    /// # Arguments
+    /// * `currency` - 주문 통화
+    /// * `notional` - 주문금액 (산출하지 못했으면 None → 초기 주문 구간에서는 거부)
+    pub fn check(&self, currency: &str, notional: Option<Amount>) -> Result<(), RiskViolation> {
+        if !self.in_warmup() {
+            return Ok(());
+        }
+        let position = self.orders + 1;
+        let window = self.guard.first_orders;
+        let max = self.cap(currency).ok_or_else(|| {
+            RiskViolation::new(
+                RiskRule::LiveSessionNotional,
+                format!(
+                    "no live session cap for {} (order {} of first {})",
+                    currency, position, window
+                ),
+            )
+        })?;
+        let notional = notional.ok_or_else(|| {
+            RiskViolation::new(
+                RiskRule::LiveSessionNotional,
+                format!("cannot price order {} of first {}", position, window),
+            )
+        })?;
+
+        let total = self.used(currency) + notional;
+        if total > max {
+            return Err(RiskViolation::new(
+                RiskRule::LiveSessionNotional,
+                format!(
+                    "session notional {} {} would exceed {} during the first {} orders",
+                    total, currency, max, window
+                ),
+            )
+            .values(max, total));
+        }
+        Ok(())
+    }
+
+    /// Count an accepted order
+    pub fn record(&mut self, currency: &str, notional: Option<Amount>) {
+        if self.in_warmup() {
+            let used = self
+                .notional
+                .entry(currency.to_ascii_uppercase())
+                .or_insert(Amount::ZERO);
+            *used = *used + notional.unwrap_or(Amount::ZERO);
+        }
+        self.orders += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arming_token_is_bound_to_account_and_secret() {
+        // RFC 4231 test case 2 (key "Jefe")
+        assert_eq!(
+            arming_token("what do ya want for nothing?", "Jefe"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+
+        let token = arming_token("1234567801", "secret");
+        assert!(verify_arming_token("1234567801", "secret", Some(&token)).is_ok());
+        assert!(verify_arming_token("1234567801", "secret", Some(&token.to_uppercase())).is_ok());
+        assert!(verify_arming_token("9999999901", "secret", Some(&token)).is_err());
+        assert!(verify_arming_token("1234567801", "other", Some(&token)).is_err());
+        assert!(verify_arming_token("1234567801", "secret", Some("ARM-LIVE-1234567801")).is_err());
+        assert!(verify_arming_token("1234567801", "secret", None).is_err());
+
+        // An empty secret never arms, even with the matching HMAC
+        let unkeyed = arming_token("1234567801", "");
+        assert!(verify_arming_token("1234567801", "", Some(&unkeyed)).is_err());
+    }
+
+    #[test]
+    fn test_warmup_cap_applies_to_first_orders() {
+        let mut interlock = LiveInterlock::new(LiveGuard {
+            first_orders: 2,
+            ..Default::default()
+        });
+
+        let krw = |v: i64| Some(Amount::from_int(v));
+        assert!(interlock.check("KRW", krw(600_000)).is_ok());
+        interlock.record("KRW", krw(600_000));
+        let violation = interlock.check("KRW", krw(500_000)).unwrap_err();
+        assert_eq!(violation.rule, RiskRule::LiveSessionNotional);
+        assert_eq!(violation.actual.as_deref(), Some("1100000"));
+        assert!(interlock.check("KRW", krw(400_000)).is_ok());
+        assert!(interlock.check("usd", Amount::from_f64(999.99)).is_ok());
+        assert!(interlock.check("HKD", krw(1)).is_err());
+        assert!(interlock.check("KRW", None).is_err());
+
+        interlock.record("USD", Amount::from_f64(900.0));
+        assert!(!interlock.in_warmup());
+        assert!(interlock.check("KRW", krw(50_000_000)).is_ok());
+        assert!(interlock.check("HKD", None).is_ok());
+    }
+
+    #[test]
+    fn test_live_guard_amounts_from_config() {
+        let guard: LiveGuard =
+            serde_json::from_str(r#"{ "first_orders": 3, "max_notional": { "USD": 0.3 } }"#)
+                .unwrap();
+        assert_eq!(guard.max_notional["USD"], "0.3".parse().unwrap());
+
+        // 0.1 + 0.2 stays exactly at the cap (f64 sums would overshoot)
+        let mut interlock = LiveInterlock::new(guard);
+        interlock.record("USD", Amount::from_f64(0.1));
+        assert!(interlock.check("USD", Amount::from_f64(0.2)).is_ok());
+        assert!(
+            serde_json::from_str::<LiveGuard>(r#"{ "max_notional": { "USD": "x" } }"#).is_err()
+        );
+    }
+}
@@ -0,0 +1,476 @@
+//! Account-wide kill switch
+//!
+//! 발동하면 신규 주문을 차단하고 국내주식/해외주식/국내선물옵션/해외선물옵션/장내채권의 미체결
+//! 주문을 각 상품의 취소 API로 일괄 취소합니다. 선택적으로 선물옵션 미결제 포지션을 시장가 청산
+//! 주문으로 정리합니다. 발동 상태는 명시적으로 재무장(rearm)하기 전까지 유지되며, 상태 디렉터리가
+//! 지정되면 파일로 보존되어 플러그인 재시작 후에도 복원됩니다.
+
+use super::cache::{CacheCategory, Freshness};
+use super::domestic_future::FuturePriceType;
+use super::overseas_future::{OrderSide as FutureSide, PositionType, PriceType};
+use super::overseas_stock::ACCOUNT_EXCHANGES;
+use super::state_file;
+use super::types::{kst_now, Exchange, KisError, KisResult, Price, Quantity};
+use super::KisClient;
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+/// 상태 디렉터리 안의 저장 파일명
+pub const STATE_FILE: &str = "kill_switch.json";
+
+/// 재무장 시 요구하는 확인 문자열
+pub const REARM_CONFIRMATION: &str = "REARM";
+
+/// 예약주문 조회 기간 (오늘 기준 앞뒤 일수)
+const RESERVATION_LOOKAROUND_DAYS: i64 = 30;
+
+/// 해외선물옵션 미체결 조회 기간 (GTC 주문이 남아 있을 수 있는 과거 일수)
+const OVERSEAS_FUTURE_LOOKBACK_DAYS: i64 = 90;
+
+/// Persisted kill switch state
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KillSwitchState {
+    /// 발동 여부
+    pub engaged: bool,
+    /// 최초 발동 시각 (KST, ISO 8601)
+    pub engaged_at: Option<String>,
+    /// 발동 사유
+    pub reason: Option<String>,
+}
+
+impl KillSwitchState {
+    /// Load the state saved in `dir` (`None` if nothing was saved yet)
+    pub fn load(dir: &str) -> KisResult<Option<Self>> {
+        state_file::load(dir, STATE_FILE)
+    }
+
+    /// Save the state to `dir`
+    pub fn save(&self, dir: &str) -> KisResult<()> {
+        state_file::save(dir, STATE_FILE, self)
+    }
+
+    /// Engage the switch (이미 발동 중이면 최초 발동 시각과 사유를 유지)
+    ///
+    /// # Returns
+    /// * `bool` - 이번 호출로 새로 발동되었으면 true
+    pub fn engage(&mut self, at: NaiveDateTime, reason: Option<String>) -> bool {
+        if self.engaged {
+            return false;
+        }
+        self.engaged = true;
+        self.engaged_at = Some(at.format("%Y-%m-%dT%H:%M:%S+09:00").to_string());
+        self.reason = reason;
+        true
+    }
+
+    /// Re-arm (해제) the switch; requires the exact confirmation string
+    pub fn rearm(&mut self, confirmation: &str) -> KisResult<()> {
+        if confirmation != REARM_CONFIRMATION {
+            return Err(KisError::Validation(format!(
+                "Re-arming the kill switch requires confirm = \"{}\"",
+                REARM_CONFIRMATION
+            )));
+        }
+        *self = KillSwitchState::default();
+        Ok(())
+    }
+}
+
+/// 상품군별 일괄 취소 결과
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SweepReport {
+    /// "domestic_stock", "overseas_stock", "domestic_future", "overseas_future", "bond"
+    pub category: String,
+    /// 취소 요청이 접수된 주문번호 (예약주문은 `rsvn:` 접두사)
+    pub cancelled: Vec<String>,
+    /// 청산 주문을 낸 종목 (`종목:수량`)
+    pub flattened: Vec<String>,
+    /// 조회/취소/청산 실패 내역
+    pub errors: Vec<String>,
+}
+
+impl SweepReport {
+    fn new(category: &str) -> Self {
+        Self {
+            category: category.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn fail(&mut self, context: &str, error: KisError) {
+        self.errors.push(format!("{}: {}", context, error));
+    }
+}
+
+impl KisClient {
+    /// Cancel every open order across all product groups (선택적으로 선물옵션 포지션 청산)
+    ///
+    /// 한 상품군의 실패가 다른 상품군의 취소를 막지 않도록 결과를 상품군별로 모아 반환합니다.
+    /// 미체결 조회는 캐시를 사용하지 않습니다.
+    pub fn kill_switch_sweep(
+        &mut self,
+        today: NaiveDate,
+        flatten_futures: bool,
+    ) -> Vec<SweepReport> {
+        if let Err(e) = self.ensure_auth() {
+            let mut report = SweepReport::new("auth");
+            report.fail("authenticate", e);
+            return vec![report];
+        }
+
+        self.set_freshness(Freshness::Refresh);
+        let reports = vec![
+            self.sweep_domestic_stock(today),
+            self.sweep_overseas_stock(today),
+            self.sweep_domestic_future(flatten_futures),
+            self.sweep_overseas_future(today, flatten_futures),
+            self.sweep_bond(),
+        ];
+        self.set_freshness(Freshness::Default);
+        self.invalidate_cache(CacheCategory::Account);
+
+        reports
+    }
+
+    fn sweep_domestic_stock(&mut self, today: NaiveDate) -> SweepReport {
+        let mut report = SweepReport::new("domestic_stock");
+
+        match self.domestic_open_orders() {
+            Ok(orders) => {
+                for order in orders {
+                    let Some(odno) = order.odno.clone() else {
+                        continue;
+                    };
+                    let org_no = order.ord_gno_brno.clone().unwrap_or_default();
+                    match self.domestic_cancel_order(&odno, &org_no, order.exchange(), 0) {
+                        Ok(_) => report.cancelled.push(odno),
+                        Err(e) => report.fail(&format!("cancel {}", odno), e),
+                    }
+                }
+            }
+            Err(e) => report.fail("open orders", e),
+        }
+
+        // 예약주문은 실전투자에서만 조회/취소 가능
+        if !self.config.is_paper {
+            let (start, end) = reservation_window(today);
+            match self.domestic_reservation_list(&start, &end) {
+                Ok(items) => {
+                    let pending = items.into_iter().filter(|item| item.is_pending());
+                    for item in pending {
+                        let Some(seq) = item.rsvn_ord_seq else {
+                            continue;
+                        };
+                        match self.domestic_cancel_reservation(&seq) {
+                            Ok(_) => report.cancelled.push(format!("rsvn:{}", seq)),
+                            Err(e) => report.fail(&format!("cancel reservation {}", seq), e),
+                        }
+                    }
+                }
+                Err(e) => report.fail("reservations", e),
+            }
+        }
+
+        report
+    }
+
+    fn sweep_overseas_stock(&mut self, today: NaiveDate) -> SweepReport {
+        let mut report = SweepReport::new("overseas_stock");
+        let now = kst_now();
+
+        for exchange in ACCOUNT_EXCHANGES {
+            let orders = match self.overseas_open_orders(exchange) {
+                Ok(orders) => orders,
+                Err(e) => {
+                    report.fail(&format!("open orders {}", exchange.code()), e);
+                    continue;
+                }
+            };
+            for order in orders {
+                let (Some(odno), Some(symbol)) = (order.odno.clone(), order.pdno.clone()) else {
+                    continue;
+                };
+                let order_exchange = order
+                    .ovrs_excg_cd
+                    .as_deref()
+                    .and_then(Exchange::from_code)
+                    .unwrap_or(exchange);
+                let Some(quantity) = order.nccs_qty.or_zero().to_order_qty() else {
+                    report
+                        .errors
+                        .push(format!("cancel {}: invalid quantity", odno));
+                    continue;
+                };
+                // 미국 주간거래 시간의 미체결은 주간거래 주문이므로 주간거래 정정취소로 취소
+                // (정규장 주문이면 정규장 취소로 재시도)
+                let daytime = order_exchange
+                    .daytime()
+                    .filter(|d| !self.config.is_paper && d.is_open_at(now));
+                let result = match daytime {
+                    Some(daytime) => self
+                        .overseas_cancel_order(daytime, &symbol, &odno, quantity)
+                        .or_else(|_| {
+                            self.overseas_cancel_order(
+                                order_exchange.regular(),
+                                &symbol,
+                                &odno,
+                                quantity,
+                            )
+                        }),
+                    None => self.overseas_cancel_order(order_exchange, &symbol, &odno, quantity),
+                };
+                match result {
+                    Ok(_) => report.cancelled.push(odno),
+                    Err(e) => report.fail(&format!("cancel {}", odno), e),
+                }
+            }
+        }
+
+        // 예약주문: 미국은 NASD 하나로 전체 조회, 아시아는 거래소별로 조회 (실전 전용)
+        // 조회에 실패한 시장은 errors에 남겨 청산이 끝난 것처럼 보이지 않게 한다
+        if !self.config.is_paper {
+            let (start, end) = reservation_window(today);
+            for exchange in ACCOUNT_EXCHANGES {
+                let items = match self.overseas_reservation_list(exchange, &start, &end) {
+                    Ok(items) => items,
+                    Err(e) => {
+                        report.fail(&format!("reservations {}", exchange.code()), e);
+                        continue;
+                    }
+                };
+                let pending = items.into_iter().filter(|item| {
+                    item.cncl_yn.as_deref() != Some("Y")
+                        && item.odno.as_deref().unwrap_or("").is_empty()
+                });
+                for item in pending {
+                    let rsvn_no = item.ovrs_rsvn_odno.clone().unwrap_or_default();
+                    match self.overseas_cancel_reservation(exchange, &item) {
+                        Ok(_) => report.cancelled.push(format!("rsvn:{}", rsvn_no)),
+                        Err(e) => report.fail(&format!("cancel reservation {}", rsvn_no), e),
+                    }
+                }
+            }
+        }
+
+        report
+    }
+
+    fn sweep_domestic_future(&mut self, flatten: bool) -> SweepReport {
+        let mut report = SweepReport::new("domestic_future");
+
+        match self.future_open_orders() {
+            Ok(orders) => {
+                for order in orders {
+                    let Some(ord_no) = order.ord_no else {
+                        continue;
+                    };
+                    match self.future_cancel_order(&ord_no) {
+                        Ok(_) => report.cancelled.push(ord_no),
+                        Err(e) => report.fail(&format!("cancel {}", ord_no), e),
+                    }
+                }
+            }
+            Err(e) => report.fail("open orders", e),
+        }
+
+        // 야간 세션 주문은 별도 조회/취소 API (실전 전용)
+        if !self.config.is_paper {
+            match self.future_night_open_orders() {
+                Ok(orders) => {
+                    for order in orders {
+                        let Some(odno) = order.odno else {
+                            continue;
+                        };
+                        match self.future_night_cancel_order(&odno) {
+                            Ok(_) => report.cancelled.push(odno),
+                            Err(e) => report.fail(&format!("cancel night {}", odno), e),
+                        }
+                    }
+                }
+                Err(e) => report.fail("night open orders", e),
+            }
+        }
+
+        if flatten {
+            match self.future_get_balance() {
+                Ok(positions) => {
+                    for position in positions {
+                        let Some(symbol) = position.pdno.clone() else {
+                            continue;
+                        };
+                        let Some(quantity) = position
+                            .cblc_qty
+                            .or_zero()
+                            .to_order_qty()
+                            .filter(|q| *q > 0)
+                        else {
+                            continue;
+                        };
+                        // 01: 매도 포지션 → 청산매수, 02: 매수 포지션 → 청산매도
+                        let result = match position.sll_buy_dvsn_cd.as_deref() {
+                            Some("01") => self.future_liquidate_buy(
+                                &symbol,
+                                quantity,
+                                "0",
+                                FuturePriceType::Market,
+                            ),
+                            _ => self.future_liquidate_sell(
+                                &symbol,
+                                quantity,
+                                "0",
+                                FuturePriceType::Market,
+                            ),
+                        };
+                        match result {
+                            Ok(_) => report.flattened.push(format!("{}:{}", symbol, quantity)),
+                            Err(e) => report.fail(&format!("liquidate {}", symbol), e),
+                        }
+                    }
+                }
+                Err(e) => report.fail("positions", e),
+            }
+        }
+
+        report
+    }
+
+    fn sweep_overseas_future(&mut self, today: NaiveDate, flatten: bool) -> SweepReport {
+        let mut report = SweepReport::new("overseas_future");
+
+        let since = today - Duration::days(OVERSEAS_FUTURE_LOOKBACK_DAYS);
+        match self.overseas_future_open_orders(since) {
+            Ok(orders) => {
+                for order in orders {
+                    if order.order_no.is_empty() {
+                        continue;
+                    }
+                    match self.overseas_future_cancel(&order.order_no) {
+                        Ok(_) => report.cancelled.push(order.order_no),
+                        Err(e) => report.fail(&format!("cancel {}", order.order_no), e),
+                    }
+                }
+            }
+            Err(e) => report.fail("open orders", e),
+        }
+
+        if flatten {
+            match self.overseas_future_inquire_unsettled() {
+                Ok(positions) => {
+                    for position in positions {
+                        let Some(quantity) = position
+                            .quantity
+                            .or_zero()
+                            .to_order_qty()
+                            .filter(|q| *q > 0)
+                        else {
+                            continue;
+                        };
+                        // 01: 매도 포지션 → 매수 청산, 02: 매수 포지션 → 매도 청산
+                        let side = if position.side == "01" {
+                            FutureSide::Buy
+                        } else {
+                            FutureSide::Sell
+                        };
+                        match self.overseas_future_order(
+                            &position.symbol,
+                            side,
+                            PositionType::Close,
+                            PriceType::Market,
+                            quantity,
+                            Price::ZERO,
+                        ) {
+                            Ok(_) => report
+                                .flattened
+                                .push(format!("{}:{}", position.symbol, quantity)),
+                            Err(e) => report.fail(&format!("close {}", position.symbol), e),
+                        }
+                    }
+                }
+                Err(e) => report.fail("positions", e),
+            }
+        }
+
+        report
+    }
+
+    fn sweep_bond(&mut self) -> SweepReport {
+        let mut report = SweepReport::new("bond");
+
+        // 장내채권은 모의투자를 지원하지 않음
+        if self.config.is_paper {
+            return report;
+        }
+
+        match self.bond_open_orders() {
+            Ok(orders) => {
+                for order in orders {
+                    if order.remaining_quantity() <= Quantity::ZERO {
+                        continue;
+                    }
+                    let Some(odno) = order.odno.clone() else {
+                        continue;
+                    };
+                    let org_no = order.ord_gno_brno.clone().unwrap_or_default();
+                    match self.bond_cancel_order(&org_no, &odno) {
+                        Ok(_) => report.cancelled.push(odno),
+                        Err(e) => report.fail(&format!("cancel {}", odno), e),
+                    }
+                }
+            }
+            Err(e) => report.fail("open orders", e),
+        }
+
+        report
+    }
+}
+
+/// 예약주문 조회 기간 (YYYYMMDD)
+pub fn reservation_window(today: NaiveDate) -> (String, String) {
+    let span = Duration::days(RESERVATION_LOOKAROUND_DAYS);
+    (
+        (today - span).format("%Y%m%d").to_string(),
+        (today + span).format("%Y%m%d").to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kst(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_engage_keeps_first_trigger_and_rearm_requires_confirmation() {
+        let mut state = KillSwitchState::default();
+        assert!(state.engage(kst("2026-03-02 10:15:00"), Some("drawdown".to_string())));
+        assert!(!state.engage(kst("2026-03-02 11:00:00"), Some("again".to_string())));
+        assert_eq!(
+            state.engaged_at.as_deref(),
+            Some("2026-03-02T10:15:00+09:00")
+        );
+        assert_eq!(state.reason.as_deref(), Some("drawdown"));
+
+        assert!(state.rearm("yes").is_err());
+        assert!(state.engaged);
+        state.rearm(REARM_CONFIRMATION).unwrap();
+        assert_eq!(state, KillSwitchState::default());
+    }
+
+    #[test]
+    fn test_state_persists_across_load() {
+        let dir = std::env::temp_dir().join(format!("kill_switch_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir = dir.to_str().unwrap();
+
+        assert_eq!(KillSwitchState::load(dir).unwrap(), None);
+
+        let mut state = KillSwitchState::default();
+        state.engage(kst("2026-03-02 10:15:00"), None);
+        state.save(dir).unwrap();
+        assert_eq!(KillSwitchState::load(dir).unwrap(), Some(state));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,1056 @@
+//! KRX master file parser (종목정보파일)
+//!
+//! KIS가 매일 배포하는 `kospi_code.mst`, `kosdaq_code.mst`, `konex_code.mst`, `elw_code.mst`를
+//! 파싱해 종목 테이블을 만듭니다. 각 행은 CP949 인코딩의 고정폭 레코드로,
+//! 앞부분은 단축코드(9) + 표준코드(12) + 한글명(가변), 뒷부분은 시장별 고정 길이 속성입니다.
+//! 파일은 호스트가 내려받아 바이트로 전달하며, 파싱할 수 없는 행은 건너뛰고 개수만 집계합니다.
+//!
+//! 선물옵션 마스터(`fo_idx_code_mts.mst`, `fo_stk_code_mts.mst`)는 `|` 구분 레코드이며 주식 주문에
+//! 쓰이지 않도록 별도의 [`DerivativeTable`]에 적재합니다.
+
+use super::field::{impl_field_report, FieldReport, KisField};
+use super::types::{KisError, KisResult, KrxMarket, Price, Quantity};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+/// 단축코드 필드 길이
+const SHORT_CODE_LEN: usize = 9;
+/// 표준코드(ISIN) 필드 길이
+const ISIN_LEN: usize = 12;
+
+/// Master file kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MasterFile {
+    /// kospi_code.mst
+    Kospi,
+    /// kosdaq_code.mst
+    Kosdaq,
+    /// konex_code.mst
+    Konex,
+    /// elw_code.mst
+    Elw,
+    /// fo_idx_code_mts.mst (지수선물옵션)
+    IndexDerivatives,
+    /// fo_stk_code_mts.mst (주식선물옵션)
+    StockDerivatives,
+}
+
+impl MasterFile {
+    /// Parse from "kospi", "kosdaq", "konex", "elw", "fo_idx", "fo_stk" (or the file name)
+    pub fn from_code(code: &str) -> Option<MasterFile> {
+        let code = code.to_ascii_lowercase();
+        let name = code
+            .trim_end_matches(".mst")
+            .trim_end_matches("_mts")
+            .trim_end_matches("_code");
+        match name {
+            "kospi" => Some(MasterFile::Kospi),
+            "kosdaq" => Some(MasterFile::Kosdaq),
+            "konex" => Some(MasterFile::Konex),
+            "elw" => Some(MasterFile::Elw),
+            "fo_idx" => Some(MasterFile::IndexDerivatives),
+            "fo_stk" => Some(MasterFile::StockDerivatives),
+            _ => None,
+        }
+    }
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            MasterFile::Kospi => "kospi_code.mst",
+            MasterFile::Kosdaq => "kosdaq_code.mst",
+            MasterFile::Konex => "konex_code.mst",
+            MasterFile::Elw => "elw_code.mst",
+            MasterFile::IndexDerivatives => "fo_idx_code_mts.mst",
+            MasterFile::StockDerivatives => "fo_stk_code_mts.mst",
+        }
+    }
+
+    /// 시장명 ("KOSPI", "KOSDAQ", "KONEX", "ELW", "KRX 파생")
+    pub fn market_name(&self) -> &'static str {
+        match self {
+            MasterFile::Kospi => "KOSPI",
+            MasterFile::Kosdaq => "KOSDAQ",
+            MasterFile::Konex => "KONEX",
+            MasterFile::Elw => "ELW",
+            MasterFile::IndexDerivatives | MasterFile::StockDerivatives => "KRX 파생",
+        }
+    }
+
+    /// Whether this is a futures/options master (loaded into [`DerivativeTable`])
+    pub fn is_derivatives(&self) -> bool {
+        matches!(
+            self,
+            MasterFile::IndexDerivatives | MasterFile::StockDerivatives
+        )
+    }
+
+    /// 고정폭 속성부 길이 (줄바꿈 제외, 선물옵션은 구분자 레코드라 0)
+    fn tail_len(&self) -> usize {
+        match self {
+            MasterFile::Kospi => 227,
+            MasterFile::Kosdaq => 221,
+            MasterFile::Konex => 183,
+            MasterFile::Elw => 56,
+            MasterFile::IndexDerivatives | MasterFile::StockDerivatives => 0,
+        }
+    }
+
+    /// 주식 속성부 레이아웃 (ELW와 선물옵션은 없음)
+    fn layout(&self) -> Option<&'static TailLayout> {
+        match self {
+            MasterFile::Kospi => Some(&KOSPI_LAYOUT),
+            MasterFile::Kosdaq => Some(&KOSDAQ_LAYOUT),
+            MasterFile::Konex => Some(&KONEX_LAYOUT),
+            _ => None,
+        }
+    }
+
+    /// 기본 시장 (ETF/ETN은 그룹코드로 구분)
+    fn market(&self) -> KrxMarket {
+        match self {
+            MasterFile::Kosdaq => KrxMarket::Kosdaq,
+            MasterFile::Konex => KrxMarket::Konex,
+            MasterFile::Elw => KrxMarket::Elw,
+            _ => KrxMarket::Kospi,
+        }
+    }
+}
+
+/// (시작, 길이) in the fixed-width tail
+type Span = (usize, usize);
+
+/// Offsets of the attributes we read from each master's tail
+struct TailLayout {
+    group_code: Span,
+    /// 지수업종 대/중/소분류 (코넥스 없음)
+    sector: Option<[Span; 3]>,
+    etp: Option<Span>,
+    spac: Option<Span>,
+    short_term_overheat: Option<Span>,
+    base_price: Span,
+    lot_size: Span,
+    halted: Span,
+    liquidation: Span,
+    administrative: Span,
+    market_warning: Span,
+    warning_notice: Span,
+    unfaithful_disclosure: Span,
+    credit_available: Span,
+    par_value: Span,
+    listing_date: Span,
+    preferred: Span,
+    short_sale_overheat: Span,
+    abnormal_surge: Span,
+}
+
+const KOSPI_LAYOUT: TailLayout = TailLayout {
+    group_code: (0, 2),
+    sector: Some([(3, 4), (7, 4), (11, 4)]),
+    etp: Some((22, 1)),
+    spac: Some((29, 1)),
+    short_term_overheat: Some((32, 1)),
+    base_price: (41, 9),
+    lot_size: (50, 5),
+    halted: (60, 1),
+    liquidation: (61, 1),
+    administrative: (62, 1),
+    market_warning: (63, 2),
+    warning_notice: (65, 1),
+    unfaithful_disclosure: (66, 1),
+    credit_available: (77, 1),
+    par_value: (93, 12),
+    listing_date: (105, 8),
+    preferred: (158, 1),
+    short_sale_overheat: (159, 1),
+    abnormal_surge: (160, 1),
+};
+
+const KOSDAQ_LAYOUT: TailLayout = TailLayout {
+    group_code: (0, 2),
+    sector: Some([(3, 4), (7, 4), (11, 4)]),
+    etp: Some((18, 1)),
+    spac: Some((24, 1)),
+    short_term_overheat: Some((27, 1)),
+    base_price: (37, 9),
+    lot_size: (46, 5),
+    halted: (56, 1),
+    liquidation: (57, 1),
+    administrative: (58, 1),
+    market_warning: (59, 2),
+    warning_notice: (61, 1),
+    unfaithful_disclosure: (62, 1),
+    credit_available: (73, 1),
+    par_value: (77, 12),
+    listing_date: (89, 8),
+    preferred: (146, 1),
+    short_sale_overheat: (147, 1),
+    abnormal_surge: (148, 1),
+};
+
+const KONEX_LAYOUT: TailLayout = TailLayout {
+    group_code: (0, 2),
+    sector: None,
+    etp: None,
+    spac: None,
+    short_term_overheat: None,
+    base_price: (2, 9),
+    lot_size: (11, 5),
+    halted: (21, 1),
+    liquidation: (22, 1),
+    administrative: (23, 1),
+    market_warning: (24, 2),
+    warning_notice: (26, 1),
+    unfaithful_disclosure: (27, 1),
+    credit_available: (38, 1),
+    par_value: (54, 12),
+    listing_date: (66, 8),
+    preferred: (119, 1),
+    short_sale_overheat: (120, 1),
+    abnormal_surge: (121, 1),
+};
+
+/// Offsets of the ELW master tail
+struct ElwLayout {
+    group_code: Span,
+    /// 권리유형 (C: 콜, P: 풋)
+    right: Span,
+    underlying: Span,
+    strike: Span,
+    expiry: Span,
+    listing_date: Span,
+    base_price: Span,
+    lot_size: Span,
+    halted: Span,
+    liquidation: Span,
+}
+
+const ELW_LAYOUT: ElwLayout = ElwLayout {
+    group_code: (0, 2),
+    right: (2, 1),
+    underlying: (3, 9),
+    strike: (12, 12),
+    expiry: (24, 8),
+    listing_date: (32, 8),
+    base_price: (40, 9),
+    lot_size: (49, 5),
+    halted: (54, 1),
+    liquidation: (55, 1),
+};
+
+/// 시장경고 구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarketWarning {
+    #[default]
+    None,
+    /// 투자주의
+    Caution,
+    /// 투자경고
+    Warning,
+    /// 투자위험
+    Risk,
+}
+
+impl MarketWarning {
+    pub fn from_code(code: &str) -> MarketWarning {
+        match code {
+            "01" => MarketWarning::Caution,
+            "02" => MarketWarning::Warning,
+            "03" => MarketWarning::Risk,
+            _ => MarketWarning::None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            MarketWarning::None => "00",
+            MarketWarning::Caution => "01",
+            MarketWarning::Warning => "02",
+            MarketWarning::Risk => "03",
+        }
+    }
+}
+
+/// 지수업종 분류코드
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SectorCode {
+    pub large: String,
+    pub medium: String,
+    pub small: String,
+}
+
+/// 종목 상태 (거래정지/경고 등)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InstrumentStatus {
+    /// 거래정지
+    pub halted: bool,
+    /// 정리매매
+    pub liquidation: bool,
+    /// 관리종목
+    pub administrative: bool,
+    /// 시장경고
+    pub market_warning: MarketWarning,
+    /// 시장경고 위험예고
+    pub warning_notice: bool,
+    /// 불성실공시
+    pub unfaithful_disclosure: bool,
+    /// 단기과열 지정 (지정예고/지정/연장)
+    pub short_term_overheat: bool,
+    /// 공매도과열
+    pub short_sale_overheat: bool,
+    /// 이상급등
+    pub abnormal_surge: bool,
+}
+
+/// ELW 권리유형
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElwRight {
+    Call,
+    Put,
+}
+
+/// ELW 발행조건
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElwTerms {
+    pub right: ElwRight,
+    /// 기초자산 코드 (개별주식 단축코드 또는 지수코드)
+    pub underlying: String,
+    /// 행사가
+    pub strike: KisField<Price>,
+    /// 최종거래일
+    pub expiry: KisField<NaiveDate>,
+}
+
+/// Instrument parsed from a master file row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instrument {
+    /// 출처 마스터파일
+    pub source: MasterFile,
+    /// 단축코드 (예: "005930")
+    pub short_code: String,
+    /// 표준코드 (ISIN, 예: "KR7005930003")
+    pub isin: String,
+    /// 한글 종목명
+    pub name: String,
+    /// 시장 (ETF/ETN은 [`KrxMarket::EtfEtn`], ELW는 [`KrxMarket::Elw`])
+    pub market: KrxMarket,
+    /// 증권그룹구분코드 (ST 주권, EF ETF, EN ETN, RT 리츠, EW ELW 등)
+    pub group_code: String,
+    /// 지수업종 분류 (코넥스 없음)
+    pub sector: Option<SectorCode>,
+    /// ETP 여부
+    pub is_etp: bool,
+    /// 기업인수목적회사(SPAC) 여부
+    pub is_spac: bool,
+    /// 우선주 여부
+    pub is_preferred: bool,
+    /// 신용주문 가능
+    pub credit_available: bool,
+    /// 기준가
+    pub base_price: KisField<Price>,
+    /// 정규시장 매매수량단위
+    pub lot_size: KisField<Quantity>,
+    /// 액면가
+    pub par_value: KisField<Price>,
+    /// 상장일자
+    pub listing_date: KisField<NaiveDate>,
+    pub status: InstrumentStatus,
+    /// ELW 발행조건 (ELW 마스터만)
+    pub elw: Option<ElwTerms>,
+}
+
+impl_field_report!(Instrument {
+    base_price,
+    lot_size,
+    par_value,
+    listing_date,
+});
+
+impl Instrument {
+    /// Whether the instrument can take new orders
+    pub fn is_tradable(&self) -> bool {
+        !self.status.halted
+    }
+}
+
+/// Result of parsing one master file
+#[derive(Debug, Clone)]
+pub struct MasterParse<T = Instrument> {
+    pub instruments: Vec<T>,
+    /// 건너뛴 행 (1부터 시작하는 행 번호, 사유)
+    pub skipped: Vec<(usize, String)>,
+}
+
+impl<T> Default for MasterParse<T> {
+    fn default() -> Self {
+        Self {
+            instruments: Vec::new(),
+            skipped: Vec::new(),
+        }
+    }
+}
+
+/// Parse every non-blank line of a master file with `parse`
+fn parse_lines<T>(bytes: &[u8], parse: impl Fn(&[u8]) -> KisResult<T>) -> MasterParse<T> {
+    let mut result = MasterParse::default();
+    for (index, line) in bytes.split(|&b| b == b'\n').enumerate() {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        match parse(line) {
+            Ok(row) => result.instruments.push(row),
+            Err(e) => result.skipped.push((index + 1, e.to_string())),
+        }
+    }
+    result
+}
+
+/// Parse a stock or ELW master file from raw CP949 bytes
+pub fn parse_master(file: MasterFile, bytes: &[u8]) -> MasterParse {
+    parse_lines(bytes, |line| parse_row(file, line))
+}
+
+/// Parse a single stock or ELW master row (without line terminator)
+pub fn parse_row(file: MasterFile, line: &[u8]) -> KisResult<Instrument> {
+    if file.is_derivatives() {
+        return Err(KisError::Parse(format!(
+            "{} is a futures/options master",
+            file.file_name()
+        )));
+    }
+    let tail_len = file.tail_len();
+    if line.len() < SHORT_CODE_LEN + ISIN_LEN + tail_len {
+        return Err(KisError::Parse(format!(
+            "{} row too short: {} bytes",
+            file.file_name(),
+            line.len()
+        )));
+    }
+    let (head, tail) = line.split_at(line.len() - tail_len);
+    if !tail.is_ascii() {
+        return Err(KisError::Parse(format!(
+            "{} row has non-ASCII attribute bytes",
+            file.file_name()
+        )));
+    }
+    // 속성부는 ASCII이므로 바이트 오프셋으로 바로 자를 수 있음
+    let tail = std::str::from_utf8(tail).unwrap_or_default();
+
+    let short_code = ascii_field(&head[..SHORT_CODE_LEN]);
+    if short_code.is_empty() {
+        return Err(KisError::Parse("Missing short code".to_string()));
+    }
+    let isin = ascii_field(&head[SHORT_CODE_LEN..SHORT_CODE_LEN + ISIN_LEN]);
+    let name = decode_cp949(&head[SHORT_CODE_LEN + ISIN_LEN..])
+        .trim()
+        .to_string();
+
+    let text = |span: Span| tail[span.0..span.0 + span.1].trim();
+    let flag = |span: Option<Span>| span.is_some_and(|s| text(s) == "Y");
+    let Some(layout) = file.layout() else {
+        return elw_row(short_code, isin, name, tail);
+    };
+
+    let group_code = text(layout.group_code).to_string();
+    let is_etp = match file {
+        // 코스피/코스닥 ETP 구분은 코드값(0 = 해당없음)
+        MasterFile::Kospi | MasterFile::Kosdaq => layout
+            .etp
+            .is_some_and(|s| !matches!(text(s), "" | "0" | "N")),
+        _ => false,
+    } || matches!(group_code.as_str(), "EF" | "EN");
+
+    let market = if is_etp {
+        KrxMarket::EtfEtn
+    } else {
+        file.market()
+    };
+
+    Ok(Instrument {
+        source: file,
+        short_code,
+        isin,
+        name,
+        market,
+        sector: layout.sector.map(|[large, medium, small]| SectorCode {
+            large: text(large).to_string(),
+            medium: text(medium).to_string(),
+            small: text(small).to_string(),
+        }),
+        group_code,
+        is_etp,
+        is_spac: flag(layout.spac),
+        is_preferred: !matches!(text(layout.preferred), "" | "0"),
+        credit_available: flag(Some(layout.credit_available)),
+        base_price: KisField::parse(text(layout.base_price)),
+        lot_size: KisField::parse(text(layout.lot_size)),
+        par_value: KisField::parse(text(layout.par_value)),
+        listing_date: KisField::parse(text(layout.listing_date)),
+        status: InstrumentStatus {
+            halted: flag(Some(layout.halted)),
+            liquidation: flag(Some(layout.liquidation)),
+            administrative: flag(Some(layout.administrative)),
+            market_warning: MarketWarning::from_code(text(layout.market_warning)),
+            warning_notice: flag(Some(layout.warning_notice)),
+            unfaithful_disclosure: flag(Some(layout.unfaithful_disclosure)),
+            short_term_overheat: layout
+                .short_term_overheat
+                .is_some_and(|s| !matches!(text(s), "" | "0")),
+            short_sale_overheat: flag(Some(layout.short_sale_overheat)),
+            abnormal_surge: flag(Some(layout.abnormal_surge)),
+        },
+        elw: None,
+    })
+}
+
+/// Build an ELW instrument from its attribute tail
+fn elw_row(short_code: String, isin: String, name: String, tail: &str) -> KisResult<Instrument> {
+    let layout = &ELW_LAYOUT;
+    let text = |span: Span| tail[span.0..span.0 + span.1].trim();
+    let right = match text(layout.right) {
+        "C" => ElwRight::Call,
+        "P" => ElwRight::Put,
+        other => {
+            return Err(KisError::Parse(format!(
+                "Unknown ELW right type {:?} for {}",
+                other, short_code
+            )))
+        }
+    };
+
+    Ok(Instrument {
+        source: MasterFile::Elw,
+        short_code,
+        isin,
+        name,
+        market: KrxMarket::Elw,
+        group_code: text(layout.group_code).to_string(),
+        sector: None,
+        is_etp: false,
+        is_spac: false,
+        is_preferred: false,
+        credit_available: false,
+        base_price: KisField::parse(text(layout.base_price)),
+        lot_size: KisField::parse(text(layout.lot_size)),
+        par_value: KisField::Blank,
+        listing_date: KisField::parse(text(layout.listing_date)),
+        status: InstrumentStatus {
+            halted: text(layout.halted) == "Y",
+            liquidation: text(layout.liquidation) == "Y",
+            ..Default::default()
+        },
+        elw: Some(ElwTerms {
+            right,
+            underlying: text(layout.underlying).to_string(),
+            strike: KisField::parse(text(layout.strike)),
+            expiry: KisField::parse(text(layout.expiry)),
+        }),
+    })
+}
+
+fn ascii_field(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).trim().to_string()
+}
+
+/// Decode CP949 (EUC-KR 확장) bytes
+pub fn decode_cp949(bytes: &[u8]) -> String {
+    let (text, _, _) = encoding_rs::EUC_KR.decode(bytes);
+    text.into_owned()
+}
+
+/// Offline instrument table built from master files
+#[derive(Debug, Clone, Default)]
+pub struct InstrumentTable {
+    by_code: HashMap<String, Instrument>,
+    isin_index: HashMap<String, String>,
+    loaded: Vec<MasterFile>,
+}
+
+impl InstrumentTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load (or reload) one master file; rows from the previous load of that file are replaced
+    pub fn load(&mut self, file: MasterFile, bytes: &[u8]) -> MasterParse {
+        let parsed = parse_master(file, bytes);
+        if self.loaded.contains(&file) {
+            self.by_code.retain(|_, i| i.source != file);
+            let by_code = &self.by_code;
+            self.isin_index.retain(|_, code| by_code.contains_key(code));
+        } else {
+            self.loaded.push(file);
+        }
+        for instrument in &parsed.instruments {
+            for (name, raw) in instrument.invalid_fields() {
+                eprintln!(
+                    "[broker-korea] {} {}: invalid {} {:?}",
+                    file.file_name(),
+                    instrument.short_code,
+                    name,
+                    raw
+                );
+            }
+            if !instrument.isin.is_empty() {
+                self.isin_index
+                    .insert(instrument.isin.clone(), instrument.short_code.clone());
+            }
+            self.by_code
+                .insert(instrument.short_code.clone(), instrument.clone());
+        }
+        parsed
+    }
+
+    /// Master files loaded so far
+    pub fn loaded_files(&self) -> &[MasterFile] {
+        &self.loaded
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_code.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_code.is_empty()
+    }
+
+    /// Look up by short code ("005930") or ISIN ("KR7005930003")
+    pub fn get(&self, code: &str) -> Option<&Instrument> {
+        let code = code.trim();
+        self.by_code.get(code).or_else(|| {
+            self.isin_index
+                .get(code)
+                .and_then(|short| self.by_code.get(short))
+        })
+    }
+
+    /// All loaded instruments (순서 없음)
+    pub fn iter(&self) -> impl Iterator<Item = &Instrument> {
+        self.by_code.values()
+    }
+
+    /// Instruments whose Korean name contains `query` (공백 무시)
+    pub fn find_by_name(&self, query: &str) -> Vec<&Instrument> {
+        let query: String = query.split_whitespace().collect();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut found: Vec<&Instrument> = self
+            .by_code
+            .values()
+            .filter(|i| {
+                i.name
+                    .split_whitespace()
+                    .collect::<String>()
+                    .contains(&query)
+            })
+            .collect();
+        found.sort_by(|a, b| a.short_code.cmp(&b.short_code));
+        found
+    }
+
+    /// Validate that a symbol is listed and not halted
+    pub fn validate_tradable(&self, code: &str) -> KisResult<&Instrument> {
+        let instrument = self
+            .get(code)
+            .ok_or_else(|| KisError::Validation(format!("Unknown symbol: {}", code)))?;
+        if !instrument.is_tradable() {
+            return Err(KisError::Validation(format!(
+                "{} ({}) is halted (거래정지)",
+                instrument.short_code, instrument.name
+            )));
+        }
+        Ok(instrument)
+    }
+}
+
+/// 선물옵션 상품종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DerivativeKind {
+    Future,
+    Call,
+    Put,
+    Spread,
+}
+
+impl DerivativeKind {
+    /// Parse from 상품종류 (1: 선물, 2: 콜옵션, 3: 풋옵션, 4: 스프레드)
+    pub fn from_code(code: &str) -> Option<DerivativeKind> {
+        match code {
+            "1" => Some(DerivativeKind::Future),
+            "2" => Some(DerivativeKind::Call),
+            "3" => Some(DerivativeKind::Put),
+            "4" => Some(DerivativeKind::Spread),
+            _ => None,
+        }
+    }
+}
+
+/// Futures/options contract parsed from a derivatives master row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Derivative {
+    /// 출처 마스터파일
+    pub source: MasterFile,
+    pub kind: DerivativeKind,
+    /// 단축코드 (예: "101W09")
+    pub short_code: String,
+    /// 표준코드
+    pub isin: String,
+    /// 한글 종목명
+    pub name: String,
+    /// ATM구분
+    pub atm_code: String,
+    /// 행사가 (선물은 공백)
+    pub strike: KisField<Price>,
+    /// 월물구분코드
+    pub month_code: String,
+    /// 기초자산 단축코드
+    pub underlying_code: String,
+    /// 기초자산명
+    pub underlying_name: String,
+}
+
+impl_field_report!(Derivative { strike });
+
+/// Parse a futures/options master file from raw CP949 bytes
+///
+/// 각 행은 `상품종류|단축코드|표준코드|한글종목명|ATM구분|행사가|월물구분코드|기초자산 단축코드|기초자산명`
+pub fn parse_derivative_master(file: MasterFile, bytes: &[u8]) -> MasterParse<Derivative> {
+    parse_lines(bytes, |line| parse_derivative_row(file, line))
+}
+
+/// Parse a single futures/options master row (without line terminator)
+pub fn parse_derivative_row(file: MasterFile, line: &[u8]) -> KisResult<Derivative> {
+    if !file.is_derivatives() {
+        return Err(KisError::Parse(format!(
+            "{} is not a futures/options master",
+            file.file_name()
+        )));
+    }
+    let line = decode_cp949(line);
+    let columns: Vec<&str> = line.split('|').map(str::trim).collect();
+    if columns.len() < 9 {
+        return Err(KisError::Parse(format!(
+            "{} row has {} columns",
+            file.file_name(),
+            columns.len()
+        )));
+    }
+    let kind = DerivativeKind::from_code(columns[0])
+        .ok_or_else(|| KisError::Parse(format!("Unknown product type {:?}", columns[0])))?;
+    if columns[1].is_empty() {
+        return Err(KisError::Parse("Missing short code".to_string()));
+    }
+
+    Ok(Derivative {
+        source: file,
+        kind,
+        short_code: columns[1].to_string(),
+        isin: columns[2].to_string(),
+        name: columns[3].to_string(),
+        atm_code: columns[4].to_string(),
+        strike: KisField::parse(columns[5]),
+        month_code: columns[6].to_string(),
+        underlying_code: columns[7].to_string(),
+        underlying_name: columns[8].to_string(),
+    })
+}
+
+/// Offline futures/options contract table built from derivatives masters
+#[derive(Debug, Clone, Default)]
+pub struct DerivativeTable {
+    by_code: HashMap<String, Derivative>,
+    loaded: Vec<MasterFile>,
+}
+
+impl DerivativeTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load (or reload) one derivatives master; rows from the previous load of that file are replaced
+    pub fn load(&mut self, file: MasterFile, bytes: &[u8]) -> MasterParse<Derivative> {
+        let parsed = parse_derivative_master(file, bytes);
+        if self.loaded.contains(&file) {
+            self.by_code.retain(|_, d| d.source != file);
+        } else {
+            self.loaded.push(file);
+        }
+        for contract in &parsed.instruments {
+            for (name, raw) in contract.invalid_fields() {
+                eprintln!(
+                    "[broker-korea] {} {}: invalid {} {:?}",
+                    file.file_name(),
+                    contract.short_code,
+                    name,
+                    raw
+                );
+            }
+            self.by_code
+                .insert(contract.short_code.clone(), contract.clone());
+        }
+        parsed
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_code.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_code.is_empty()
+    }
+
+    /// Look up by short code ("101W09") or standard code
+    pub fn get(&self, code: &str) -> Option<&Derivative> {
+        let code = code.trim();
+        self.by_code
+            .get(code)
+            .or_else(|| self.by_code.values().find(|d| d.isin == code))
+    }
+
+    /// Contracts on the given underlying (기초자산 단축코드), sorted by short code
+    pub fn by_underlying(&self, underlying: &str) -> Vec<&Derivative> {
+        let mut found: Vec<&Derivative> = self
+            .by_code
+            .values()
+            .filter(|d| d.underlying_code == underlying)
+            .collect();
+        found.sort_by(|a, b| a.short_code.cmp(&b.short_code));
+        found
+    }
+
+    /// Validate that a futures/options code is listed
+    pub fn validate(&self, code: &str) -> KisResult<&Derivative> {
+        self.get(code)
+            .ok_or_else(|| KisError::Validation(format!("Unknown futures/options code: {}", code)))
+    }
+}
+
+/// Master rows for unit tests, built from the same layouts the parser reads
+#[cfg(test)]
+pub mod fixtures {
+    use super::MasterFile;
+
+    /// Build a row: head + tail with the given (offset, value) patches
+    pub fn row(
+        file: MasterFile,
+        code: &str,
+        isin: &str,
+        name: &[u8],
+        patches: &[(usize, &str)],
+    ) -> Vec<u8> {
+        let mut line = format!("{:<9}{:<12}", code, isin).into_bytes();
+        line.extend_from_slice(name);
+        let mut tail = vec![b' '; file.tail_len()];
+        for (offset, value) in patches {
+            tail[*offset..*offset + value.len()].copy_from_slice(value.as_bytes());
+        }
+        line.extend_from_slice(&tail);
+        line
+    }
+
+    /// Tail offset of the 거래정지 flag
+    pub fn halted_offset(file: MasterFile) -> usize {
+        file.layout()
+            .map_or(super::ELW_LAYOUT.halted.0, |layout| layout.halted.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::row;
+    use super::*;
+
+    #[test]
+    fn test_parse_kospi_master() {
+        // "삼성전자" in CP949
+        let name = [0xBB, 0xEF, 0xBC, 0xBA, 0xC0, 0xFC, 0xC0, 0xDA];
+        let mut bytes = row(
+            MasterFile::Kospi,
+            "005930",
+            "KR7005930003",
+            &name,
+            &[
+                (0, "ST"),
+                (3, "0013"),
+                (41, "000055000"),
+                (50, "00001"),
+                (63, "00"),
+                (77, "Y"),
+                (93, "000000000100"),
+                (105, "19750611"),
+                (158, "0"),
+            ],
+        );
+        bytes.extend_from_slice(b"\r\n");
+        bytes.extend(row(
+            MasterFile::Kospi,
+            "069500",
+            "KR7069500007",
+            b"KODEX 200",
+            &[
+                (0, "EF"),
+                (22, "1"),
+                (41, "00003500X"),
+                (60, "Y"),
+                (63, "02"),
+            ],
+        ));
+        bytes.extend_from_slice(b"\nshort row\n");
+
+        let parsed = parse_master(MasterFile::Kospi, &bytes);
+        assert_eq!(parsed.instruments.len(), 2);
+        assert_eq!(parsed.skipped.len(), 1);
+        assert_eq!(parsed.skipped[0].0, 3);
+
+        let samsung = &parsed.instruments[0];
+        assert_eq!(samsung.short_code, "005930");
+        assert_eq!(samsung.isin, "KR7005930003");
+        assert_eq!(samsung.name, "삼성전자");
+        assert_eq!(samsung.market, KrxMarket::Kospi);
+        assert_eq!(samsung.sector.as_ref().unwrap().large, "0013");
+        assert_eq!(samsung.base_price.get(), Some(Price::from_int(55_000)));
+        assert_eq!(samsung.lot_size.get(), Some(Quantity::from_int(1)));
+        assert_eq!(
+            samsung.listing_date.get(),
+            NaiveDate::from_ymd_opt(1975, 6, 11)
+        );
+        assert!(samsung.credit_available);
+        assert!(samsung.is_tradable());
+
+        let etf = &parsed.instruments[1];
+        assert_eq!(etf.market, KrxMarket::EtfEtn);
+        assert!(etf.is_etp && etf.status.halted);
+        assert_eq!(etf.status.market_warning, MarketWarning::Warning);
+        assert!(etf.base_price.is_invalid());
+        assert_eq!(etf.invalid_fields().len(), 1);
+    }
+
+    #[test]
+    fn test_instrument_table_lookup() {
+        let mut table = InstrumentTable::new();
+        let mut bytes = row(
+            MasterFile::Kosdaq,
+            "035720",
+            "KR7035720002",
+            b"KAKAO GAMES",
+            &[(0, "ST"), (37, "000030000")],
+        );
+        bytes.push(b'\n');
+        bytes.extend(row(
+            MasterFile::Kosdaq,
+            "900000",
+            "KR7900000000",
+            b"HALTED CO",
+            &[(0, "ST"), (56, "Y")],
+        ));
+        let parsed = table.load(MasterFile::Kosdaq, &bytes);
+        assert!(parsed.skipped.is_empty());
+        assert_eq!(table.len(), 2);
+
+        assert_eq!(table.get("KR7035720002").unwrap().short_code, "035720");
+        assert_eq!(table.find_by_name("kakao").len(), 0);
+        assert_eq!(table.find_by_name("KAKAOGAMES").len(), 1);
+        assert!(table.validate_tradable("035720").is_ok());
+        assert!(matches!(
+            table.validate_tradable("900000"),
+            Err(KisError::Validation(_))
+        ));
+        assert!(table.validate_tradable("123456").is_err());
+
+        // Reloading replaces the previous rows of that file
+        table.load(
+            MasterFile::Kosdaq,
+            &bytes[..bytes.iter().position(|&b| b == b'\n').unwrap()],
+        );
+        assert_eq!(table.len(), 1);
+        assert_eq!(
+            MasterFile::from_code("kosdaq_code.mst"),
+            Some(MasterFile::Kosdaq)
+        );
+    }
+
+    #[test]
+    fn test_parse_elw_master() {
+        let mut bytes = row(
+            MasterFile::Elw,
+            "52K123",
+            "KRA52K123000",
+            b"ELW CALL 005930",
+            &[
+                (0, "EW"),
+                (2, "C"),
+                (3, "005930"),
+                (12, "000080000.00"),
+                (24, "20261217"),
+                (32, "20260602"),
+                (40, "000000125"),
+                (49, "00010"),
+            ],
+        );
+        bytes.push(b'\n');
+        bytes.extend(row(
+            MasterFile::Elw,
+            "52K124",
+            "KRA52K124000",
+            b"ELW BAD",
+            &[(0, "EW"), (2, "X")],
+        ));
+
+        let mut table = InstrumentTable::new();
+        let parsed = table.load(MasterFile::Elw, &bytes);
+        assert_eq!(parsed.instruments.len(), 1);
+        assert_eq!(parsed.skipped.len(), 1);
+
+        let elw = table.validate_tradable("52K123").unwrap();
+        assert_eq!(elw.market, KrxMarket::Elw);
+        assert_eq!(elw.group_code, "EW");
+        assert_eq!(elw.lot_size.get(), Some(Quantity::from_int(10)));
+        assert_eq!(elw.base_price.get(), Some(Price::from_int(125)));
+        let terms = elw.elw.as_ref().unwrap();
+        assert_eq!(terms.right, ElwRight::Call);
+        assert_eq!(terms.underlying, "005930");
+        assert_eq!(terms.strike.get(), Some(Price::from_int(80_000)));
+        assert_eq!(terms.expiry.get(), NaiveDate::from_ymd_opt(2026, 12, 17));
+        assert_eq!(MasterFile::from_code("elw_code.mst"), Some(MasterFile::Elw));
+    }
+
+    #[test]
+    fn test_parse_derivative_master() {
+        // "코스피200" in CP949
+        let kospi200 = [0xC4, 0xDA, 0xBD, 0xBA, 0xC7, 0xC7, b'2', b'0', b'0'];
+        let mut bytes = b"1|101W09|KR4101W90009|F 202609|0||W09|K2I|".to_vec();
+        bytes.extend_from_slice(&kospi200);
+        bytes.extend_from_slice(b"\r\n2|201W9350|KR4201W93503|C 202609 350.0|1|350.00|W09|K2I|");
+        bytes.extend_from_slice(&kospi200);
+        bytes.extend_from_slice(b"\n9|999999|||||||\n1|101W09|too few\n");
+
+        let mut table = DerivativeTable::new();
+        let file = MasterFile::from_code("fo_idx_code_mts.mst").unwrap();
+        assert_eq!(file, MasterFile::IndexDerivatives);
+        let parsed = table.load(file, &bytes);
+        assert_eq!(parsed.instruments.len(), 2);
+        assert_eq!(
+            parsed
+                .skipped
+                .iter()
+                .map(|(line, _)| *line)
+                .collect::<Vec<_>>(),
+            vec![3, 4]
+        );
+
+        let future = table.validate("101W09").unwrap();
+        assert_eq!(future.kind, DerivativeKind::Future);
+        assert!(future.strike.is_blank());
+        assert_eq!(future.underlying_name, "코스피200");
+
+        let call = table.get("KR4201W93503").unwrap();
+        assert_eq!(call.kind, DerivativeKind::Call);
+        assert_eq!(call.strike.get(), Some("350".parse().unwrap()));
+        assert_eq!(table.by_underlying("K2I").len(), 2);
+        assert!(table.validate("005930").is_err());
+
+        // 주식 테이블에는 적재하지 않음
+        assert!(parse_master(file, &bytes).instruments.is_empty());
+    }
+}
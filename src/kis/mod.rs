@@ -10,16 +10,34 @@
 
 pub mod auth;
 pub mod bond;
+pub mod cache;
+pub mod calendar;
 pub mod domestic_future;
 pub mod domestic_stock;
+pub mod field;
+pub mod interlock;
+pub mod kill_switch;
+pub mod master;
 pub mod overseas_future;
+pub mod overseas_master;
 pub mod overseas_stock;
+pub mod pnl;
+pub mod reconcile;
+pub mod risk;
+pub mod search;
+pub mod state_file;
 pub mod types;
 
 use crate::http::{HttpClient, HttpResponse};
 use auth::TokenInfo;
+use cache::{CacheCategory, Freshness, ResponseCache};
+use calendar::TradingCalendar;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
-use types::{KisConfig, KisError, KisResult};
+use types::{KisApiResponse, KisConfig, KisError, KisResult};
+
+/// 연속조회로 따라갈 최대 페이지 수
+pub const MAX_PAGES: usize = 50;
 
 /// KIS API Base URLs
 pub const PROD_BASE_URL: &str = "https://openapi.koreainvestment.com:9443";
@@ -30,6 +48,12 @@ pub struct KisClient {
     config: KisConfig,
     http: HttpClient,
     token: Option<TokenInfo>,
+    /// GET 응답 캐시
+    cache: RefCell<ResponseCache>,
+    /// 다음 조회에 적용할 신선도 힌트
+    freshness: Cell<Freshness>,
+    /// KRX 휴장일 달력
+    calendar: TradingCalendar,
 }
 
 impl KisClient {
@@ -50,6 +74,9 @@ impl KisClient {
             config,
             http,
             token: None,
+            cache: RefCell::new(ResponseCache::new()),
+            freshness: Cell::new(Freshness::Default),
+            calendar: TradingCalendar::new(),
         }
     }
 
@@ -110,15 +137,131 @@ impl KisClient {
         Ok(headers)
     }
 
+    /// Set the freshness hint applied to subsequent GET requests
+    pub fn set_freshness(&self, freshness: Freshness) {
+        self.freshness.set(freshness);
+    }
+
+    /// Run `f` with a freshness hint, restoring the previous hint afterwards (오류로 빠져나와도 복원)
+    pub fn with_freshness<R>(&mut self, freshness: Freshness, f: impl FnOnce(&mut Self) -> R) -> R {
+        let previous = self.freshness.replace(freshness);
+        let result = f(self);
+        self.freshness.set(previous);
+        result
+    }
+
+    /// Override the cache TTL for a category (0 disables caching)
+    pub fn set_cache_ttl_ms(&self, category: CacheCategory, ttl_ms: u64) {
+        self.cache.borrow_mut().set_ttl_ms(category, ttl_ms);
+    }
+
+    /// Drop cached responses of a category
+    pub fn invalidate_cache(&self, category: CacheCategory) {
+        self.cache.borrow_mut().invalidate(category);
+    }
+
+    /// Drop all cached responses
+    pub fn clear_cache(&self) {
+        self.cache.borrow_mut().clear();
+    }
+
     /// Make authenticated GET request
+    ///
+    /// 잔고/시세/기준정보 조회는 [`CacheCategory`]별 TTL과 현재 신선도 힌트에 따라 캐시됩니다.
     pub fn get(&self, path: &str, tr_id: &str, query: Option<&str>) -> KisResult<HttpResponse> {
-        let headers = self.build_headers(tr_id)?;
+        self.get_page(path, tr_id, query, None)
+    }
+
+    /// Make authenticated GET requests following the continuation keys (연속조회) to the last page
+    ///
+    /// 응답 헤더 `tr_cont`가 `F`/`M`(다음 데이터 있음)이면 응답 본문의 `ctx_area_fk*`/`ctx_area_nk*`
+    /// 값을 `query`의 `CTX_AREA_FK*`/`CTX_AREA_NK*`에 넣고 요청 헤더 `tr_cont: N`으로 다음 페이지를
+    /// 조회합니다. 최대 [`MAX_PAGES`]페이지까지 따라갑니다.
+    pub fn get_pages(&self, path: &str, tr_id: &str, query: &str) -> KisResult<Vec<HttpResponse>> {
+        let mut pages = Vec::new();
+        let mut query = query.to_string();
+        let mut tr_cont = None;
+
+        while pages.len() < MAX_PAGES {
+            let response = self.get_page(path, tr_id, Some(&query), tr_cont)?;
+            let next = next_page_keys(&response);
+            pages.push(response);
+            match next {
+                Some((fk, nk)) => {
+                    query = with_continuation(&query, &fk, &nk);
+                    tr_cont = Some("N");
+                }
+                None => return Ok(pages),
+            }
+        }
+
+        Err(KisError::Validation(format!(
+            "{} returned more than {} pages",
+            path, MAX_PAGES
+        )))
+    }
+
+    /// Fetch every page of a list inquiry and collect its rows
+    ///
+    /// # Arguments
+    /// * `context` - 파싱 오류 메시지에 쓸 조회 이름
+    /// * `rows` - 응답에서 목록(`output` 또는 `output1`)을 꺼내는 함수
+    pub(crate) fn get_list<T, R>(
+        &self,
+        path: &str,
+        tr_id: &str,
+        query: &str,
+        context: &str,
+        rows: impl Fn(KisApiResponse<T>) -> Option<Vec<R>>,
+    ) -> KisResult<Vec<R>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let mut items = Vec::new();
+        for response in self.get_pages(path, tr_id, query)? {
+            let api_response: KisApiResponse<T> = response
+                .json()
+                .map_err(|e| KisError::Parse(format!("Failed to parse {}: {}", context, e)))?;
+            if !api_response.is_success() {
+                return Err(KisError::Api {
+                    code: api_response.msg_cd,
+                    message: api_response.msg1,
+                });
+            }
+            items.extend(rows(api_response).unwrap_or_default());
+        }
+        Ok(items)
+    }
+
+    fn get_page(
+        &self,
+        path: &str,
+        tr_id: &str,
+        query: Option<&str>,
+        tr_cont: Option<&str>,
+    ) -> KisResult<HttpResponse> {
         let url = if let Some(q) = query {
             format!("{}?{}", path, q)
         } else {
             path.to_string()
         };
 
+        let category = CacheCategory::classify(path);
+        let key = ResponseCache::key(tr_id, &url);
+        if category.is_some() {
+            let cached = self
+                .cache
+                .borrow()
+                .lookup(&key, self.freshness.get(), auth::now_millis());
+            if let Some(response) = cached {
+                return Ok(response);
+            }
+        }
+
+        let mut headers = self.build_headers(tr_id)?;
+        if let Some(tr_cont) = tr_cont {
+            headers.insert("tr_cont".to_string(), tr_cont.to_string());
+        }
         let response = self.http.get(&url, Some(headers));
 
         if !response.is_success() {
@@ -128,6 +271,14 @@ impl KisClient {
             });
         }
 
+        if let Some(category) = category {
+            if cache::is_cacheable(&response) {
+                self.cache
+                    .borrow_mut()
+                    .store(key, category, response.clone(), auth::now_millis());
+            }
+        }
+
         Ok(response)
     }
 
@@ -139,7 +290,7 @@ impl KisClient {
         body: &T,
     ) -> KisResult<HttpResponse> {
         let headers = self.build_headers(tr_id)?;
-        let response = self.http.post_json(path, body, Some(headers));
+        let response = self.send_post(path, body, headers);
 
         if !response.is_success() {
             return Err(KisError::Api {
@@ -151,12 +302,122 @@ impl KisClient {
         Ok(response)
     }
 
+    /// Make an order/revise/cancel POST signed with a hashkey
+    ///
+    /// 전송 실패는 [`order_post_error`]로 변환하므로 결과를 알 수 없는 요청은 [`KisError::Network`]가 됩니다.
+    pub(crate) fn post_order<T: serde::Serialize>(
+        &self,
+        path: &str,
+        tr_id: &str,
+        body: &T,
+    ) -> KisResult<HttpResponse> {
+        let hashkey = self.get_hashkey(body)?;
+        let mut headers = self.build_headers(tr_id)?;
+        headers.insert("hashkey".to_string(), hashkey);
+
+        let response = self.send_post(path, body, headers);
+        if !response.is_success() {
+            return Err(order_post_error(&response));
+        }
+        Ok(response)
+    }
+
+    fn send_post<T: serde::Serialize>(
+        &self,
+        path: &str,
+        body: &T,
+        headers: HashMap<String, String>,
+    ) -> HttpResponse {
+        let response = self.http.post_json(path, body, Some(headers));
+
+        // 주문/정정/취소 후 잔고·주문가능금액이 바뀌므로 계좌 캐시를 비움
+        // (타임아웃 등 실패 응답이어도 주문이 접수됐을 수 있음)
+        self.invalidate_cache(CacheCategory::Account);
+        response
+    }
+
     /// Get hashkey for order requests
     pub fn get_hashkey<T: serde::Serialize>(&self, body: &T) -> KisResult<String> {
         auth::get_hashkey(&self.http, &self.config, body)
     }
 }
 
+/// Continuation keys of a response that has more pages (`None` on the last page)
+fn next_page_keys(response: &HttpResponse) -> Option<(String, String)> {
+    let more = response
+        .headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("tr_cont"))
+        .is_some_and(|(_, value)| matches!(value.trim(), "F" | "M"));
+    if !more {
+        return None;
+    }
+
+    let body: serde_json::Value = serde_json::from_str(&response.body).ok()?;
+    let key = |prefix: &str| {
+        body.as_object()?
+            .iter()
+            .find(|(name, _)| name.to_ascii_lowercase().starts_with(prefix))
+            .and_then(|(_, value)| value.as_str())
+            .map(str::to_string)
+    };
+    let (fk, nk) = (key("ctx_area_fk")?, key("ctx_area_nk")?);
+    (!nk.trim().is_empty()).then_some((fk, nk))
+}
+
+/// Replace the `CTX_AREA_FK*`/`CTX_AREA_NK*` values of a query string
+fn with_continuation(query: &str, fk: &str, nk: &str) -> String {
+    query
+        .split('&')
+        .map(|param| {
+            let name = param.split('=').next().unwrap_or(param);
+            let upper = name.to_ascii_uppercase();
+            if upper.starts_with("CTX_AREA_FK") {
+                format!("{}={}", name, encode_query_value(fk))
+            } else if upper.starts_with("CTX_AREA_NK") {
+                format!("{}={}", name, encode_query_value(nk))
+            } else {
+                param.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Percent-encode a query value (연속조회키는 공백과 특수문자를 포함)
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Error for a failed order POST
+///
+/// 전송 실패(상태 0)나 5xx 응답은 주문 접수 여부를 알 수 없으므로 [`KisError::Network`]로 구분합니다.
+pub(crate) fn order_post_error(response: &HttpResponse) -> KisError {
+    let message = response
+        .error
+        .clone()
+        .unwrap_or_else(|| response.body.clone());
+    if response.status == 0 || response.status >= 500 {
+        KisError::Network(format!(
+            "order outcome unknown ({}): {}",
+            response.status, message
+        ))
+    } else {
+        KisError::Api {
+            code: response.status.to_string(),
+            message,
+        }
+    }
+}
+
 /// Environment mode
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Environment {
@@ -181,3 +442,127 @@ impl Environment {
         }
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::http::mock;
+
+    /// Authenticated client whose requests go to the mock transport
+    pub(crate) fn test_client(is_paper: bool) -> KisClient {
+        let config = KisConfig::new(
+            "key".to_string(),
+            "secret".to_string(),
+            "1234567801".to_string(),
+            is_paper,
+        );
+        let mut client = KisClient::new(config);
+        client.token = Some(TokenInfo {
+            access_token: "token".to_string(),
+            token_type: "Bearer".to_string(),
+            expires_in: 86_400,
+            expires_at: u64::MAX / 2,
+        });
+        client
+    }
+
+    #[test]
+    fn test_get_pages_follows_continuation() {
+        let client = test_client(false);
+        let more = HashMap::from([("tr_cont".to_string(), "M".to_string())]);
+        let last = HashMap::from([("tr_cont".to_string(), "D".to_string())]);
+
+        mock::respond_with(
+            200,
+            more,
+            r#"{"rt_cd":"0","msg_cd":"","msg1":"","ctx_area_fk100":"FK ","ctx_area_nk100":"NK1 ","output1":[{"odno":"1"}]}"#,
+        );
+        mock::respond_with(
+            200,
+            last,
+            r#"{"rt_cd":"0","msg_cd":"","msg1":"","ctx_area_fk100":"","ctx_area_nk100":"","output1":[{"odno":"2"}]}"#,
+        );
+
+        let rows = client
+            .get_list(
+                "/uapi/domestic-stock/v1/trading/inquire-daily-ccld",
+                "TTTC8001R",
+                "CANO=12345678&CTX_AREA_FK100=&CTX_AREA_NK100=",
+                "orders",
+                |r: KisApiResponse<serde_json::Value>| r.output1,
+            )
+            .unwrap();
+        assert_eq!(rows.len(), 2);
+
+        let requests = mock::requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1]
+            .url
+            .ends_with("CANO=12345678&CTX_AREA_FK100=FK%20&CTX_AREA_NK100=NK1%20"));
+        assert_eq!(
+            requests[1].headers.get("tr_cont").map(String::as_str),
+            Some("N")
+        );
+        assert!(!requests[0].headers.contains_key("tr_cont"));
+    }
+
+    #[test]
+    fn test_with_freshness_restores_hint_on_error() {
+        let mut client = test_client(false);
+        let result: KisResult<()> = client.with_freshness(Freshness::Refresh, |client| {
+            assert_eq!(client.freshness.get(), Freshness::Refresh);
+            Err(KisError::Network("timeout".to_string()))
+        });
+        assert!(result.is_err());
+        assert_eq!(client.freshness.get(), Freshness::Default);
+    }
+
+    #[test]
+    fn test_order_post_invalidates_cached_balance() {
+        let client = test_client(false);
+        let path = "/uapi/domestic-stock/v1/trading/inquire-balance";
+        let query = Some("CANO=12345678&ACNT_PRDT_CD=01");
+
+        mock::respond(r#"{"rt_cd":"0","output1":[{"pdno":"005930","hldg_qty":"10"}]}"#);
+        let before = client.get(path, "TTTC8434R", query).unwrap();
+        assert_eq!(
+            client.get(path, "TTTC8434R", query).unwrap().body,
+            before.body
+        );
+        assert_eq!(mock::requests().len(), 1);
+
+        // 주문 (hashkey 발급 + 주문 POST)
+        mock::respond(r#"{"HASH":"hash"}"#);
+        mock::respond(r#"{"rt_cd":"0","output":{"ODNO":"0000000101"}}"#);
+        client
+            .post_order(
+                "/uapi/domestic-stock/v1/trading/order-cash",
+                "TTTC0802U",
+                &serde_json::json!({ "PDNO": "005930" }),
+            )
+            .unwrap();
+
+        mock::respond(r#"{"rt_cd":"0","output1":[{"pdno":"005930","hldg_qty":"20"}]}"#);
+        let after = client.get(path, "TTTC8434R", query).unwrap();
+        assert!(after.body.contains(r#""hldg_qty":"20""#));
+        assert_eq!(mock::requests().len(), 4);
+
+        // 결과를 알 수 없는 전송 실패도 계좌 캐시를 비움
+        mock::respond(r#"{"HASH":"hash"}"#);
+        mock::respond_with(504, HashMap::new(), "gateway timeout");
+        let error = client
+            .post_order(
+                "/uapi/domestic-stock/v1/trading/order-cash",
+                "TTTC0802U",
+                &serde_json::json!({ "PDNO": "005930" }),
+            )
+            .unwrap_err();
+        assert!(error.is_ambiguous());
+        mock::respond(r#"{"rt_cd":"0","output1":[]}"#);
+        assert!(!client
+            .get(path, "TTTC8434R", query)
+            .unwrap()
+            .body
+            .contains("hldg_qty"));
+    }
+}
@@ -5,9 +5,13 @@
 //! margin inquiry, and execution history.
 
 use super::{
-    types::{KisApiResponse, KisError, KisResult, OrderResult},
+    field::{impl_field_report, KisField},
+    types::{
+        kst_now, Amount, KisApiResponse, KisError, KisResult, OrderResult, Price, Quantity, Rate,
+    },
     KisClient,
 };
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 // ============================================================================
@@ -79,7 +83,7 @@ impl OverseasFutureOrderRequest {
         side: OrderSide,
         price_type: PriceType,
         quantity: u32,
-        limit_price: f64,
+        limit_price: Price,
     ) -> Self {
         Self {
             cano,
@@ -140,7 +144,7 @@ impl OverseasFutureModifyCancelRequest {
         acnt_prdt_cd: String,
         original_order_no: String,
         quantity: Option<u32>,
-        limit_price: Option<f64>,
+        limit_price: Option<Price>,
     ) -> Self {
         Self {
             cano,
@@ -185,24 +189,24 @@ pub struct UnsettledPosition {
     pub symbol_name: String,
 
     /// 미결제수량
-    #[serde(rename = "UNPD_QTY")]
-    pub quantity: String,
+    #[serde(rename = "UNPD_QTY", default)]
+    pub quantity: KisField<Quantity>,
 
     /// 평균가
-    #[serde(rename = "AVG_PRIC")]
-    pub average_price: String,
+    #[serde(rename = "AVG_PRIC", default)]
+    pub average_price: KisField<Price>,
 
     /// 현재가
-    #[serde(rename = "PRPR")]
-    pub current_price: String,
+    #[serde(rename = "PRPR", default)]
+    pub current_price: KisField<Price>,
 
     /// 평가손익
-    #[serde(rename = "EVLU_PFLS_AMT")]
-    pub profit_loss: String,
+    #[serde(rename = "EVLU_PFLS_AMT", default)]
+    pub profit_loss: KisField<Amount>,
 
     /// 평가손익률
-    #[serde(rename = "EVLU_PFLS_RT")]
-    pub profit_loss_rate: String,
+    #[serde(rename = "EVLU_PFLS_RT", default)]
+    pub profit_loss_rate: KisField<Rate>,
 
     /// 매수매도구분 (01: 매도, 02: 매수)
     #[serde(rename = "SLL_BUY_DVSN_CD")]
@@ -213,6 +217,14 @@ pub struct UnsettledPosition {
     pub currency: String,
 }
 
+impl_field_report!(UnsettledPosition {
+    quantity,
+    average_price,
+    current_price,
+    profit_loss,
+    profit_loss_rate,
+});
+
 /// Deposit information (증거금 정보)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DepositInfo {
@@ -369,7 +381,7 @@ impl KisClient {
         position_type: PositionType,
         price_type: PriceType,
         quantity: u32,
-        limit_price: f64,
+        limit_price: Price,
     ) -> KisResult<OrderResult> {
         let tr_id = match side {
             OrderSide::Buy => position_type.buy_tr_id(),
@@ -386,23 +398,11 @@ impl KisClient {
             limit_price,
         );
 
-        // Get hashkey for order
-        let hashkey = self.get_hashkey(&request)?;
-        let mut headers = self.build_headers(tr_id)?;
-        headers.insert("hashkey".to_string(), hashkey);
-
-        let response = self.http.post_json(
+        let response = self.post_order(
             "/uapi/overseas-futureoption/v1/trading/order",
+            tr_id,
             &request,
-            Some(headers),
-        );
-
-        if !response.is_success() {
-            return Err(KisError::Api {
-                code: response.status.to_string(),
-                message: response.error.unwrap_or_else(|| response.body.clone()),
-            });
-        }
+        )?;
 
         let api_response: KisApiResponse<OverseasFutureOrderResponse> = response
             .json()
@@ -451,7 +451,7 @@ impl KisClient {
         &self,
         order_no: &str,
         quantity: Option<u32>,
-        limit_price: Option<f64>,
+        limit_price: Option<Price>,
     ) -> KisResult<OrderResult> {
         let request = OverseasFutureModifyCancelRequest::modify(
             self.cano().to_string(),
@@ -469,22 +469,11 @@ impl KisClient {
         &self,
         request: OverseasFutureModifyCancelRequest,
     ) -> KisResult<OrderResult> {
-        let hashkey = self.get_hashkey(&request)?;
-        let mut headers = self.build_headers(tr_id::MODIFY_CANCEL)?;
-        headers.insert("hashkey".to_string(), hashkey);
-
-        let response = self.http.post_json(
+        let response = self.post_order(
             "/uapi/overseas-futureoption/v1/trading/order-rvsecncl",
+            tr_id::MODIFY_CANCEL,
             &request,
-            Some(headers),
-        );
-
-        if !response.is_success() {
-            return Err(KisError::Api {
-                code: response.status.to_string(),
-                message: response.error.unwrap_or_else(|| response.body.clone()),
-            });
-        }
+        )?;
 
         let api_response: KisApiResponse<OverseasFutureOrderResponse> =
             response.json().map_err(|e| {
@@ -568,6 +557,29 @@ impl KisClient {
             .ok_or_else(|| KisError::Parse("Deposit response missing output".to_string()))
     }
 
+    /// Inquire unfilled orders placed since a date (미체결 주문 조회)
+    ///
+    /// # Arguments
+    /// * `since` - 조회시작일자 (GTC 주문은 주문일 이후 계속 미체결로 남으므로 충분히 앞선 날짜)
+    pub fn overseas_future_open_orders(&self, since: NaiveDate) -> KisResult<Vec<ExecutionRecord>> {
+        let today = kst_now().format("%Y%m%d").to_string();
+        let query = format!(
+            "CANO={}&ACNT_PRDT_CD={}&OVRS_FUTR_FX_PDNO=&STRT_DT={}&END_DT={}&SLL_BUY_DVSN_CD=&CCLD_NCCS_DVSN_CD=03&SORT_SQN=DS&CTX_AREA_FK200=&CTX_AREA_NK200=",
+            self.cano(),
+            self.acnt_prdt_cd(),
+            since.format("%Y%m%d"),
+            today
+        );
+
+        self.get_list(
+            "/uapi/overseas-futureoption/v1/trading/inquire-ccld",
+            tr_id::INQUIRE_EXECUTION,
+            &query,
+            "open orders",
+            |r: KisApiResponse<ExecutionRecord>| r.output1,
+        )
+    }
+
     /// Inquire execution history (체결내역 조회)
     ///
     /// # Arguments
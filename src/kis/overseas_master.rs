@@ -0,0 +1,413 @@
+//! Overseas stock master file parser (해외주식 종목정보파일)
+//!
+//! KIS가 배포하는 `NASMST.COD`, `NYSMST.COD`, `AMSMST.COD`, `HKSMST.COD`, `SHSMST.COD`,
+//! `SZSMST.COD`, `TSEMST.COD`, `HNXMST.COD`, `HSXMST.COD`는 CP949 인코딩의 탭 구분 파일입니다.
+//! 파싱 결과로 티커만으로 거래소, 통화, 매매단위를 찾는 [`OverseasSymbolTable`]을 구성합니다.
+
+use super::field::{impl_field_report, FieldReport, KisField};
+use super::master::decode_cp949;
+use super::types::{Exchange, KisError, KisResult, Price, Quantity};
+use std::collections::HashMap;
+
+/// 필수 컬럼 수 (국가코드 ~ 매도주문수량단위)
+const MIN_COLUMNS: usize = 15;
+
+/// Security type (Security type 컬럼)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecurityType {
+    /// 1: 지수
+    Index,
+    /// 2: 주식
+    Stock,
+    /// 3: ETP(ETF)
+    Etp,
+    /// 4: 워런트
+    Warrant,
+    Other,
+}
+
+impl SecurityType {
+    pub fn from_code(code: &str) -> SecurityType {
+        match code {
+            "1" => SecurityType::Index,
+            "2" => SecurityType::Stock,
+            "3" => SecurityType::Etp,
+            "4" => SecurityType::Warrant,
+            _ => SecurityType::Other,
+        }
+    }
+
+    /// Whether orders can be placed (지수 제외)
+    pub fn is_tradable(&self) -> bool {
+        !matches!(self, SecurityType::Index)
+    }
+}
+
+/// Overseas instrument parsed from a master file row
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverseasInstrument {
+    /// 거래소
+    pub exchange: Exchange,
+    /// 종목코드 (예: "AAPL", "00700")
+    pub symbol: String,
+    /// 실시간 종목코드 (예: "DNASAAPL")
+    pub realtime_symbol: String,
+    /// 한글명
+    pub name_kr: String,
+    /// 영문명
+    pub name_en: String,
+    pub security_type: SecurityType,
+    /// 거래통화 (USD, HKD, CNY, JPY, VND)
+    pub currency: String,
+    /// 기준가
+    pub base_price: KisField<Price>,
+    /// 매수주문 수량단위
+    pub bid_lot: KisField<Quantity>,
+    /// 매도주문 수량단위
+    pub ask_lot: KisField<Quantity>,
+}
+
+impl_field_report!(OverseasInstrument {
+    base_price,
+    bid_lot,
+    ask_lot,
+});
+
+impl OverseasInstrument {
+    /// Order lot size for a side (missing = 1주)
+    pub fn lot_size(&self, is_buy: bool) -> u32 {
+        let lot = if is_buy { &self.bid_lot } else { &self.ask_lot };
+        lot.get()
+            .and_then(Quantity::to_order_qty)
+            .filter(|&q| q > 0)
+            .unwrap_or(1)
+    }
+
+    /// Check that an order quantity is a multiple of the lot size
+    pub fn validate_quantity(&self, quantity: u32, is_buy: bool) -> KisResult<()> {
+        let lot = self.lot_size(is_buy);
+        if quantity == 0 || !quantity.is_multiple_of(lot) {
+            return Err(KisError::Validation(format!(
+                "{} ({}) trades in lots of {}; got {}",
+                self.symbol,
+                self.exchange.code(),
+                lot,
+                quantity
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Exchange of a master file name ("NASMST.COD", "nasmst", "NAS")
+pub fn master_exchange(file: &str) -> Option<Exchange> {
+    let name = file.to_ascii_uppercase();
+    let name = name.trim_end_matches(".COD").trim_end_matches("MST");
+    Exchange::from_quote_code(name)
+}
+
+/// Result of parsing one overseas master file
+#[derive(Debug, Clone, Default)]
+pub struct OverseasMasterParse {
+    pub instruments: Vec<OverseasInstrument>,
+    /// 건너뛴 행 (1부터 시작하는 행 번호, 사유)
+    pub skipped: Vec<(usize, String)>,
+}
+
+/// Parse an overseas master file from raw CP949 bytes
+///
+/// 행의 거래소코드 컬럼이 `exchange`와 다르면 건너뜁니다.
+pub fn parse_overseas_master(exchange: Exchange, bytes: &[u8]) -> OverseasMasterParse {
+    let mut result = OverseasMasterParse::default();
+    let text = decode_cp949(bytes);
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_overseas_row(exchange, line) {
+            Ok(instrument) => result.instruments.push(instrument),
+            Err(e) => result.skipped.push((index + 1, e.to_string())),
+        }
+    }
+    result
+}
+
+/// Parse a single tab-separated overseas master row
+pub fn parse_overseas_row(exchange: Exchange, line: &str) -> KisResult<OverseasInstrument> {
+    let columns: Vec<&str> = line.split('\t').map(str::trim).collect();
+    if columns.len() < MIN_COLUMNS {
+        return Err(KisError::Parse(format!(
+            "Expected at least {} columns, got {}",
+            MIN_COLUMNS,
+            columns.len()
+        )));
+    }
+
+    let row_exchange = Exchange::from_quote_code(columns[2]);
+    if row_exchange != Some(exchange) {
+        return Err(KisError::Parse(format!(
+            "Exchange code {:?} does not match {}",
+            columns[2],
+            exchange.quote_code()
+        )));
+    }
+    let symbol = columns[4].to_ascii_uppercase();
+    if symbol.is_empty() {
+        return Err(KisError::Parse("Missing symbol".to_string()));
+    }
+
+    Ok(OverseasInstrument {
+        exchange,
+        symbol,
+        realtime_symbol: columns[5].to_string(),
+        name_kr: columns[6].to_string(),
+        name_en: columns[7].to_string(),
+        security_type: SecurityType::from_code(columns[8]),
+        currency: columns[9].to_string(),
+        base_price: KisField::parse(columns[12]),
+        bid_lot: KisField::parse(columns[13]),
+        ask_lot: KisField::parse(columns[14]),
+    })
+}
+
+/// Ticker → exchange resolver built from overseas master files
+#[derive(Debug, Clone, Default)]
+pub struct OverseasSymbolTable {
+    by_symbol: HashMap<String, Vec<OverseasInstrument>>,
+    loaded: Vec<Exchange>,
+}
+
+impl OverseasSymbolTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load (or reload) the master file of one exchange
+    pub fn load(&mut self, exchange: Exchange, bytes: &[u8]) -> OverseasMasterParse {
+        let parsed = parse_overseas_master(exchange, bytes);
+        if self.loaded.contains(&exchange) {
+            for listings in self.by_symbol.values_mut() {
+                listings.retain(|i| i.exchange != exchange);
+            }
+            self.by_symbol.retain(|_, listings| !listings.is_empty());
+        } else {
+            self.loaded.push(exchange);
+        }
+        for instrument in &parsed.instruments {
+            for (name, raw) in instrument.invalid_fields() {
+                eprintln!(
+                    "[broker-korea] {} {}: invalid {} {:?}",
+                    exchange.quote_code(),
+                    instrument.symbol,
+                    name,
+                    raw
+                );
+            }
+            self.by_symbol
+                .entry(instrument.symbol.clone())
+                .or_default()
+                .push(instrument.clone());
+        }
+        parsed
+    }
+
+    /// Exchanges loaded so far
+    pub fn loaded_exchanges(&self) -> &[Exchange] {
+        &self.loaded
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_symbol.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_symbol.values().map(Vec::len).sum()
+    }
+
+    /// All loaded listings, including indices (순서 없음)
+    pub fn iter(&self) -> impl Iterator<Item = &OverseasInstrument> {
+        self.by_symbol.values().flatten()
+    }
+
+    /// All tradable listings of a ticker
+    pub fn listings(&self, symbol: &str) -> Vec<&OverseasInstrument> {
+        self.by_symbol
+            .get(&symbol.trim().to_ascii_uppercase())
+            .map(|listings| {
+                listings
+                    .iter()
+                    .filter(|i| i.security_type.is_tradable())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Listing of a ticker on a specific exchange (주간거래 거래소는 정규장 기준)
+    pub fn get(&self, symbol: &str, exchange: Exchange) -> Option<&OverseasInstrument> {
+        let regular = exchange.regular();
+        self.listings(symbol)
+            .into_iter()
+            .find(|i| i.exchange == regular)
+    }
+
+    /// Resolve a bare ticker to its unique listing
+    ///
+    /// 여러 거래소에 같은 티커가 있으면 거래소를 지정하도록 오류를 반환합니다.
+    pub fn resolve(&self, symbol: &str) -> KisResult<&OverseasInstrument> {
+        match self.listings(symbol).as_slice() {
+            [] => Err(KisError::Validation(format!(
+                "Unknown overseas symbol: {}",
+                symbol
+            ))),
+            [only] => Ok(only),
+            many => Err(KisError::Validation(format!(
+                "{} is listed on multiple exchanges ({}); specify one",
+                symbol,
+                many.iter()
+                    .map(|i| i.exchange.code())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))),
+        }
+    }
+
+    /// Check a ticker against an explicitly requested exchange
+    ///
+    /// 해당 거래소에 없고 다른 거래소에 상장돼 있으면 올바른 거래소를 안내합니다.
+    /// 거래소 마스터가 적재되지 않았으면 검사하지 않습니다.
+    pub fn check_exchange(
+        &self,
+        symbol: &str,
+        exchange: Exchange,
+    ) -> KisResult<Option<&OverseasInstrument>> {
+        if !self.loaded.contains(&exchange.regular()) {
+            return Ok(None);
+        }
+        if let Some(instrument) = self.get(symbol, exchange) {
+            return Ok(Some(instrument));
+        }
+        let elsewhere: Vec<&str> = self
+            .listings(symbol)
+            .iter()
+            .map(|i| i.exchange.code())
+            .collect();
+        Err(KisError::Validation(if elsewhere.is_empty() {
+            format!("{} is not listed on {}", symbol, exchange.code())
+        } else {
+            format!(
+                "{} is not listed on {} (listed on {})",
+                symbol,
+                exchange.code(),
+                elsewhere.join(", ")
+            )
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(excd: &str, symbol: &str, kind: &str, currency: &str, lot: &str) -> String {
+        [
+            "US",
+            "512",
+            excd,
+            "NASDAQ",
+            symbol,
+            "DNASAAPL",
+            "애플",
+            "APPLE INC",
+            kind,
+            currency,
+            "4",
+            "",
+            "227.5200",
+            lot,
+            lot,
+            "0930",
+            "1600",
+            "N",
+            "",
+            "",
+            "0",
+            "",
+            "",
+            "",
+        ]
+        .join("\t")
+    }
+
+    fn cp949(text: &str) -> Vec<u8> {
+        encoding_rs::EUC_KR.encode(text).0.into_owned()
+    }
+
+    #[test]
+    fn test_parse_overseas_master() {
+        let text = [
+            row("NAS", "AAPL", "2", "USD", "1"),
+            row("NAS", "QQQ", "3", "USD", "1"),
+            row("NAS", "COMP", "1", "USD", "1"),
+            row("NYS", "IBM", "2", "USD", "1"),
+            "truncated\trow".to_string(),
+        ]
+        .join("\r\n");
+
+        let parsed = parse_overseas_master(Exchange::NASDAQ, &cp949(&text));
+        assert_eq!(parsed.instruments.len(), 3);
+        assert_eq!(parsed.skipped.len(), 2);
+
+        let aapl = &parsed.instruments[0];
+        assert_eq!(aapl.symbol, "AAPL");
+        assert_eq!(aapl.name_kr, "애플");
+        assert_eq!(aapl.currency, "USD");
+        assert_eq!(aapl.security_type, SecurityType::Stock);
+        assert_eq!(aapl.base_price.get(), "227.52".parse().ok());
+        assert_eq!(master_exchange("NASMST.COD"), Some(Exchange::NASDAQ));
+        assert_eq!(master_exchange("hksmst"), Some(Exchange::SEHK));
+    }
+
+    #[test]
+    fn test_overseas_symbol_resolution() {
+        let mut table = OverseasSymbolTable::new();
+        let nas = [
+            row("NAS", "AAPL", "2", "USD", "1"),
+            row("NAS", "DUAL", "2", "USD", "1"),
+            row("NAS", "COMP", "1", "USD", "1"),
+        ]
+        .join("\n");
+        table.load(Exchange::NASDAQ, &cp949(&nas));
+        table.load(Exchange::NYSE, &cp949(&row("NYS", "DUAL", "2", "USD", "1")));
+        table.load(
+            Exchange::SEHK,
+            &cp949(&row("HKS", "00700", "2", "HKD", "100")),
+        );
+
+        assert_eq!(table.resolve("aapl").unwrap().exchange, Exchange::NASDAQ);
+        assert!(table.resolve("DUAL").is_err());
+        assert!(table.resolve("COMP").is_err());
+        assert!(table.resolve("MSFT").is_err());
+        assert!(table
+            .check_exchange("DUAL", Exchange::BAY)
+            .unwrap()
+            .is_some());
+        assert!(matches!(
+            table.check_exchange("AAPL", Exchange::NYSE),
+            Err(KisError::Validation(msg)) if msg.contains("NASD")
+        ));
+        assert!(table
+            .check_exchange("AAPL", Exchange::TKSE)
+            .unwrap()
+            .is_none());
+
+        let tencent = table.resolve("00700").unwrap();
+        assert_eq!(tencent.currency, "HKD");
+        assert_eq!(tencent.lot_size(true), 100);
+        assert!(tencent.validate_quantity(200, true).is_ok());
+        assert!(tencent.validate_quantity(150, true).is_err());
+
+        // Reloading an exchange replaces its listings
+        table.load(Exchange::NYSE, b"");
+        assert_eq!(table.resolve("DUAL").unwrap().exchange, Exchange::NASDAQ);
+    }
+}
@@ -3,11 +3,26 @@
 //! 해외주식 주문, 잔고조회, 현재가 조회 API
 
 use super::{
-    types::{Exchange, KisApiResponse, KisError, KisResult, OrderSide},
+    field::{impl_field_report, KisField},
+    types::{
+        Amount, Exchange, KisApiResponse, KisError, KisResult, OrderSide, Price, Quantity, Rate,
+    },
     KisClient,
 };
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+/// 계좌 전체를 조회할 때의 거래소 목록 (미국은 NASD 하나로 전체 조회)
+pub const ACCOUNT_EXCHANGES: [Exchange; 7] = [
+    Exchange::NASDAQ,
+    Exchange::SEHK,
+    Exchange::SHAA,
+    Exchange::SZAA,
+    Exchange::TKSE,
+    Exchange::HASE,
+    Exchange::VNSE,
+];
+
 /// 해외주식 주문 요청
 #[derive(Debug, Clone, Serialize)]
 pub struct OverseasOrderRequest {
@@ -37,6 +52,135 @@ pub struct OverseasOrderRequest {
     pub ord_dvsn: String,
 }
 
+/// 해외주식 정정/취소 요청
+#[derive(Debug, Clone, Serialize)]
+pub struct OverseasReviseRequest {
+    /// 계좌번호 (8자리)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (2자리)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 거래소코드
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 종목코드
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 원주문번호
+    #[serde(rename = "ORGN_ODNO")]
+    pub orgn_odno: String,
+    /// 정정취소구분코드 (01:정정, 02:취소)
+    #[serde(rename = "RVSE_CNCL_DVSN_CD")]
+    pub rvse_cncl_dvsn_cd: String,
+    /// 주문수량
+    #[serde(rename = "ORD_QTY")]
+    pub ord_qty: String,
+    /// 주문단가 (취소: 0)
+    #[serde(rename = "OVRS_ORD_UNPR")]
+    pub ovrs_ord_unpr: String,
+    /// 운용사지정주문번호
+    #[serde(rename = "MGCO_APTM_ODNO")]
+    pub mgco_aptm_odno: String,
+    /// 주문서버구분코드
+    #[serde(rename = "ORD_SVR_DVSN_CD")]
+    pub ord_svr_dvsn_cd: String,
+}
+
+/// 해외주식 미체결내역 항목
+#[derive(Debug, Clone, Deserialize)]
+pub struct OverseasOpenOrder {
+    /// 주문일자
+    #[serde(rename = "ord_dt")]
+    pub ord_dt: Option<String>,
+    /// 주문번호
+    #[serde(rename = "odno")]
+    pub odno: Option<String>,
+    /// 종목코드
+    #[serde(rename = "pdno")]
+    pub pdno: Option<String>,
+    /// 종목명
+    #[serde(rename = "prdt_name")]
+    pub prdt_name: Option<String>,
+    /// 매도매수구분코드 (01:매도, 02:매수)
+    #[serde(rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: Option<String>,
+    /// 해외거래소코드
+    #[serde(rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: Option<String>,
+    /// 주문수량
+    #[serde(rename = "ft_ord_qty", default)]
+    pub ft_ord_qty: KisField<Quantity>,
+    /// 미체결수량
+    #[serde(rename = "nccs_qty", default)]
+    pub nccs_qty: KisField<Quantity>,
+}
+
+impl_field_report!(OverseasOpenOrder {
+    ft_ord_qty,
+    nccs_qty
+});
+
+/// 해외주식 주문체결내역 항목
+#[derive(Debug, Clone, Deserialize)]
+pub struct OverseasOrderHistoryItem {
+    /// 주문일자
+    #[serde(rename = "ord_dt")]
+    pub ord_dt: Option<String>,
+    /// 주문시각 (HHMMSS)
+    #[serde(rename = "ord_tmd")]
+    pub ord_tmd: Option<String>,
+    /// 주문번호
+    #[serde(rename = "odno")]
+    pub odno: Option<String>,
+    /// 매도매수구분코드 (01:매도, 02:매수)
+    #[serde(rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: Option<String>,
+    /// 종목코드
+    #[serde(rename = "pdno")]
+    pub pdno: Option<String>,
+    /// 주문수량
+    #[serde(rename = "ft_ord_qty", default)]
+    pub ft_ord_qty: KisField<Quantity>,
+    /// 주문단가
+    #[serde(rename = "ft_ord_unpr3", default)]
+    pub ft_ord_unpr3: KisField<Price>,
+    /// 체결수량
+    #[serde(rename = "ft_ccld_qty", default)]
+    pub ft_ccld_qty: KisField<Quantity>,
+    /// 체결단가
+    #[serde(rename = "ft_ccld_unpr3", default)]
+    pub ft_ccld_unpr3: KisField<Price>,
+    /// 해외거래소코드
+    #[serde(rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: Option<String>,
+    /// 국내주문일자 (KST)
+    #[serde(rename = "dmst_ord_dt", default)]
+    pub dmst_ord_dt: KisField<NaiveDate>,
+    /// 당사주문시각 (KST, HHMMSS)
+    #[serde(rename = "thco_ord_tmd")]
+    pub thco_ord_tmd: Option<String>,
+}
+
+impl_field_report!(OverseasOrderHistoryItem {
+    ft_ord_qty,
+    ft_ord_unpr3,
+    ft_ccld_qty,
+    ft_ccld_unpr3,
+    dmst_ord_dt,
+});
+
+impl OverseasOrderHistoryItem {
+    /// 매도/매수 구분
+    pub fn side(&self) -> Option<OrderSide> {
+        match self.sll_buy_dvsn_cd.as_deref() {
+            Some("01") => Some(OrderSide::Sell),
+            Some("02") => Some(OrderSide::Buy),
+            _ => None,
+        }
+    }
+}
+
 /// 해외주식 주문 응답
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OverseasOrderResponse {
@@ -70,88 +214,536 @@ pub struct OverseasBalanceItem {
     #[serde(rename = "ovrs_item_name")]
     pub ovrs_item_name: Option<String>,
     /// 보유수량
-    #[serde(rename = "ovrs_cblc_qty")]
-    pub ovrs_cblc_qty: Option<String>,
+    #[serde(rename = "ovrs_cblc_qty", default)]
+    pub ovrs_cblc_qty: KisField<Quantity>,
     /// 평균매입가격(현지화폐)
-    #[serde(rename = "frcr_pchs_amt1")]
-    pub frcr_pchs_amt1: Option<String>,
+    #[serde(rename = "frcr_pchs_amt1", default)]
+    pub frcr_pchs_amt1: KisField<Price>,
     /// 해외현재가격
-    #[serde(rename = "ovrs_now_pric1")]
-    pub ovrs_now_pric1: Option<String>,
+    #[serde(rename = "ovrs_now_pric1", default)]
+    pub ovrs_now_pric1: KisField<Price>,
     /// 평가손익금액(외화)
-    #[serde(rename = "frcr_evlu_pfls_amt")]
-    pub frcr_evlu_pfls_amt: Option<String>,
+    #[serde(rename = "frcr_evlu_pfls_amt", default)]
+    pub frcr_evlu_pfls_amt: KisField<Amount>,
     /// 평가손익율
-    #[serde(rename = "evlu_pfls_rt")]
-    pub evlu_pfls_rt: Option<String>,
+    #[serde(rename = "evlu_pfls_rt", default)]
+    pub evlu_pfls_rt: KisField<Rate>,
     /// 평가금액(외화)
-    #[serde(rename = "frcr_evlu_amt2")]
-    pub frcr_evlu_amt2: Option<String>,
+    #[serde(rename = "frcr_evlu_amt2", default)]
+    pub frcr_evlu_amt2: KisField<Amount>,
     /// 통화코드
     #[serde(rename = "tr_crcy_cd")]
     pub tr_crcy_cd: Option<String>,
 }
 
+impl_field_report!(OverseasBalanceItem {
+    ovrs_cblc_qty,
+    frcr_pchs_amt1,
+    ovrs_now_pric1,
+    frcr_evlu_pfls_amt,
+    evlu_pfls_rt,
+    frcr_evlu_amt2,
+});
+
 /// 해외주식 잔고조회 응답
 pub type OverseasBalanceResponse = Vec<OverseasBalanceItem>;
 
+/// 해외주식 체결기준현재잔고 항목 (output1)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverseasPresentBalanceItem {
+    /// 상품번호
+    #[serde(rename = "pdno")]
+    pub pdno: Option<String>,
+    /// 상품명
+    #[serde(rename = "prdt_name")]
+    pub prdt_name: Option<String>,
+    /// 해외거래소코드
+    #[serde(rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: Option<String>,
+    /// 매수통화코드
+    #[serde(rename = "buy_crcy_cd")]
+    pub buy_crcy_cd: Option<String>,
+    /// 잔고수량
+    #[serde(rename = "cblc_qty13", default)]
+    pub cblc_qty13: KisField<Quantity>,
+    /// 평균단가(외화)
+    #[serde(rename = "avg_unpr3", default)]
+    pub avg_unpr3: KisField<Price>,
+    /// 해외현재가격
+    #[serde(rename = "ovrs_now_pric1", default)]
+    pub ovrs_now_pric1: KisField<Price>,
+    /// 외화평가금액
+    #[serde(rename = "frcr_evlu_amt2", default)]
+    pub frcr_evlu_amt2: KisField<Amount>,
+    /// 평가손익금액
+    #[serde(rename = "evlu_pfls_amt2", default)]
+    pub evlu_pfls_amt2: KisField<Amount>,
+    /// 평가손익율
+    #[serde(rename = "evlu_pfls_rt1", default)]
+    pub evlu_pfls_rt1: KisField<Rate>,
+    /// 기준환율
+    #[serde(rename = "bass_exrt", default)]
+    pub bass_exrt: KisField<Price>,
+}
+
+impl_field_report!(OverseasPresentBalanceItem {
+    cblc_qty13,
+    avg_unpr3,
+    ovrs_now_pric1,
+    frcr_evlu_amt2,
+    evlu_pfls_amt2,
+    evlu_pfls_rt1,
+    bass_exrt,
+});
+
+/// 해외주식 체결기준현재잔고 통화별 예수금 (output2)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverseasCurrencyBalance {
+    /// 통화코드
+    #[serde(rename = "crcy_cd")]
+    pub crcy_cd: Option<String>,
+    /// 통화코드명
+    #[serde(rename = "crcy_cd_name")]
+    pub crcy_cd_name: Option<String>,
+    /// 외화매수금액합계
+    #[serde(rename = "frcr_buy_amt_smtl", default)]
+    pub frcr_buy_amt_smtl: KisField<Amount>,
+    /// 외화매도금액합계
+    #[serde(rename = "frcr_sll_amt_smtl", default)]
+    pub frcr_sll_amt_smtl: KisField<Amount>,
+    /// 외화예수금액
+    #[serde(rename = "frcr_dncl_amt_2", default)]
+    pub frcr_dncl_amt_2: KisField<Amount>,
+    /// 최초고시환율
+    #[serde(rename = "frst_bltn_exrt", default)]
+    pub frst_bltn_exrt: KisField<Price>,
+    /// 외화출금가능금액
+    #[serde(rename = "frcr_drwg_psbl_amt_1", default)]
+    pub frcr_drwg_psbl_amt_1: KisField<Amount>,
+    /// 외화평가금액 (원화환산)
+    #[serde(rename = "frcr_evlu_amt2", default)]
+    pub frcr_evlu_amt2: KisField<Amount>,
+}
+
+impl_field_report!(OverseasCurrencyBalance {
+    frcr_buy_amt_smtl,
+    frcr_sll_amt_smtl,
+    frcr_dncl_amt_2,
+    frst_bltn_exrt,
+    frcr_drwg_psbl_amt_1,
+    frcr_evlu_amt2,
+});
+
+/// 해외주식 체결기준현재잔고 원화환산 합계 (output3)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OverseasPresentBalanceSummary {
+    /// 매입금액합계금액 (원화)
+    #[serde(rename = "pchs_amt_smtl_amt", default)]
+    pub pchs_amt_smtl_amt: KisField<Amount>,
+    /// 평가금액합계금액 (원화)
+    #[serde(rename = "evlu_amt_smtl_amt", default)]
+    pub evlu_amt_smtl_amt: KisField<Amount>,
+    /// 총평가손익금액 (원화)
+    #[serde(rename = "tot_evlu_pfls_amt", default)]
+    pub tot_evlu_pfls_amt: KisField<Amount>,
+    /// 평가수익율
+    #[serde(rename = "evlu_erng_rt1", default)]
+    pub evlu_erng_rt1: KisField<Rate>,
+    /// 외화평가총액 (원화환산 외화예수금)
+    #[serde(rename = "frcr_evlu_tot", default)]
+    pub frcr_evlu_tot: KisField<Amount>,
+    /// 총예수금액
+    #[serde(rename = "tot_dncl_amt", default)]
+    pub tot_dncl_amt: KisField<Amount>,
+    /// 외화사용가능금액
+    #[serde(rename = "frcr_use_psbl_amt", default)]
+    pub frcr_use_psbl_amt: KisField<Amount>,
+    /// 출금가능총금액
+    #[serde(rename = "wdrw_psbl_tot_amt", default)]
+    pub wdrw_psbl_tot_amt: KisField<Amount>,
+    /// 총자산금액
+    #[serde(rename = "tot_asst_amt", default)]
+    pub tot_asst_amt: KisField<Amount>,
+}
+
+impl_field_report!(OverseasPresentBalanceSummary {
+    pchs_amt_smtl_amt,
+    evlu_amt_smtl_amt,
+    tot_evlu_pfls_amt,
+    evlu_erng_rt1,
+    frcr_evlu_tot,
+    tot_dncl_amt,
+    frcr_use_psbl_amt,
+    wdrw_psbl_tot_amt,
+    tot_asst_amt,
+});
+
+/// 해외주식 체결기준현재잔고 전체 응답
+#[derive(Debug, Clone)]
+pub struct OverseasPresentBalance {
+    /// 종목별 잔고
+    pub items: Vec<OverseasPresentBalanceItem>,
+    /// 통화별 예수금 및 적용환율
+    pub currencies: Vec<OverseasCurrencyBalance>,
+    /// 원화환산 합계
+    pub summary: OverseasPresentBalanceSummary,
+}
+
+/// 해외주식 매수가능금액 조회 응답
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverseasBuyingPower {
+    /// 거래통화코드
+    #[serde(rename = "tr_crcy_cd")]
+    pub tr_crcy_cd: Option<String>,
+    /// 주문가능외화금액
+    #[serde(rename = "ord_psbl_frcr_amt", default)]
+    pub ord_psbl_frcr_amt: KisField<Amount>,
+    /// 해외주문가능금액
+    #[serde(rename = "ovrs_ord_psbl_amt", default)]
+    pub ovrs_ord_psbl_amt: KisField<Amount>,
+    /// 주문가능수량 (외화 기준)
+    #[serde(rename = "ord_psbl_qty", default)]
+    pub ord_psbl_qty: KisField<Quantity>,
+    /// 최대주문가능수량
+    #[serde(rename = "max_ord_psbl_qty", default)]
+    pub max_ord_psbl_qty: KisField<Quantity>,
+    /// 해외최대주문가능수량 (통합증거금 포함)
+    #[serde(rename = "ovrs_max_ord_psbl_qty", default)]
+    pub ovrs_max_ord_psbl_qty: KisField<Quantity>,
+    /// 환전이후주문가능금액
+    #[serde(rename = "echm_af_ord_psbl_amt", default)]
+    pub echm_af_ord_psbl_amt: KisField<Amount>,
+    /// 환전이후주문가능수량
+    #[serde(rename = "echm_af_ord_psbl_qty", default)]
+    pub echm_af_ord_psbl_qty: KisField<Quantity>,
+    /// 환율
+    #[serde(rename = "exrt", default)]
+    pub exrt: KisField<Price>,
+}
+
+impl_field_report!(OverseasBuyingPower {
+    ord_psbl_frcr_amt,
+    ovrs_ord_psbl_amt,
+    ord_psbl_qty,
+    max_ord_psbl_qty,
+    ovrs_max_ord_psbl_qty,
+    echm_af_ord_psbl_amt,
+    echm_af_ord_psbl_qty,
+    exrt,
+});
+
+impl OverseasBuyingPower {
+    /// 통합증거금을 반영한 최대 주문가능수량
+    ///
+    /// `ovrs_max_ord_psbl_qty`가 비어 있으면 `max_ord_psbl_qty`를 사용합니다.
+    pub fn max_quantity(&self) -> Option<u64> {
+        self.ovrs_max_ord_psbl_qty
+            .get()
+            .or_else(|| self.max_ord_psbl_qty.get())
+            .map(|q| q.trunc().max(0) as u64)
+    }
+}
+
 /// 해외주식 현재가 조회 응답
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OverseasPriceResponse {
     /// 실시간조회종목코드
     #[serde(rename = "rsym")]
     pub rsym: Option<String>,
-    /// 영업일자
-    #[serde(rename = "zdiv")]
-    pub zdiv: Option<String>,
+    /// 소수점자리수
+    #[serde(rename = "zdiv", default)]
+    pub zdiv: KisField<i64>,
+    /// 전일종가
+    #[serde(rename = "base", default)]
+    pub base: KisField<Price>,
     /// 현재가
-    #[serde(rename = "last")]
-    pub last: Option<String>,
+    #[serde(rename = "last", default)]
+    pub last: KisField<Price>,
     /// 전일대비
-    #[serde(rename = "diff")]
-    pub diff: Option<String>,
+    #[serde(rename = "diff", default)]
+    pub diff: KisField<Price>,
     /// 등락율
-    #[serde(rename = "rate")]
-    pub rate: Option<String>,
+    #[serde(rename = "rate", default)]
+    pub rate: KisField<Rate>,
     /// 시가
-    #[serde(rename = "open")]
-    pub open: Option<String>,
+    #[serde(rename = "open", default)]
+    pub open: KisField<Price>,
     /// 고가
-    #[serde(rename = "high")]
-    pub high: Option<String>,
+    #[serde(rename = "high", default)]
+    pub high: KisField<Price>,
     /// 저가
-    #[serde(rename = "low")]
-    pub low: Option<String>,
+    #[serde(rename = "low", default)]
+    pub low: KisField<Price>,
     /// 거래량
-    #[serde(rename = "tvol")]
-    pub tvol: Option<String>,
+    #[serde(rename = "tvol", default)]
+    pub tvol: KisField<Quantity>,
     /// 거래대금
-    #[serde(rename = "tamt")]
-    pub tamt: Option<String>,
+    #[serde(rename = "tamt", default)]
+    pub tamt: KisField<Amount>,
+}
+
+impl_field_report!(OverseasPriceResponse {
+    zdiv,
+    base,
+    last,
+    diff,
+    rate,
+    open,
+    high,
+    low,
+    tvol,
+    tamt,
+});
+
+/// 해외주식 예약주문 요청
+///
+/// 미국은 ORD_SVR_DVSN_CD/ORD_DVSN을, 아시아는 SLL_BUY_DVSN_CD/RVSE_CNCL_DVSN_CD/PRDT_TYPE_CD를 사용합니다.
+#[derive(Debug, Clone, Serialize)]
+pub struct OverseasReservationRequest {
+    /// 계좌번호 (8자리)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (2자리)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 매도매수구분코드 (01: 매도, 02: 매수) - 아시아
+    #[serde(rename = "SLL_BUY_DVSN_CD", skip_serializing_if = "Option::is_none")]
+    pub sll_buy_dvsn_cd: Option<String>,
+    /// 정정취소구분코드 (00: 주문, 02: 취소) - 아시아
+    #[serde(rename = "RVSE_CNCL_DVSN_CD", skip_serializing_if = "Option::is_none")]
+    pub rvse_cncl_dvsn_cd: Option<String>,
+    /// 예약주문접수일자 (YYYYMMDD) - 아시아 취소
+    #[serde(rename = "RSVN_ORD_RCIT_DT", skip_serializing_if = "Option::is_none")]
+    pub rsvn_ord_rcit_dt: Option<String>,
+    /// 해외예약주문번호 - 아시아 취소
+    #[serde(rename = "OVRS_RSVN_ODNO", skip_serializing_if = "Option::is_none")]
+    pub ovrs_rsvn_odno: Option<String>,
+    /// 종목코드
+    #[serde(rename = "PDNO")]
+    pub pdno: String,
+    /// 상품유형코드 - 아시아
+    #[serde(rename = "PRDT_TYPE_CD", skip_serializing_if = "Option::is_none")]
+    pub prdt_type_cd: Option<String>,
+    /// 거래소코드
+    #[serde(rename = "OVRS_EXCG_CD")]
+    pub ovrs_excg_cd: String,
+    /// 주문수량
+    #[serde(rename = "FT_ORD_QTY")]
+    pub ft_ord_qty: String,
+    /// 주문단가
+    #[serde(rename = "FT_ORD_UNPR3")]
+    pub ft_ord_unpr3: String,
+    /// 주문서버구분코드 - 미국
+    #[serde(rename = "ORD_SVR_DVSN_CD", skip_serializing_if = "Option::is_none")]
+    pub ord_svr_dvsn_cd: Option<String>,
+    /// 주문구분 (00: 지정가, 31: MOO) - 미국
+    #[serde(rename = "ORD_DVSN", skip_serializing_if = "Option::is_none")]
+    pub ord_dvsn: Option<String>,
+}
+
+/// 해외주식 예약주문 응답
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverseasReservationResponse {
+    /// 한국거래소전송주문조직번호
+    #[serde(rename = "ODNO")]
+    pub odno: Option<String>,
+    /// 예약주문접수일자
+    #[serde(rename = "RSVN_ORD_RCIT_DT")]
+    pub rsvn_ord_rcit_dt: Option<String>,
+    /// 해외예약주문번호
+    #[serde(rename = "OVRS_RSVN_ODNO")]
+    pub ovrs_rsvn_odno: Option<String>,
+}
+
+/// 해외주식 예약주문 취소 요청 (미국)
+#[derive(Debug, Clone, Serialize)]
+pub struct OverseasReservationCancelRequest {
+    /// 계좌번호 (8자리)
+    #[serde(rename = "CANO")]
+    pub cano: String,
+    /// 계좌상품코드 (2자리)
+    #[serde(rename = "ACNT_PRDT_CD")]
+    pub acnt_prdt_cd: String,
+    /// 예약주문접수일자 (YYYYMMDD)
+    #[serde(rename = "RSYN_ORD_RCIT_DT")]
+    pub rsyn_ord_rcit_dt: String,
+    /// 해외예약주문번호
+    #[serde(rename = "OVRS_RSVN_ODNO")]
+    pub ovrs_rsvn_odno: String,
+}
+
+/// 해외주식 예약주문 조회 항목
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverseasReservationItem {
+    /// 취소여부
+    #[serde(rename = "cncl_yn")]
+    pub cncl_yn: Option<String>,
+    /// 예약주문접수일자
+    #[serde(rename = "rsvn_ord_rcit_dt", default)]
+    pub rsvn_ord_rcit_dt: KisField<NaiveDate>,
+    /// 해외예약주문번호
+    #[serde(rename = "ovrs_rsvn_odno")]
+    pub ovrs_rsvn_odno: Option<String>,
+    /// 주문번호 (전송 후)
+    #[serde(rename = "odno")]
+    pub odno: Option<String>,
+    /// 매도매수구분코드
+    #[serde(rename = "sll_buy_dvsn_cd")]
+    pub sll_buy_dvsn_cd: Option<String>,
+    /// 해외예약주문상태코드
+    #[serde(rename = "ovrs_rsvn_ord_stat_cd")]
+    pub ovrs_rsvn_ord_stat_cd: Option<String>,
+    /// 해외예약주문상태명
+    #[serde(rename = "ovrs_rsvn_ord_stat_cd_name")]
+    pub ovrs_rsvn_ord_stat_cd_name: Option<String>,
+    /// 종목코드
+    #[serde(rename = "pdno")]
+    pub pdno: Option<String>,
+    /// 종목명
+    #[serde(rename = "prdt_name")]
+    pub prdt_name: Option<String>,
+    /// 거래소코드
+    #[serde(rename = "ovrs_excg_cd")]
+    pub ovrs_excg_cd: Option<String>,
+    /// 주문수량
+    #[serde(rename = "ft_ord_qty", default)]
+    pub ft_ord_qty: KisField<Quantity>,
+    /// 주문단가
+    #[serde(rename = "ft_ord_unpr3", default)]
+    pub ft_ord_unpr3: KisField<Price>,
+    /// 체결수량
+    #[serde(rename = "ft_ccld_qty", default)]
+    pub ft_ccld_qty: KisField<Quantity>,
+    /// 미처리사유
+    #[serde(rename = "nprc_rson_text")]
+    pub nprc_rson_text: Option<String>,
 }
 
-/// 미국 주문구분 타입
+impl_field_report!(OverseasReservationItem {
+    rsvn_ord_rcit_dt,
+    ft_ord_qty,
+    ft_ord_unpr3,
+    ft_ccld_qty,
+});
+
+/// 해외주식 주문구분 (ORD_DVSN)
+///
+/// 거래소와 매수/매도 구분에 따라 허용되는 코드가 다릅니다.
+/// - 미국 매수: 00, 32(LOO), 34(LOC)
+/// - 미국 매도: 00, 31(MOO), 32(LOO), 33(MOC), 34(LOC)
+/// - 미국 주간거래: 00
+/// - 홍콩 매도: 00, 50(단주지정가)
+/// - 그 외: 00
+/// - 모의투자: 00만 가능
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub enum UsOrderType {
+pub enum OverseasOrderType {
     /// 00: 지정가
     Limit,
-    /// 31: 장개시전시간외(MOO - Market On Open)
-    PreMarketMOO,
-    /// 32: 장마감전시간외(LOO - Limit On Open)
-    PreMarketLOO,
-    /// 34: 장마감후시간외(MOC - Market On Close)
-    AfterMarketMOC,
+    /// 31: 장개시시장가 (MOO - Market On Open)
+    MarketOnOpen,
+    /// 32: 장개시지정가 (LOO - Limit On Open)
+    LimitOnOpen,
+    /// 33: 장마감시장가 (MOC - Market On Close)
+    MarketOnClose,
+    /// 34: 장마감지정가 (LOC - Limit On Close)
+    LimitOnClose,
+    /// 50: 단주지정가 (홍콩)
+    OddLotLimit,
 }
 
-impl UsOrderType {
+impl OverseasOrderType {
     pub fn code(&self) -> &'static str {
         match self {
-            UsOrderType::Limit => "00",
-            UsOrderType::PreMarketMOO => "31",
-            UsOrderType::PreMarketLOO => "32",
-            UsOrderType::AfterMarketMOC => "34",
+            OverseasOrderType::Limit => "00",
+            OverseasOrderType::MarketOnOpen => "31",
+            OverseasOrderType::LimitOnOpen => "32",
+            OverseasOrderType::MarketOnClose => "33",
+            OverseasOrderType::LimitOnClose => "34",
+            OverseasOrderType::OddLotLimit => "50",
+        }
+    }
+
+    /// Parse an ORD_DVSN code
+    pub fn from_code(code: &str) -> Option<OverseasOrderType> {
+        match code {
+            "00" => Some(OverseasOrderType::Limit),
+            "31" => Some(OverseasOrderType::MarketOnOpen),
+            "32" => Some(OverseasOrderType::LimitOnOpen),
+            "33" => Some(OverseasOrderType::MarketOnClose),
+            "34" => Some(OverseasOrderType::LimitOnClose),
+            "50" => Some(OverseasOrderType::OddLotLimit),
+            _ => None,
+        }
+    }
+
+    /// 시장가 계열 주문 여부 (주문단가 0 전송)
+    pub fn is_market(&self) -> bool {
+        matches!(
+            self,
+            OverseasOrderType::MarketOnOpen | OverseasOrderType::MarketOnClose
+        )
+    }
+
+    /// Limit price sent as OVRS_ORD_UNPR (시장가 계열은 0)
+    ///
+    /// # Arguments
+    /// * `round` - 거래소 주문단가 자릿수로 반올림 (false면 자릿수를 넘는 가격은 거부)
+    pub fn wire_price(&self, exchange: Exchange, price: Price, round: bool) -> KisResult<Price> {
+        if self.is_market() {
+            return Ok(Price::ZERO);
+        }
+        let price = if round {
+            exchange.round_price(price)
+        } else {
+            price
+        };
+        exchange.format_price(price).map(|_| price)
+    }
+
+    /// Check whether this order type is accepted for the exchange and side
+    pub fn is_allowed(&self, exchange: Exchange, side: OrderSide) -> bool {
+        match self {
+            OverseasOrderType::Limit => true,
+            // 주간거래는 지정가만 가능
+            OverseasOrderType::LimitOnOpen | OverseasOrderType::LimitOnClose => {
+                exchange.is_us() && !exchange.is_daytime()
+            }
+            OverseasOrderType::MarketOnOpen | OverseasOrderType::MarketOnClose => {
+                exchange.is_us() && !exchange.is_daytime() && side == OrderSide::Sell
+            }
+            OverseasOrderType::OddLotLimit => exchange == Exchange::SEHK && side == OrderSide::Sell,
         }
     }
+
+    /// Validate the order type for the exchange, side and environment
+    pub fn validate(&self, exchange: Exchange, side: OrderSide, is_paper: bool) -> KisResult<()> {
+        if is_paper && exchange.is_daytime() {
+            return Err(KisError::Validation(format!(
+                "US daytime trading ({}) is not available in paper trading",
+                exchange.code()
+            )));
+        }
+
+        if is_paper && *self != OverseasOrderType::Limit {
+            return Err(KisError::Validation(format!(
+                "Order type {:?} ({}) is not available in paper trading; only limit orders are supported",
+                self,
+                self.code()
+            )));
+        }
+
+        if !self.is_allowed(exchange, side) {
+            return Err(KisError::Validation(format!(
+                "Order type {:?} ({}) is not allowed for {:?} on {}",
+                self,
+                self.code(),
+                side,
+                exchange.code()
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 impl KisClient {
@@ -160,6 +752,13 @@ impl KisClient {
         let prefix = if self.config.is_paper { "V" } else { "T" };
 
         match exchange {
+            Exchange::BAQ | Exchange::BAY | Exchange::BAA => {
+                // 미국 주간거래 (실전 전용)
+                match side {
+                    OrderSide::Buy => "TTTS6036U".to_string(),
+                    OrderSide::Sell => "TTTS6037U".to_string(),
+                }
+            }
             Exchange::NYSE | Exchange::NASDAQ | Exchange::AMEX => {
                 // 미국
                 match side {
@@ -208,71 +807,296 @@ impl KisClient {
     /// 해외주식 주문
     ///
     /// # Arguments
-    /// * `exchange` - 거래소 (NASD/NYSE/AMEX/SEHK/SHAA/SZAA/TKSE/HASE/VNSE)
+    /// * `exchange` - 거래소 (NASD/NYSE/AMEX/SEHK/SHAA/SZAA/TKSE/HASE/VNSE, 주간거래 BAQ/BAY/BAA)
     /// * `symbol` - 종목코드 (예: AAPL)
     /// * `side` - 매수/매도
     /// * `quantity` - 주문수량
-    /// * `price` - 주문단가 (시장가의 경우 0.0)
-    /// * `order_type` - 주문구분 (미국 주식만 해당, 기본값 "00" 지정가)
+    /// * `price` - 주문단가 (MOO/MOC의 경우 무시되고 0으로 전송)
+    /// * `order_type` - 주문구분 (기본값 지정가, 거래소/매매구분별로 검증)
     pub fn overseas_order(
         &mut self,
         exchange: Exchange,
         symbol: &str,
         side: OrderSide,
         quantity: u32,
-        price: f64,
-        order_type: Option<UsOrderType>,
+        price: Price,
+        order_type: Option<OverseasOrderType>,
     ) -> KisResult<OverseasOrderResponse> {
+        let order_type = order_type.unwrap_or(OverseasOrderType::Limit);
+        order_type.validate(exchange, side, self.config.is_paper)?;
+
         self.ensure_auth()?;
 
         let tr_id = self.get_overseas_order_tr_id(exchange, side);
-
-        // 주문구분: 미국의 경우 order_type 사용, 그 외는 "00"
-        let ord_dvsn = if exchange.is_us() {
-            order_type.unwrap_or(UsOrderType::Limit).code()
+        let price = if order_type.is_market() {
+            Price::ZERO
         } else {
-            "00"
+            price
         };
 
         let request = OverseasOrderRequest {
             cano: self.cano().to_string(),
             acnt_prdt_cd: self.acnt_prdt_cd().to_string(),
-            ovrs_excg_cd: exchange.code().to_string(),
+            ovrs_excg_cd: exchange.order_code().to_string(),
             pdno: symbol.to_string(),
             ord_qty: quantity.to_string(),
-            ovrs_ord_unpr: if price == 0.0 {
-                "0".to_string()
-            } else {
-                format!("{:.2}", price)
-            },
+            ovrs_ord_unpr: exchange.format_price(price)?,
             ord_svr_dvsn_cd: "0".to_string(),
-            ord_dvsn: ord_dvsn.to_string(),
+            ord_dvsn: order_type.code().to_string(),
+        };
+
+        // POST 요청 (주간거래는 별도 엔드포인트)
+        let path = if exchange.is_daytime() {
+            "/uapi/overseas-stock/v1/trading/daytime-order"
+        } else {
+            "/uapi/overseas-stock/v1/trading/order"
+        };
+        let response = self.post_order(path, &tr_id, &request)?;
+
+        let api_response: KisApiResponse<OverseasOrderResponse> = response
+            .json()
+            .map_err(|e| KisError::Parse(format!("Failed to parse order response: {}", e)))?;
+
+        if !api_response.is_success() {
+            return Err(KisError::Api {
+                code: api_response.msg_cd,
+                message: api_response.msg1,
+            });
+        }
+
+        api_response
+            .output
+            .ok_or_else(|| KisError::Parse("No output in order response".to_string()))
+    }
+
+    /// 해외주식 예약주문
+    ///
+    /// 거래소 운영시간 외에 접수하여 다음 장 개시 시 전송되는 주문입니다.
+    /// 주간거래 거래소는 정규장 거래소로 접수됩니다.
+    ///
+    /// # Arguments
+    /// * `exchange` - 거래소
+    /// * `symbol` - 종목코드 (예: AAPL)
+    /// * `side` - 매수/매도
+    /// * `quantity` - 주문수량
+    /// * `price` - 주문단가 (MOO의 경우 무시되고 0으로 전송)
+    /// * `order_type` - 주문구분 (지정가, 미국 매도 MOO만 가능)
+    pub fn overseas_reserve_order(
+        &mut self,
+        exchange: Exchange,
+        symbol: &str,
+        side: OrderSide,
+        quantity: u32,
+        price: Price,
+        order_type: Option<OverseasOrderType>,
+    ) -> KisResult<OverseasReservationResponse> {
+        let exchange = exchange.regular();
+        let order_type = order_type.unwrap_or(OverseasOrderType::Limit);
+        let allowed = match order_type {
+            OverseasOrderType::Limit => true,
+            OverseasOrderType::MarketOnOpen => exchange.is_us() && side == OrderSide::Sell,
+            _ => false,
+        };
+        if !allowed {
+            return Err(KisError::Validation(format!(
+                "Order type {:?} ({}) is not allowed for {:?} reservation orders on {}",
+                order_type,
+                order_type.code(),
+                side,
+                exchange.code()
+            )));
+        }
+
+        self.ensure_auth()?;
+
+        let prefix = if self.config.is_paper { "V" } else { "T" };
+        let tr_id = if exchange.is_us() {
+            match side {
+                OrderSide::Buy => format!("{}TTT3014U", prefix),
+                OrderSide::Sell => format!("{}TTT3016U", prefix),
+            }
+        } else {
+            format!("{}TTS3013U", prefix)
         };
 
-        // Hashkey 생성
-        let hashkey = self.get_hashkey(&request)?;
+        let price = if order_type.is_market() {
+            Price::ZERO
+        } else {
+            price
+        };
+        let sll_buy_dvsn_cd = match side {
+            OrderSide::Sell => "01",
+            OrderSide::Buy => "02",
+        };
 
-        // 헤더에 hashkey 추가
-        let mut headers = self.build_headers(&tr_id)?;
-        headers.insert("hashkey".to_string(), hashkey);
+        let request = OverseasReservationRequest {
+            cano: self.cano().to_string(),
+            acnt_prdt_cd: self.acnt_prdt_cd().to_string(),
+            sll_buy_dvsn_cd: (!exchange.is_us()).then(|| sll_buy_dvsn_cd.to_string()),
+            rvse_cncl_dvsn_cd: (!exchange.is_us()).then(|| "00".to_string()),
+            rsvn_ord_rcit_dt: None,
+            ovrs_rsvn_odno: None,
+            pdno: symbol.to_string(),
+            prdt_type_cd: (!exchange.is_us()).then(|| exchange.product_type_code().to_string()),
+            ovrs_excg_cd: exchange.code().to_string(),
+            ft_ord_qty: quantity.to_string(),
+            ft_ord_unpr3: exchange.format_price(price)?,
+            ord_svr_dvsn_cd: exchange.is_us().then(|| "0".to_string()),
+            ord_dvsn: exchange.is_us().then(|| order_type.code().to_string()),
+        };
 
-        // POST 요청
-        let response = self.http.post_json(
-            "/uapi/overseas-stock/v1/trading/order",
+        let response = self.post_order(
+            "/uapi/overseas-stock/v1/trading/order-resv",
+            &tr_id,
             &request,
-            Some(headers),
-        );
+        )?;
+
+        let api_response: KisApiResponse<OverseasReservationResponse> = response
+            .json()
+            .map_err(|e| KisError::Parse(format!("Failed to parse reservation response: {}", e)))?;
 
-        if !response.is_success() {
+        if !api_response.is_success() {
             return Err(KisError::Api {
-                code: response.status.to_string(),
-                message: response.error.unwrap_or_else(|| response.body.clone()),
+                code: api_response.msg_cd,
+                message: api_response.msg1,
             });
         }
 
+        api_response
+            .output
+            .ok_or_else(|| KisError::Parse("No output in reservation response".to_string()))
+    }
+
+    /// 해외주식 미체결내역 조회
+    ///
+    /// 미국은 `NASD` 하나로 NYSE/AMEX 주문까지 함께 조회됩니다.
+    pub fn overseas_open_orders(
+        &mut self,
+        exchange: Exchange,
+    ) -> KisResult<Vec<OverseasOpenOrder>> {
+        self.ensure_auth()?;
+
+        let tr_id = if self.config.is_paper {
+            "VTTS3018R"
+        } else {
+            "TTTS3018R"
+        };
+        let exchange_code = if exchange.is_us() {
+            Exchange::NASDAQ.code()
+        } else {
+            exchange.code()
+        };
+
+        let query = format!(
+            "CANO={}&ACNT_PRDT_CD={}&OVRS_EXCG_CD={}&SORT_SQN=DS&CTX_AREA_FK200=&CTX_AREA_NK200=",
+            self.cano(),
+            self.acnt_prdt_cd(),
+            exchange_code
+        );
+
+        let orders = self.get_list(
+            "/uapi/overseas-stock/v1/trading/inquire-nccs",
+            tr_id,
+            &query,
+            "open orders",
+            |r: KisApiResponse<Vec<OverseasOpenOrder>>| r.output,
+        )?;
+
+        Ok(orders
+            .into_iter()
+            .filter(|order| order.nccs_qty.or_zero() > Quantity::ZERO)
+            .collect())
+    }
+
+    /// 해외주식 일자별 주문체결내역 조회 (전체 거래소, 체결/미체결 포함)
+    ///
+    /// # Arguments
+    /// * `date` - 주문일자 (YYYYMMDD)
+    pub fn overseas_orders_on(&mut self, date: &str) -> KisResult<Vec<OverseasOrderHistoryItem>> {
+        self.ensure_auth()?;
+
+        let tr_id = if self.config.is_paper {
+            "VTTS3035R"
+        } else {
+            "TTTS3035R"
+        };
+        // 전체 종목/거래소: 실전은 "%"(URL 인코딩), 모의투자는 공백만 허용
+        let all = if self.config.is_paper { "" } else { "%25" };
+        let query = format!(
+            "CANO={}&ACNT_PRDT_CD={}&PDNO={}&ORD_STRT_DT={}&ORD_END_DT={}&SLL_BUY_DVSN=00&CCLD_NCCS_DVSN=00&OVRS_EXCG_CD={}&SORT_SQN=DS&ORD_DT=&ORD_GNO_BRNO=&ODNO=&CTX_AREA_NK200=&CTX_AREA_FK200=",
+            self.cano(),
+            self.acnt_prdt_cd(),
+            all,
+            date,
+            date,
+            all
+        );
+
+        self.get_list(
+            "/uapi/overseas-stock/v1/trading/inquire-ccnl",
+            tr_id,
+            &query,
+            "order history",
+            |r: KisApiResponse<Vec<OverseasOrderHistoryItem>>| r.output,
+        )
+    }
+
+    /// 해외주식 주문 취소
+    ///
+    /// # Arguments
+    /// * `exchange` - 원주문 거래소 (주간거래 주문은 BAQ/BAY/BAA → 주간거래 정정취소, 실전 전용)
+    /// * `symbol` - 종목코드
+    /// * `order_no` - 원주문번호
+    /// * `quantity` - 취소수량 (미체결수량)
+    pub fn overseas_cancel_order(
+        &mut self,
+        exchange: Exchange,
+        symbol: &str,
+        order_no: &str,
+        quantity: u32,
+    ) -> KisResult<OverseasOrderResponse> {
+        self.ensure_auth()?;
+
+        if exchange.is_daytime() && self.config.is_paper {
+            return Err(KisError::Validation(
+                "Daytime (주간거래) orders are not supported in paper trading".to_string(),
+            ));
+        }
+
+        let prefix = if self.config.is_paper { "V" } else { "T" };
+        let tr_id = match exchange.regular() {
+            _ if exchange.is_daytime() => "TTTS6038U".to_string(),
+            Exchange::SEHK => format!("{}TTS1003U", prefix),
+            Exchange::SHAA => format!("{}TTS0302U", prefix),
+            Exchange::SZAA => format!("{}TTS0306U", prefix),
+            Exchange::TKSE => format!("{}TTS0309U", prefix),
+            Exchange::HASE | Exchange::VNSE => format!("{}TTS0312U", prefix),
+            _ => format!("{}TTT1004U", prefix),
+        };
+
+        let request = OverseasReviseRequest {
+            cano: self.cano().to_string(),
+            acnt_prdt_cd: self.acnt_prdt_cd().to_string(),
+            ovrs_excg_cd: exchange.order_code().to_string(),
+            pdno: symbol.to_string(),
+            orgn_odno: order_no.to_string(),
+            rvse_cncl_dvsn_cd: "02".to_string(), // 취소
+            ord_qty: quantity.to_string(),
+            ovrs_ord_unpr: "0".to_string(),
+            mgco_aptm_odno: String::new(),
+            ord_svr_dvsn_cd: "0".to_string(),
+        };
+
+        let path = if exchange.is_daytime() {
+            "/uapi/overseas-stock/v1/trading/daytime-order-rvsecncl"
+        } else {
+            "/uapi/overseas-stock/v1/trading/order-rvsecncl"
+        };
+        let response = self.post_order(path, &tr_id, &request)?;
+
         let api_response: KisApiResponse<OverseasOrderResponse> = response
             .json()
-            .map_err(|e| KisError::Parse(format!("Failed to parse order response: {}", e)))?;
+            .map_err(|e| KisError::Parse(format!("Failed to parse cancel response: {}", e)))?;
 
         if !api_response.is_success() {
             return Err(KisError::Api {
@@ -283,7 +1107,151 @@ impl KisClient {
 
         api_response
             .output
-            .ok_or_else(|| KisError::Parse("No output in order response".to_string()))
+            .ok_or_else(|| KisError::Parse("No output in cancel response".to_string()))
+    }
+
+    /// 해외주식 예약주문 취소
+    ///
+    /// 미국은 예약주문접수취소(TTTT3017U), 아시아는 예약주문접수(TTTS3013U)에
+    /// 정정취소구분코드 02로 취소한다.
+    ///
+    /// # Arguments
+    /// * `exchange` - 예약주문을 조회한 거래소
+    /// * `reservation` - 예약주문 조회 항목
+    pub fn overseas_cancel_reservation(
+        &mut self,
+        exchange: Exchange,
+        reservation: &OverseasReservationItem,
+    ) -> KisResult<OverseasReservationResponse> {
+        let (Some(receipt_date), Some(reservation_no)) = (
+            reservation.rsvn_ord_rcit_dt.get(),
+            reservation.ovrs_rsvn_odno.as_deref(),
+        ) else {
+            return Err(KisError::Validation(
+                "Reservation has no receipt date or reservation number".to_string(),
+            ));
+        };
+        let receipt_date = receipt_date.format("%Y%m%d").to_string();
+        let exchange = reservation
+            .ovrs_excg_cd
+            .as_deref()
+            .and_then(Exchange::from_code)
+            .unwrap_or(exchange)
+            .regular();
+
+        self.ensure_auth()?;
+
+        let prefix = if self.config.is_paper { "V" } else { "T" };
+        let response = if exchange.is_us() {
+            let request = OverseasReservationCancelRequest {
+                cano: self.cano().to_string(),
+                acnt_prdt_cd: self.acnt_prdt_cd().to_string(),
+                rsyn_ord_rcit_dt: receipt_date,
+                ovrs_rsvn_odno: reservation_no.to_string(),
+            };
+            self.post_order(
+                "/uapi/overseas-stock/v1/trading/order-resv-ccnl",
+                &format!("{}TTT3017U", prefix),
+                &request,
+            )?
+        } else {
+            let (Some(symbol), Some(side), Some(quantity), Some(price)) = (
+                reservation.pdno.as_deref(),
+                reservation.sll_buy_dvsn_cd.as_deref(),
+                reservation.ft_ord_qty.get().and_then(|q| q.to_order_qty()),
+                reservation.ft_ord_unpr3.get(),
+            ) else {
+                return Err(KisError::Validation(format!(
+                    "Reservation {} is missing order details required to cancel it",
+                    reservation_no
+                )));
+            };
+            let request = OverseasReservationRequest {
+                cano: self.cano().to_string(),
+                acnt_prdt_cd: self.acnt_prdt_cd().to_string(),
+                sll_buy_dvsn_cd: Some(side.to_string()),
+                rvse_cncl_dvsn_cd: Some("02".to_string()),
+                rsvn_ord_rcit_dt: Some(receipt_date),
+                ovrs_rsvn_odno: Some(reservation_no.to_string()),
+                pdno: symbol.to_string(),
+                prdt_type_cd: Some(exchange.product_type_code().to_string()),
+                ovrs_excg_cd: exchange.code().to_string(),
+                ft_ord_qty: quantity.to_string(),
+                ft_ord_unpr3: exchange.format_price(price)?,
+                ord_svr_dvsn_cd: None,
+                ord_dvsn: None,
+            };
+            self.post_order(
+                "/uapi/overseas-stock/v1/trading/order-resv",
+                &format!("{}TTS3013U", prefix),
+                &request,
+            )?
+        };
+
+        let api_response: KisApiResponse<OverseasReservationResponse> =
+            response.json().map_err(|e| {
+                KisError::Parse(format!(
+                    "Failed to parse reservation cancel response: {}",
+                    e
+                ))
+            })?;
+
+        if !api_response.is_success() {
+            return Err(KisError::Api {
+                code: api_response.msg_cd,
+                message: api_response.msg1,
+            });
+        }
+
+        api_response
+            .output
+            .ok_or_else(|| KisError::Parse("No output in reservation cancel response".to_string()))
+    }
+
+    /// 해외주식 예약주문 조회 (실전 전용)
+    ///
+    /// # Arguments
+    /// * `exchange` - 거래소 (미국/아시아에 따라 TR_ID 구분)
+    /// * `start_date` - 조회시작일자 (YYYYMMDD)
+    /// * `end_date` - 조회종료일자 (YYYYMMDD)
+    pub fn overseas_reservation_list(
+        &mut self,
+        exchange: Exchange,
+        start_date: &str,
+        end_date: &str,
+    ) -> KisResult<Vec<OverseasReservationItem>> {
+        if self.config.is_paper {
+            return Err(KisError::Validation(
+                "Reservation order inquiry is not available in paper trading".to_string(),
+            ));
+        }
+
+        self.ensure_auth()?;
+
+        let exchange = exchange.regular();
+        let tr_id = if exchange.is_us() {
+            "TTTT3039R"
+        } else {
+            "TTTS3014R"
+        };
+
+        let query = format!(
+            "CANO={}&ACNT_PRDT_CD={}&INQR_STRT_DT={}&INQR_END_DT={}&INQR_DVSN_CD=00&PRDT_TYPE_CD={}&OVRS_EXCG_CD={}&CTX_AREA_FK200=&CTX_AREA_NK200=",
+            self.cano(),
+            self.acnt_prdt_cd(),
+            start_date,
+            end_date,
+            exchange.product_type_code(),
+            exchange.code()
+        );
+
+        self.get_list(
+            "/uapi/overseas-stock/v1/trading/order-resv-list",
+            tr_id,
+            &query,
+            "reservation list response",
+            |r: KisApiResponse<Vec<OverseasReservationItem>>| r.output,
+        )
     }
 
     /// 해외주식 잔고조회
@@ -323,11 +1291,146 @@ impl KisClient {
         Ok(api_response.output1.unwrap_or_default())
     }
 
-    /// 해외주식 현재가 조회
+    /// 해외주식 체결기준현재잔고
+    ///
+    /// 통화별 외화예수금, 적용환율과 원화환산 평가금액을 함께 반환합니다.
+    pub fn overseas_present_balance(&mut self) -> KisResult<OverseasPresentBalance> {
+        self.ensure_auth()?;
+
+        let tr_id = if self.config.is_paper {
+            "VTRP6504R"
+        } else {
+            "CTRP6504R"
+        };
+
+        // WCRC_FRCR_DVSN_CD=01: 원화 기준, NATN_CD=000: 전체 국가
+        let query = format!(
+            "CANO={}&ACNT_PRDT_CD={}&WCRC_FRCR_DVSN_CD=01&NATN_CD=000&TR_MKET_CD=00&INQR_DVSN_CD=00",
+            self.cano(),
+            self.acnt_prdt_cd()
+        );
+
+        let response = self.get(
+            "/uapi/overseas-stock/v1/trading/inquire-present-balance",
+            tr_id,
+            Some(&query),
+        )?;
+
+        #[derive(Debug, Deserialize)]
+        struct PresentBalanceApiResponse {
+            rt_cd: String,
+            msg_cd: String,
+            msg1: String,
+            output1: Option<Vec<OverseasPresentBalanceItem>>,
+            output2: Option<Vec<OverseasCurrencyBalance>>,
+            output3: Option<OverseasPresentBalanceSummary>,
+        }
+
+        let api_response: PresentBalanceApiResponse = response.json().map_err(|e| {
+            KisError::Parse(format!("Failed to parse present balance response: {}", e))
+        })?;
+
+        if api_response.rt_cd != "0" {
+            return Err(KisError::Api {
+                code: api_response.msg_cd,
+                message: api_response.msg1,
+            });
+        }
+
+        Ok(OverseasPresentBalance {
+            items: api_response.output1.unwrap_or_default(),
+            currencies: api_response.output2.unwrap_or_default(),
+            summary: api_response.output3.unwrap_or_default(),
+        })
+    }
+
+    /// 해외주식 매수가능금액 조회
     ///
     /// # Arguments
     /// * `exchange` - 거래소
     /// * `symbol` - 종목코드 (예: AAPL)
+    /// * `price` - 주문단가 (조회용이므로 거래소 자릿수로 반올림)
+    pub fn overseas_buying_power(
+        &mut self,
+        exchange: Exchange,
+        symbol: &str,
+        price: Price,
+    ) -> KisResult<OverseasBuyingPower> {
+        self.ensure_auth()?;
+
+        let tr_id = if self.config.is_paper {
+            "VTTS3007R"
+        } else {
+            "TTTS3007R"
+        };
+
+        let query = format!(
+            "CANO={}&ACNT_PRDT_CD={}&OVRS_EXCG_CD={}&OVRS_ORD_UNPR={}&ITEM_CD={}",
+            self.cano(),
+            self.acnt_prdt_cd(),
+            exchange.order_code(),
+            exchange.format_price(exchange.round_price(price))?,
+            symbol
+        );
+
+        let response = self.get(
+            "/uapi/overseas-stock/v1/trading/inquire-psamount",
+            tr_id,
+            Some(&query),
+        )?;
+
+        let api_response: KisApiResponse<OverseasBuyingPower> = response.json().map_err(|e| {
+            KisError::Parse(format!("Failed to parse buying power response: {}", e))
+        })?;
+
+        if !api_response.is_success() {
+            return Err(KisError::Api {
+                code: api_response.msg_cd,
+                message: api_response.msg1,
+            });
+        }
+
+        api_response
+            .output
+            .ok_or_else(|| KisError::Parse("No output in buying power response".to_string()))
+    }
+
+    /// 해외주식 매수 전 주문가능수량 검증
+    ///
+    /// 주문수량이 통합증거금 기준 최대 주문가능수량을 초과하면
+    /// `KisError::Validation`을 반환합니다.
+    pub fn overseas_check_buying_power(
+        &mut self,
+        exchange: Exchange,
+        symbol: &str,
+        quantity: u32,
+        price: Price,
+    ) -> KisResult<()> {
+        let buying_power = self.overseas_buying_power(exchange, symbol, price)?;
+
+        let max_quantity = buying_power.max_quantity().ok_or_else(|| {
+            KisError::Parse("No orderable quantity in buying power response".to_string())
+        })?;
+
+        if u64::from(quantity) > max_quantity {
+            return Err(KisError::Validation(format!(
+                "Insufficient buying power for {} {}: requested {}, max orderable {} ({} available)",
+                exchange.code(),
+                symbol,
+                quantity,
+                max_quantity,
+                buying_power.ovrs_ord_psbl_amt.or_zero()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// 해외주식 현재가 조회
+    ///
+    /// # Arguments
+    /// * `exchange` - 거래소 (주간거래 거래소 지정 시 주간거래 시세)
+    /// * `symbol` - 종목코드 (예: AAPL)
     pub fn overseas_price(
         &mut self,
         exchange: Exchange,
@@ -338,7 +1441,8 @@ impl KisClient {
         let tr_id = "HHDFS00000300";
 
         // 쿼리 파라미터 구성
-        let query = format!("AUTH=&EXCD={}&SYMB={}", exchange.code(), symbol);
+        // 주간거래 거래소(BAQ/BAY/BAA)는 주간 시세를 반환
+        let query = format!("AUTH=&EXCD={}&SYMB={}", exchange.quote_code(), symbol);
 
         let response = self.get(
             "/uapi/overseas-stock/v1/quotations/price",
@@ -394,6 +1498,15 @@ mod tests {
             client.get_overseas_order_tr_id(Exchange::SEHK, OrderSide::Buy),
             "TTTS1002U"
         );
+        // 미국 주간거래
+        assert_eq!(
+            client.get_overseas_order_tr_id(Exchange::BAQ, OrderSide::Buy),
+            "TTTS6036U"
+        );
+        assert_eq!(
+            client.get_overseas_order_tr_id(Exchange::BAY, OrderSide::Sell),
+            "TTTS6037U"
+        );
 
         // 모의 모드
         let config_paper = KisConfig::new(
@@ -412,10 +1525,316 @@ mod tests {
     }
 
     #[test]
-    fn test_us_order_type_codes() {
-        assert_eq!(UsOrderType::Limit.code(), "00");
-        assert_eq!(UsOrderType::PreMarketMOO.code(), "31");
-        assert_eq!(UsOrderType::PreMarketLOO.code(), "32");
-        assert_eq!(UsOrderType::AfterMarketMOC.code(), "34");
+    fn test_overseas_order_type_codes() {
+        assert_eq!(OverseasOrderType::Limit.code(), "00");
+        assert_eq!(OverseasOrderType::MarketOnOpen.code(), "31");
+        assert_eq!(OverseasOrderType::LimitOnOpen.code(), "32");
+        assert_eq!(OverseasOrderType::MarketOnClose.code(), "33");
+        assert_eq!(OverseasOrderType::LimitOnClose.code(), "34");
+        assert_eq!(OverseasOrderType::OddLotLimit.code(), "50");
+    }
+
+    #[test]
+    fn test_overseas_order_type_validation() {
+        // 미국 매수: MOO/MOC 불가, LOO/LOC 가능
+        assert!(OverseasOrderType::LimitOnClose.is_allowed(Exchange::NASDAQ, OrderSide::Buy));
+        assert!(!OverseasOrderType::MarketOnOpen.is_allowed(Exchange::NASDAQ, OrderSide::Buy));
+        assert!(!OverseasOrderType::MarketOnClose.is_allowed(Exchange::NYSE, OrderSide::Buy));
+        // 미국 매도: 전부 가능
+        assert!(OverseasOrderType::MarketOnOpen.is_allowed(Exchange::NYSE, OrderSide::Sell));
+        assert!(OverseasOrderType::MarketOnClose.is_allowed(Exchange::AMEX, OrderSide::Sell));
+        // 미국 외 거래소: 지정가만 (홍콩 매도 단주 제외)
+        assert!(!OverseasOrderType::LimitOnClose.is_allowed(Exchange::TKSE, OrderSide::Buy));
+        assert!(OverseasOrderType::OddLotLimit.is_allowed(Exchange::SEHK, OrderSide::Sell));
+        assert!(!OverseasOrderType::OddLotLimit.is_allowed(Exchange::SEHK, OrderSide::Buy));
+
+        // 주간거래: 지정가만, 모의투자 불가
+        assert!(!OverseasOrderType::LimitOnClose.is_allowed(Exchange::BAQ, OrderSide::Buy));
+        assert!(OverseasOrderType::Limit
+            .validate(Exchange::BAQ, OrderSide::Buy, false)
+            .is_ok());
+        assert!(OverseasOrderType::Limit
+            .validate(Exchange::BAQ, OrderSide::Buy, true)
+            .is_err());
+
+        // 모의투자는 지정가만
+        assert!(OverseasOrderType::Limit
+            .validate(Exchange::NASDAQ, OrderSide::Buy, true)
+            .is_ok());
+        assert!(OverseasOrderType::LimitOnClose
+            .validate(Exchange::NASDAQ, OrderSide::Buy, true)
+            .is_err());
+        assert!(OverseasOrderType::LimitOnClose
+            .validate(Exchange::NASDAQ, OrderSide::Buy, false)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_reservation_request_serialization() {
+        // 미국: ORD_DVSN 포함, 아시아 전용 필드 생략
+        let request = OverseasReservationRequest {
+            cano: "12345678".to_string(),
+            acnt_prdt_cd: "01".to_string(),
+            sll_buy_dvsn_cd: None,
+            rvse_cncl_dvsn_cd: None,
+            rsvn_ord_rcit_dt: None,
+            ovrs_rsvn_odno: None,
+            pdno: "AAPL".to_string(),
+            prdt_type_cd: None,
+            ovrs_excg_cd: "NASD".to_string(),
+            ft_ord_qty: "1".to_string(),
+            ft_ord_unpr3: "180.00".to_string(),
+            ord_svr_dvsn_cd: Some("0".to_string()),
+            ord_dvsn: Some("00".to_string()),
+        };
+
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"FT_ORD_UNPR3\":\"180.00\""));
+        assert!(json.contains("\"ORD_DVSN\":\"00\""));
+        assert!(!json.contains("SLL_BUY_DVSN_CD"));
+        assert!(!json.contains("PRDT_TYPE_CD"));
+    }
+
+    #[test]
+    fn test_buying_power_max_quantity() {
+        let json = r#"{
+            "tr_crcy_cd": "USD",
+            "ord_psbl_qty": "3",
+            "max_ord_psbl_qty": "5",
+            "ovrs_max_ord_psbl_qty": "12"
+        }"#;
+        let buying_power: OverseasBuyingPower = serde_json::from_str(json).unwrap();
+        assert_eq!(buying_power.max_quantity(), Some(12));
+
+        let json = r#"{ "max_ord_psbl_qty": "5" }"#;
+        let buying_power: OverseasBuyingPower = serde_json::from_str(json).unwrap();
+        assert_eq!(buying_power.max_quantity(), Some(5));
+    }
+
+    #[test]
+    fn test_overseas_rows_keep_unparsable_fields() {
+        use crate::kis::field::FieldReport;
+
+        let json = r#"{
+            "ovrs_pdno": "AAPL",
+            "ovrs_cblc_qty": "1O",
+            "ovrs_now_pric1": "227.5200",
+            "evlu_pfls_rt": "-1.25"
+        }"#;
+        let item: OverseasBalanceItem = serde_json::from_str(json).unwrap();
+        assert_eq!(item.ovrs_pdno.as_deref(), Some("AAPL"));
+        assert!(item.ovrs_cblc_qty.is_invalid());
+        assert_eq!(item.ovrs_now_pric1.get(), "227.52".parse().ok());
+        assert_eq!(
+            item.invalid_fields(),
+            vec![("ovrs_cblc_qty", "1O".to_string())]
+        );
+
+        let json = r#"{ "last": "227.52", "tvol": "51234567", "rate": "+0.85", "open": "" }"#;
+        let quote: OverseasPriceResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(quote.tvol.get(), Some(Quantity::from_int(51_234_567)));
+        assert!(quote.open.is_blank());
+        assert!(quote.invalid_fields().is_empty());
+    }
+
+    #[test]
+    fn test_currency_balance_deserialization() {
+        let json = r#"{
+            "crcy_cd": "USD",
+            "crcy_cd_name": "미국 달러",
+            "frcr_dncl_amt_2": "1523.45",
+            "frst_bltn_exrt": "1385.50000000"
+        }"#;
+
+        let balance: OverseasCurrencyBalance = serde_json::from_str(json).unwrap();
+        assert_eq!(balance.crcy_cd.as_deref(), Some("USD"));
+        assert_eq!(balance.frcr_dncl_amt_2.get(), "1523.45".parse().ok());
+        assert_eq!(balance.frst_bltn_exrt.get(), "1385.5".parse().ok());
+        assert!(balance.frcr_drwg_psbl_amt_1.is_blank());
+    }
+
+    #[test]
+    fn test_daytime_session_selection() {
+        use chrono::NaiveDate;
+
+        // 2025-03-05 (수)
+        let day = NaiveDate::from_ymd_opt(2025, 3, 5).unwrap();
+        let at = |h, m| day.and_hms_opt(h, m, 0).unwrap();
+
+        assert_eq!(Exchange::NASDAQ.for_session_at(at(11, 0)), Exchange::BAQ);
+        assert_eq!(Exchange::NYSE.for_session_at(at(16, 59)), Exchange::BAY);
+        assert_eq!(
+            Exchange::NASDAQ.for_session_at(at(23, 40)),
+            Exchange::NASDAQ
+        );
+        assert_eq!(Exchange::BAQ.for_session_at(at(8, 0)), Exchange::NASDAQ);
+        assert_eq!(Exchange::SEHK.for_session_at(at(11, 0)), Exchange::SEHK);
+
+        // 2025-03-08 (토)
+        let saturday = NaiveDate::from_ymd_opt(2025, 3, 8)
+            .unwrap()
+            .and_hms_opt(11, 0, 0)
+            .unwrap();
+        assert_eq!(Exchange::AMEX.for_session_at(saturday), Exchange::AMEX);
+
+        assert_eq!(Exchange::BAA.order_code(), "AMEX");
+        assert_eq!(Exchange::NASDAQ.quote_code(), "NAS");
+    }
+
+    #[test]
+    fn test_exchange_open_hours() {
+        use chrono::NaiveDate;
+
+        let at = |d, h, m| {
+            NaiveDate::from_ymd_opt(2025, 3, d)
+                .unwrap()
+                .and_hms_opt(h, m, 0)
+                .unwrap()
+        };
+
+        // 미국 정규장 (2025-03-03은 서머타임 전 표준시): 월 23:30 개장 ~ 화 06:00 마감
+        assert!(!Exchange::NASDAQ.is_open_at(at(3, 23, 29)));
+        assert!(Exchange::NASDAQ.is_open_at(at(3, 23, 45)));
+        assert!(Exchange::NASDAQ.is_open_at(at(4, 5, 59)));
+        assert!(!Exchange::NASDAQ.is_open_at(at(4, 6, 0)));
+        assert!(!Exchange::NASDAQ.is_open_at(at(4, 12, 0)));
+        // 월요일 새벽은 직전 금요일 세션이 아님 (일요일 장 없음)
+        assert!(!Exchange::NYSE.is_open_at(at(3, 2, 0)));
+        // 토요일 새벽은 금요일 세션
+        assert!(Exchange::NYSE.is_open_at(at(8, 2, 0)));
+
+        // 도쿄 (KST = JST)
+        assert!(Exchange::TKSE.is_open_at(at(5, 9, 0)));
+        assert!(!Exchange::TKSE.is_open_at(at(5, 15, 30)));
+    }
+
+    #[test]
+    fn test_exchange_sessions_dst_and_holidays() {
+        use chrono::{NaiveDate, NaiveDateTime};
+
+        let date = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        let at = |m, d, h, min| -> NaiveDateTime { date(m, d).and_hms_opt(h, min, 0).unwrap() };
+
+        // 서머타임 시작(3/8) 전후로 미국 정규장이 1시간 당겨짐
+        let friday = Exchange::NASDAQ.session_on(date(3, 6)).unwrap();
+        assert_eq!(
+            (friday.open, friday.close),
+            (at(3, 6, 23, 30), at(3, 7, 6, 0))
+        );
+        let monday = Exchange::NYSE.session_on(date(3, 9)).unwrap();
+        assert_eq!(
+            (monday.open, monday.close),
+            (at(3, 9, 22, 30), at(3, 10, 5, 0))
+        );
+        assert_eq!(Exchange::NYSE.trade_date_at(at(3, 10, 3, 0)), date(3, 9));
+        assert!(Exchange::NYSE.is_open_at(at(3, 9, 22, 45)));
+        assert!(!Exchange::NYSE.is_open_at(at(3, 10, 5, 30)));
+
+        // 주간거래: 표준시 10:00~18:00, 서머타임 09:00~17:00 KST
+        let daytime = Exchange::BAQ.session_on(date(3, 9)).unwrap();
+        assert_eq!(
+            (daytime.open, daytime.close),
+            (at(3, 9, 9, 0), at(3, 9, 17, 0))
+        );
+        assert_eq!(
+            Exchange::BAQ.session_on(date(3, 6)).unwrap().open,
+            at(3, 6, 10, 0)
+        );
+
+        // 휴장일, 조기폐장
+        assert!(Exchange::NASDAQ.session_on(date(7, 3)).is_none());
+        assert!(Exchange::BAY.is_holiday(date(7, 3)));
+        assert_eq!(
+            Exchange::NASDAQ.for_session_at(at(7, 3, 11, 0)),
+            Exchange::NASDAQ
+        );
+        assert_eq!(
+            Exchange::NASDAQ
+                .next_session_after(at(7, 3, 12, 0))
+                .unwrap()
+                .open,
+            at(7, 6, 22, 30)
+        );
+        let black_friday = Exchange::AMEX.session_on(date(11, 27)).unwrap();
+        assert!(black_friday.early_close);
+        assert_eq!(black_friday.close, at(11, 28, 3, 0));
+
+        // 점심시간은 장중으로 보되 구분 가능
+        let hk = Exchange::SEHK.session_on(date(3, 5)).unwrap();
+        assert_eq!((hk.open, hk.close), (at(3, 5, 10, 30), at(3, 5, 17, 0)));
+        assert!(hk.is_break_at(at(3, 5, 13, 30)));
+        assert!(Exchange::SEHK.is_open_at(at(3, 5, 13, 30)));
+        assert!(Exchange::SEHK.session_on(date(2, 17)).is_none());
+        assert_eq!(
+            Exchange::VNSE.session_on(date(3, 5)).unwrap().close,
+            at(3, 5, 16, 45)
+        );
+        assert_eq!(
+            Exchange::SHAA.session_on(date(3, 5)).unwrap().close,
+            at(3, 5, 16, 0)
+        );
+    }
+
+    #[test]
+    fn test_daytime_orders_cancel_through_daytime_endpoint() {
+        use crate::http::mock;
+
+        let mut client = crate::kis::tests::test_client(false);
+        for exchange in [Exchange::BAQ, Exchange::NASDAQ] {
+            mock::respond(r#"{"HASH":"hash"}"#);
+            mock::respond(r#"{"rt_cd":"0","msg_cd":"","msg1":"","output":{}}"#);
+            client
+                .overseas_cancel_order(exchange, "AAPL", "0030000001", 1)
+                .unwrap();
+        }
+
+        let requests = mock::requests();
+        assert!(requests[1].url.ends_with("/trading/daytime-order-rvsecncl"));
+        assert_eq!(requests[1].headers["tr_id"], "TTTS6038U");
+        assert!(requests[1]
+            .body
+            .as_deref()
+            .unwrap()
+            .contains(r#""OVRS_EXCG_CD":"NASD""#));
+        assert!(requests[3].url.ends_with("/trading/order-rvsecncl"));
+        assert_eq!(requests[3].headers["tr_id"], "TTTT1004U");
+
+        let mut paper = crate::kis::tests::test_client(true);
+        assert!(paper
+            .overseas_cancel_order(Exchange::BAQ, "AAPL", "0030000001", 1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_cancel_reservation_routes_by_market() {
+        use crate::http::mock;
+
+        let json = r#"[
+            {"rsvn_ord_rcit_dt": "20260302", "ovrs_rsvn_odno": "0001", "ovrs_excg_cd": "NASD",
+             "sll_buy_dvsn_cd": "02", "pdno": "AAPL", "ft_ord_qty": "1", "ft_ord_unpr3": "180"},
+            {"rsvn_ord_rcit_dt": "20260302", "ovrs_rsvn_odno": "0002", "ovrs_excg_cd": "SEHK",
+             "sll_buy_dvsn_cd": "01", "pdno": "00700", "ft_ord_qty": "100", "ft_ord_unpr3": "400.2"}
+        ]"#;
+        let items: Vec<OverseasReservationItem> = serde_json::from_str(json).unwrap();
+
+        let mut client = crate::kis::tests::test_client(false);
+        for item in &items {
+            mock::respond(r#"{"HASH":"hash"}"#);
+            mock::respond(r#"{"rt_cd":"0","msg_cd":"","msg1":"","output":{}}"#);
+            client
+                .overseas_cancel_reservation(Exchange::NASDAQ, item)
+                .unwrap();
+        }
+
+        let requests = mock::requests();
+        assert!(requests[1].url.ends_with("/trading/order-resv-ccnl"));
+        assert_eq!(requests[1].headers["tr_id"], "TTTT3017U");
+        assert!(requests[3].url.ends_with("/trading/order-resv"));
+        assert_eq!(requests[3].headers["tr_id"], "TTTS3013U");
+        let body = requests[3].body.as_deref().unwrap();
+        assert!(body.contains(r#""RVSE_CNCL_DVSN_CD":"02""#));
+        assert!(body.contains(r#""OVRS_RSVN_ODNO":"0002""#));
+        assert!(body.contains(r#""OVRS_EXCG_CD":"SEHK""#));
+        assert!(body.contains(r#""FT_ORD_UNPR3":"400.200""#));
     }
 }
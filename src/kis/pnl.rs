@@ -0,0 +1,380 @@
+//! Intraday P&L tracking
+//!
+//! 계좌 손익은 전일종가 기준입니다. 종목별로 `현재 보유수량 × 현재가 + 당일 매도금액 - 전일 보유수량 ×
+//! 전일종가 - 당일 매수금액`이며, 전일 보유수량은 현재 보유수량에서 당일 매수를 빼고 매도를 더해
+//! 구합니다. 따라서 플러그인이 언제 처음 조회했는지와 무관하게 당일 모든 체결이 반영됩니다 (수수료와
+//! 세금 제외). 페르소나 손익은 해당 페르소나 주문의 당일 체결을 현재가로 평가한 매매손익입니다
+//! (매수: (현재가 - 체결가) × 수량, 매도: (체결가 - 현재가) × 수량).
+//!
+//! 해외주식도 같은 식을 거래소 현지 거래일의 체결과 시세의 전일종가로 외화 기준으로 계산한 뒤 기준환율로
+//! 원화 환산합니다. 기준가를 메모리에 두지 않으므로 재시작해도 손익이 0부터 다시 시작하지 않습니다.
+//! 모든 계산은 고정소수점 `Amount`로 합니다.
+
+use super::types::{Amount, KisError, KisResult, OrderSide, Price, Quantity};
+use chrono::NaiveDate;
+use std::collections::{BTreeSet, HashMap};
+
+/// 당일 체결 (주문 단위)
+#[derive(Debug, Clone)]
+pub struct Fill {
+    pub order_no: String,
+    /// 종목코드 (해외주식은 거래소코드:종목코드)
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: Quantity,
+    /// 체결평균가
+    pub price: Price,
+}
+
+/// 보유 종목 스냅샷 (당일 전량 매도한 종목은 수량 0)
+#[derive(Debug, Clone)]
+pub struct Holding {
+    pub symbol: String,
+    /// 보유수량
+    pub quantity: Quantity,
+    /// 전일종가
+    pub prev_close: Price,
+    /// 현재가
+    pub last: Price,
+}
+
+/// 해외주식 보유 종목 스냅샷 (당일 전량 매도한 종목은 수량 0)
+#[derive(Debug, Clone)]
+pub struct OverseasHolding {
+    /// 거래소코드:종목코드 (예: NASD:AAPL)
+    pub symbol: String,
+    /// 보유수량
+    pub quantity: Quantity,
+    /// 전일종가 (외화)
+    pub prev_close: Price,
+    /// 현재가 (외화)
+    pub last: Price,
+    /// 원화 환산 기준환율
+    pub exchange_rate: Price,
+}
+
+/// Intraday P&L (KRW)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DailyPnl {
+    pub date: String,
+    /// 당일 매도의 실현손익 (전일 보유분은 전일종가, 당일 매수분은 당일 매수평균가 대비, 해외주식 포함)
+    pub realized: Amount,
+    /// 보유분의 당일 평가손익 (전일종가 또는 당일 매수가 대비, 해외주식 포함)
+    pub unrealized: Amount,
+    /// 해외주식 당일 손익 (실현 + 평가, realized/unrealized에 나눠 포함)
+    pub overseas: Amount,
+    /// 당일 손익 (realized + unrealized)
+    pub total: Amount,
+    /// 당일 최고 손익
+    pub peak: Amount,
+    /// 최고 손익 대비 하락폭 (0 이상)
+    pub drawdown: Amount,
+}
+
+/// Tracks the day's peak P&L
+#[derive(Debug, Clone, Default)]
+pub struct PnlTracker {
+    day: Option<NaiveDate>,
+    peak: Amount,
+}
+
+impl PnlTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Update with a fresh balance/execution snapshot and return the day's P&L
+    ///
+    /// # Arguments
+    /// * `today` - KST 일자 (바뀌면 최고 손익을 다시 잡음)
+    /// * `holdings` - 현재 보유 종목과 당일 매도로 잔고에서 빠진 종목
+    /// * `fills` - 당일 전체 체결
+    /// * `overseas` - 해외주식 보유 종목과 당일 매도로 잔고에서 빠진 종목
+    /// * `overseas_fills` - 거래소 현지 거래일의 해외주식 체결 (외화)
+    ///
+    /// # Errors
+    /// * 전일 보유분이 있는데 전일종가가 없는 종목이 있거나, 해외 체결 종목의 환율을 모르면
+    ///   `KisError::Validation`
+    pub fn update(
+        &mut self,
+        today: NaiveDate,
+        holdings: &[Holding],
+        fills: &[Fill],
+        overseas: &[OverseasHolding],
+        overseas_fills: &[Fill],
+    ) -> KisResult<DailyPnl> {
+        let mut realized = Amount::ZERO;
+        let mut total = Amount::ZERO;
+        for symbol in symbols(holdings, fills) {
+            let (symbol_realized, symbol_total) = symbol_pnl(symbol, holdings, fills)?;
+            realized = realized + symbol_realized;
+            total = total + symbol_total;
+        }
+
+        let overseas_holdings: Vec<Holding> = overseas
+            .iter()
+            .map(|h| Holding {
+                symbol: h.symbol.clone(),
+                quantity: h.quantity,
+                prev_close: h.prev_close,
+                last: h.last,
+            })
+            .collect();
+        let mut overseas_pnl = Amount::ZERO;
+        for symbol in symbols(&overseas_holdings, overseas_fills) {
+            let rate = overseas
+                .iter()
+                .find(|h| h.symbol == symbol)
+                .map(|h| h.exchange_rate)
+                .ok_or_else(|| KisError::Validation(format!("No exchange rate for {}", symbol)))?;
+            let (symbol_realized, symbol_total) =
+                symbol_pnl(symbol, &overseas_holdings, overseas_fills)?;
+            realized = realized + symbol_realized.at_rate(rate);
+            overseas_pnl = overseas_pnl + symbol_total.at_rate(rate);
+        }
+        total = total + overseas_pnl;
+
+        if self.day != Some(today) {
+            self.day = Some(today);
+            self.peak = Amount::ZERO;
+        }
+        self.peak = self.peak.max(total);
+
+        Ok(DailyPnl {
+            date: today.format("%Y-%m-%d").to_string(),
+            realized,
+            unrealized: total - realized,
+            overseas: overseas_pnl,
+            total,
+            peak: self.peak,
+            drawdown: self.peak - total,
+        })
+    }
+}
+
+/// Symbols that are held or traded today
+fn symbols<'a>(holdings: &'a [Holding], fills: &'a [Fill]) -> BTreeSet<&'a str> {
+    holdings
+        .iter()
+        .map(|h| h.symbol.as_str())
+        .chain(fills.iter().map(|f| f.symbol.as_str()))
+        .collect()
+}
+
+/// (실현손익, 당일 손익) of one symbol against its previous close
+fn symbol_pnl(symbol: &str, holdings: &[Holding], fills: &[Fill]) -> KisResult<(Amount, Amount)> {
+    let (mut bought, mut bought_amount) = (Quantity::ZERO, Amount::ZERO);
+    let (mut sold, mut sold_amount) = (Quantity::ZERO, Amount::ZERO);
+    for fill in fills.iter().filter(|f| f.symbol == symbol) {
+        let amount = fill.price * fill.quantity;
+        match fill.side {
+            OrderSide::Buy => {
+                bought = bought + fill.quantity;
+                bought_amount = bought_amount + amount;
+            }
+            OrderSide::Sell => {
+                sold = sold + fill.quantity;
+                sold_amount = sold_amount + amount;
+            }
+        }
+    }
+
+    let holding = holdings.iter().find(|h| h.symbol == symbol);
+    let held = holding.map(|h| h.quantity).unwrap_or(Quantity::ZERO);
+    let overnight = (held - bought + sold).max(Quantity::ZERO);
+    let (prev_close, last) = match holding {
+        Some(h) => (h.prev_close, h.last),
+        None if !overnight.is_zero() => {
+            return Err(KisError::Validation(format!(
+                "No previous close for {}",
+                symbol
+            )))
+        }
+        None => (Price::ZERO, Price::ZERO),
+    };
+
+    // 매도는 전일 보유분부터 소진한 것으로 보고, 나머지는 당일 매수평균가 대비
+    let from_overnight = sold.min(overnight);
+    let average_buy = if bought.is_zero() {
+        Price::ZERO
+    } else {
+        bought_amount / bought
+    };
+    let realized =
+        sold_amount - prev_close * from_overnight - average_buy * (sold - from_overnight);
+    let total = last * held + sold_amount - prev_close * overnight - bought_amount;
+    Ok((realized, total))
+}
+
+/// Mark-to-market P&L of today's fills per persona
+///
+/// # Arguments
+/// * `fills` - 당일 체결
+/// * `owners` - 주문번호 → 페르소나
+/// * `marks` - 종목별 현재가
+///
+/// # Returns
+/// * 페르소나별 손익 (현재가가 없는 종목의 체결이 있으면 해당 페르소나는 `None`)
+pub fn persona_pnl(
+    fills: &[Fill],
+    owners: &HashMap<String, String>,
+    marks: &HashMap<String, Price>,
+) -> HashMap<String, Option<Amount>> {
+    let mut result: HashMap<String, Option<Amount>> = HashMap::new();
+    for fill in fills {
+        let Some(persona) = owners.get(&fill.order_no) else {
+            continue;
+        };
+        let pnl = marks.get(&fill.symbol).map(|mark| {
+            let diff = (*mark - fill.price) * fill.quantity;
+            match fill.side {
+                OrderSide::Buy => diff,
+                OrderSide::Sell => -diff,
+            }
+        });
+        let entry = result.entry(persona.clone()).or_insert(Some(Amount::ZERO));
+        *entry = match (*entry, pnl) {
+            (Some(total), Some(pnl)) => Some(total + pnl),
+            _ => None,
+        };
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill(order_no: &str, side: OrderSide, quantity: i64, price: i64) -> Fill {
+        Fill {
+            order_no: order_no.to_string(),
+            symbol: "005930".to_string(),
+            side,
+            quantity: Quantity::from_int(quantity),
+            price: Price::from_int(price),
+        }
+    }
+
+    fn krw(value: i64) -> Amount {
+        Amount::from_int(value)
+    }
+
+    #[test]
+    fn test_daily_pnl_from_previous_close_and_peak() {
+        let day = NaiveDate::from_ymd_opt(2025, 10, 2).unwrap();
+        let holding = |quantity: i64, last: i64| Holding {
+            symbol: "005930".to_string(),
+            quantity: Quantity::from_int(quantity),
+            prev_close: Price::from_int(70_000),
+            last: Price::from_int(last),
+        };
+        let mut tracker = PnlTracker::new();
+
+        // 전일 10주 보유, 전일종가 70,000 → 71,000: 평가 +10,000 (첫 조회여도 0이 아님)
+        let pnl = tracker
+            .update(day, &[holding(10, 71_000)], &[], &[], &[])
+            .unwrap();
+        assert_eq!(pnl.realized, Amount::ZERO);
+        assert_eq!(pnl.total, krw(10_000));
+
+        // 첫 조회 전의 체결도 반영: 5주 72,000 매도, 잔여 5주 72,000
+        let fills = [fill("1", OrderSide::Sell, 5, 72_000)];
+        let pnl = tracker
+            .update(day, &[holding(5, 72_000)], &fills, &[], &[])
+            .unwrap();
+        assert_eq!(pnl.realized, krw(10_000));
+        assert_eq!(pnl.unrealized, krw(10_000));
+        assert_eq!(pnl.total, krw(20_000));
+        assert_eq!(pnl.peak, krw(20_000));
+
+        // 당일 3주 69,000 매수 후 전량 매도 (잔고에서 빠져 수량 0)
+        let fills = [
+            fill("1", OrderSide::Sell, 5, 72_000),
+            fill("2", OrderSide::Buy, 3, 69_000),
+            fill("3", OrderSide::Sell, 8, 66_000),
+        ];
+        let pnl = tracker
+            .update(day, &[holding(0, 66_000)], &fills, &[], &[])
+            .unwrap();
+        // 전일분 10주: 5 × 2,000 + 5 × -4,000, 당일분 3주: 3 × -3,000
+        assert_eq!(pnl.realized, krw(-19_000));
+        assert_eq!(pnl.unrealized, Amount::ZERO);
+        assert_eq!(pnl.total, krw(-19_000));
+        assert_eq!(pnl.drawdown, krw(39_000));
+
+        // 전일 보유분이 있는데 전일종가를 모르면 오류
+        assert!(tracker.update(day, &[], &fills, &[], &[]).is_err());
+
+        // 다음 날은 최고 손익을 다시 잡음
+        let next = NaiveDate::from_ymd_opt(2025, 10, 3).unwrap();
+        let pnl = tracker
+            .update(next, &[holding(0, 66_000)], &[], &[], &[])
+            .unwrap();
+        assert_eq!(pnl.total, Amount::ZERO);
+        assert_eq!(pnl.peak, Amount::ZERO);
+    }
+
+    #[test]
+    fn test_overseas_pnl_from_previous_close_with_realized() {
+        let day = NaiveDate::from_ymd_opt(2025, 10, 2).unwrap();
+        let aapl = |quantity: i64, last: &str| OverseasHolding {
+            symbol: "NASD:AAPL".to_string(),
+            quantity: Quantity::from_int(quantity),
+            prev_close: "200".parse().unwrap(),
+            last: last.parse().unwrap(),
+            exchange_rate: "1400.5".parse().unwrap(),
+        };
+        let sell = Fill {
+            order_no: "1".to_string(),
+            symbol: "NASD:AAPL".to_string(),
+            side: OrderSide::Sell,
+            quantity: Quantity::from_int(4),
+            price: "198.25".parse().unwrap(),
+        };
+
+        // 새로 만든 트래커(재시작)도 전일종가 기준: 10주 × -5달러 × 1,400.5원
+        let pnl = PnlTracker::new()
+            .update(day, &[], &[], &[aapl(10, "195")], &[])
+            .unwrap();
+        assert_eq!(pnl.overseas, krw(-70_025));
+        assert_eq!(pnl.unrealized, krw(-70_025));
+        assert_eq!(pnl.total, krw(-70_025));
+
+        // 4주 198.25달러 매도: 실현 4 × -1.75달러, 잔여 6주 × -5달러
+        let pnl = PnlTracker::new()
+            .update(day, &[], &[], &[aapl(6, "195")], &[sell.clone()])
+            .unwrap();
+        assert_eq!(pnl.realized, "-9803.5".parse::<Amount>().unwrap());
+        assert_eq!(pnl.unrealized, krw(-42_015));
+        assert_eq!(pnl.overseas, pnl.total);
+
+        // 전량 매도로 잔고에서 빠진 종목의 시세나 환율이 없으면 오류
+        assert!(PnlTracker::new()
+            .update(day, &[], &[], &[], &[sell])
+            .is_err());
+    }
+
+    #[test]
+    fn test_persona_pnl_marks_fills() {
+        let owners = HashMap::from([
+            ("1".to_string(), "alpha".to_string()),
+            ("2".to_string(), "alpha".to_string()),
+            ("3".to_string(), "beta".to_string()),
+        ]);
+        let fills = [
+            fill("1", OrderSide::Buy, 10, 70_000),
+            fill("2", OrderSide::Sell, 4, 71_000),
+            fill("3", OrderSide::Buy, 1, 72_000),
+            fill("9", OrderSide::Buy, 100, 1),
+        ];
+        let marks = HashMap::from([("005930".to_string(), Price::from_int(69_000))]);
+
+        let pnl = persona_pnl(&fills, &owners, &marks);
+        assert_eq!(pnl["alpha"], Some(krw(-10_000 + 8_000)));
+        assert_eq!(pnl["beta"], Some(krw(-3_000)));
+        assert_eq!(pnl.len(), 2);
+
+        let unpriced = persona_pnl(&fills, &owners, &HashMap::new());
+        assert_eq!(unpriced["alpha"], None);
+    }
+}
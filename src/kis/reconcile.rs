@@ -0,0 +1,286 @@
+//! Order reconciliation after ambiguous submissions
+//!
+//! 주문 전송이 타임아웃 등으로 결과를 알 수 없을 때, 당일 주문체결내역에서 같은 종목/방향/수량/가격으로
+//! 전송 시각 이후 접수된 주문을 찾아 이미 접수되었는지 판단합니다. `client_order_id`별 결과는
+//! 상태 디렉터리가 지정되면 파일로 보존되며, 최초 전송 후 24시간이 지난 기록은 삭제됩니다 (KST
+//! 자정을 넘기는 해외 세션 중의 재요청도 같은 주문으로 처리).
+
+use super::state_file;
+use super::types::{KisResult, OrderSide, Price, Quantity};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// 전송 시각과 KIS 주문시각의 허용 오차 (호스트 시계와 KIS 서버 시계 차이)
+const CLOCK_SKEW_SECS: i64 = 30;
+
+/// 상태 디렉터리 안의 저장 파일명
+pub const CLIENT_ORDERS_FILE: &str = "client_orders.json";
+
+/// `client_order_id` 기록 보존 시간
+pub const CLIENT_ORDER_TTL_HOURS: i64 = 24;
+
+/// Outcome recorded for a host-supplied `client_order_id`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum ClientOrder<T> {
+    /// 결과가 확정된 주문 (재요청 시 그대로 반환)
+    Settled {
+        /// 최초 전송 시각 (KST)
+        sent_at: NaiveDateTime,
+        order: T,
+    },
+    /// 전송 결과를 알 수 없는 주문 (재요청 시 주문내역과 대사)
+    Unknown {
+        /// 최초 전송 시각 (KST)
+        sent_at: NaiveDateTime,
+        order: T,
+    },
+}
+
+impl<T> ClientOrder<T> {
+    /// 최초 전송 시각 (KST)
+    pub fn sent_at(&self) -> NaiveDateTime {
+        match self {
+            ClientOrder::Settled { sent_at, .. } | ClientOrder::Unknown { sent_at, .. } => *sent_at,
+        }
+    }
+}
+
+/// `client_order_id` outcomes of the last 24 hours
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClientOrderBook<T> {
+    pub orders: HashMap<String, ClientOrder<T>>,
+}
+
+impl<T> Default for ClientOrderBook<T> {
+    fn default() -> Self {
+        Self {
+            orders: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Serialize + for<'de> Deserialize<'de>> ClientOrderBook<T> {
+    /// Load the book saved in `dir` (`None` if nothing was saved yet)
+    pub fn load(dir: &str) -> KisResult<Option<Self>> {
+        state_file::load(dir, CLIENT_ORDERS_FILE)
+    }
+
+    /// Save the book to `dir`
+    pub fn save(&self, dir: &str) -> KisResult<()> {
+        state_file::save(dir, CLIENT_ORDERS_FILE, self)
+    }
+}
+
+impl<T> ClientOrderBook<T> {
+    /// Drop the outcomes first sent more than `CLIENT_ORDER_TTL_HOURS` before `now` (KST)
+    pub fn expire(&mut self, now: NaiveDateTime) {
+        let ttl = Duration::hours(CLIENT_ORDER_TTL_HOURS);
+        self.orders.retain(|_, order| now - order.sent_at() < ttl);
+    }
+}
+
+/// The order whose outcome is unknown
+#[derive(Debug, Clone)]
+pub struct OrderIntent<'a> {
+    pub symbol: &'a str,
+    pub side: OrderSide,
+    pub quantity: Quantity,
+    /// 주문단가 (시장가는 0, None이면 가격을 비교하지 않음)
+    pub limit_price: Option<Price>,
+    /// 최초 전송 시각 (KST)
+    pub sent_at: NaiveDateTime,
+}
+
+/// An order row from the order history
+#[derive(Debug, Clone)]
+pub struct OrderCandidate {
+    pub order_no: String,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: Quantity,
+    /// 주문단가 (시장가는 0 또는 None)
+    pub price: Option<Price>,
+    /// 주문일시 (KST)
+    pub time: Option<NaiveDateTime>,
+}
+
+/// Combine an order date with a KIS HHMMSS time field
+pub fn parse_order_time(date: NaiveDate, raw: Option<&str>) -> Option<NaiveDateTime> {
+    NaiveTime::parse_from_str(raw?.trim(), "%H%M%S")
+        .ok()
+        .map(|time| date.and_time(time))
+}
+
+/// Find the earliest unknown order matching the intent
+///
+/// # Arguments
+/// * `intent` - 결과를 알 수 없는 주문
+/// * `candidates` - 전송일(해외는 현지 거래일 포함)의 주문내역
+/// * `known` - 이미 다른 요청에 대응된 주문번호 (앞자리 0 제거)
+pub fn find_submitted<'c>(
+    intent: &OrderIntent,
+    candidates: &'c [OrderCandidate],
+    known: &HashSet<String>,
+) -> Option<&'c OrderCandidate> {
+    let earliest = intent.sent_at - Duration::seconds(CLOCK_SKEW_SECS);
+    candidates
+        .iter()
+        .filter(|c| !known.contains(c.order_no.trim_start_matches('0')))
+        .filter(|c| c.symbol == intent.symbol && c.side == intent.side)
+        .filter(|c| c.quantity == intent.quantity)
+        .filter(|c| match intent.limit_price {
+            Some(limit) => c.price.unwrap_or(Price::ZERO) == limit,
+            None => true,
+        })
+        .filter(|c| c.time.is_some_and(|t| t >= earliest))
+        .min_by_key(|c| c.time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_order_book_persists_and_expires_by_age() {
+        let dir = std::env::temp_dir().join(format!("client_orders_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir = dir.to_str().unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+
+        assert_eq!(ClientOrderBook::<String>::load(dir).unwrap(), None);
+
+        let mut book = ClientOrderBook::default();
+        book.orders.insert(
+            "a".to_string(),
+            ClientOrder::Settled {
+                sent_at: today.and_hms_opt(9, 30, 0).unwrap(),
+                order: "0000123".to_string(),
+            },
+        );
+        book.orders.insert(
+            "b".to_string(),
+            ClientOrder::Unknown {
+                sent_at: today.and_hms_opt(23, 50, 0).unwrap(),
+                order: "pending".to_string(),
+            },
+        );
+        book.save(dir).unwrap();
+
+        let mut loaded = ClientOrderBook::<String>::load(dir).unwrap().unwrap();
+        assert_eq!(loaded, book);
+
+        // KST 자정을 넘겨도 24시간 안의 기록은 유지
+        let tomorrow = today.succ_opt().unwrap();
+        loaded.expire(tomorrow.and_hms_opt(0, 10, 0).unwrap());
+        assert_eq!(loaded.orders.len(), 2);
+        loaded.expire(tomorrow.and_hms_opt(9, 30, 0).unwrap());
+        assert_eq!(loaded.orders.keys().collect::<Vec<_>>(), ["b"]);
+        loaded.expire(tomorrow.and_hms_opt(23, 50, 0).unwrap());
+        assert!(loaded.orders.is_empty());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    fn candidate(order_no: &str, quantity: i64, price: i64, time: &str) -> OrderCandidate {
+        OrderCandidate {
+            order_no: order_no.to_string(),
+            symbol: "005930".to_string(),
+            side: OrderSide::Buy,
+            quantity: Quantity::from_int(quantity),
+            price: Some(Price::from_int(price)),
+            time: parse_order_time(day(), Some(time)),
+        }
+    }
+
+    fn day() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 2).unwrap()
+    }
+
+    #[test]
+    fn test_find_submitted_matches_unknown_order_after_send() {
+        let intent = OrderIntent {
+            symbol: "005930",
+            side: OrderSide::Buy,
+            quantity: Quantity::from_int(10),
+            limit_price: Some(Price::from_int(70_000)),
+            sent_at: day().and_hms_opt(10, 0, 0).unwrap(),
+        };
+        let candidates = [
+            candidate("0000000101", 10, 70_000, "093000"), // 전송 전 주문
+            candidate("0000000102", 10, 70_000, "100002"), // 이미 대응됨
+            candidate("0000000104", 10, 70_000, "100009"),
+            candidate("0000000103", 10, 70_000, "100005"),
+            candidate("0000000105", 5, 70_000, "100001"),
+            candidate("0000000106", 10, 0, "100001"), // 시장가
+        ];
+        let known = HashSet::from(["102".to_string()]);
+
+        let found = find_submitted(&intent, &candidates, &known).unwrap();
+        assert_eq!(found.order_no, "0000000103");
+
+        let market = OrderIntent {
+            limit_price: Some(Price::ZERO),
+            ..intent.clone()
+        };
+        let found = find_submitted(&market, &candidates, &known).unwrap();
+        assert_eq!(found.order_no, "0000000106");
+
+        // 호가단위로 보정된 지정가는 가격을 비교하지 않음
+        let rounded = OrderIntent {
+            limit_price: None,
+            ..intent.clone()
+        };
+        let found = find_submitted(&rounded, &candidates, &known).unwrap();
+        assert_eq!(found.order_no, "0000000106");
+
+        let sell = OrderIntent {
+            side: OrderSide::Sell,
+            ..intent.clone()
+        };
+        assert!(find_submitted(&sell, &candidates, &known).is_none());
+
+        // 전날 같은 시각의 주문은 대응하지 않음
+        let earlier_day = [OrderCandidate {
+            time: parse_order_time(day().pred_opt().unwrap(), Some("100005")),
+            ..candidate("0000000107", 10, 70_000, "100005")
+        }];
+        assert!(find_submitted(&intent, &earlier_day, &known).is_none());
+    }
+
+    #[test]
+    fn test_find_submitted_matches_rounded_overseas_limit() {
+        use crate::kis::overseas_stock::OverseasOrderType;
+        use crate::kis::types::Exchange;
+
+        let requested: Price = "187.125".parse().unwrap();
+        // 자릿수를 넘는 지정가는 보정 요청 없이는 전송되지 않음
+        assert!(OverseasOrderType::Limit
+            .wire_price(Exchange::NASDAQ, requested, false)
+            .is_err());
+        let sent = OverseasOrderType::Limit
+            .wire_price(Exchange::NASDAQ, requested, true)
+            .unwrap();
+
+        let intent = OrderIntent {
+            symbol: "AAPL",
+            side: OrderSide::Buy,
+            quantity: Quantity::from_int(3),
+            limit_price: Some(sent),
+            sent_at: day().and_hms_opt(23, 59, 50).unwrap(),
+        };
+        let candidates = [OrderCandidate {
+            order_no: "0030123456".to_string(),
+            symbol: "AAPL".to_string(),
+            side: OrderSide::Buy,
+            quantity: Quantity::from_int(3),
+            price: Some("187.1300".parse().unwrap()),
+            // KST 자정을 넘겨 접수
+            time: parse_order_time(day().succ_opt().unwrap(), Some("000005")),
+        }];
+
+        let found = find_submitted(&intent, &candidates, &HashSet::new()).unwrap();
+        assert_eq!(found.order_no, "0030123456");
+    }
+}
@@ -0,0 +1,587 @@
+//! Pre-trade risk checks
+//!
+//! 호스트가 `initialize()`의 `risk` 설정으로 지정한 한도를 모든 주문 경로(국내/해외, 예약주문
+//! 포함) 앞에서 검사합니다. 한도가 설정된 항목의 기준 데이터(현재가, 보유수량)를 확보하지 못하면
+//! 주문을 거부합니다 (fail-closed).
+//!
+//! 당일 손실 한도(계좌/페르소나)와 최고 손익 대비 하락폭 한도는 한 번 도달하면 해당 KST 일자가
+//! 끝날 때까지 매수(위험 증가) 주문을 거부하며, 보유 축소인 매도 주문은 허용합니다.
+
+use super::pnl::DailyPnl;
+use super::types::{Amount, OrderSide, Price, Quantity};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Risk limits (모든 항목은 선택이며 설정하지 않으면 검사하지 않음)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RiskLimits {
+    /// 통화별 1회 주문금액 한도 (예: {"KRW": 50000000, "USD": 20000})
+    pub max_order_notional: HashMap<String, f64>,
+    /// 1회 주문수량 한도
+    pub max_order_quantity: Option<u64>,
+    /// 종목당 최대 보유수량 (매수 체결 후 보유수량 기준)
+    pub max_position_quantity: Option<u64>,
+    /// 주문가격과 현재가의 최대 괴리율 (%)
+    pub max_price_deviation_pct: Option<f64>,
+    /// 허용 종목 (비어 있으면 모든 종목 허용)
+    pub allow_symbols: Vec<String>,
+    /// 거부 종목
+    pub deny_symbols: Vec<String>,
+    /// KST 일자별 최대 주문 건수 (접수된 주문 기준)
+    pub max_daily_orders: Option<u32>,
+    /// 계좌 당일 손실 한도 (KRW, 양수)
+    pub max_daily_loss: Option<f64>,
+    /// 당일 최고 손익 대비 최대 하락폭 (KRW, 양수)
+    pub max_intraday_drawdown: Option<f64>,
+    /// 페르소나별 당일 손실 한도 (KRW, 양수). "*"는 모든 페르소나의 기본값
+    pub max_persona_daily_loss: HashMap<String, f64>,
+}
+
+/// Which limit an order breached
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiskRule {
+    SymbolDenied,
+    SymbolNotAllowed,
+    MaxOrderQuantity,
+    MaxOrderNotional,
+    MaxPosition,
+    PriceDeviation,
+    DailyOrderCount,
+    DailyLoss,
+    IntradayDrawdown,
+    PersonaDailyLoss,
+    LiveSessionNotional,
+}
+
+impl RiskRule {
+    pub fn code(&self) -> &'static str {
+        match self {
+            RiskRule::SymbolDenied => "symbol_denied",
+            RiskRule::SymbolNotAllowed => "symbol_not_allowed",
+            RiskRule::MaxOrderQuantity => "max_order_quantity",
+            RiskRule::MaxOrderNotional => "max_order_notional",
+            RiskRule::MaxPosition => "max_position_quantity",
+            RiskRule::PriceDeviation => "max_price_deviation_pct",
+            RiskRule::DailyOrderCount => "max_daily_orders",
+            RiskRule::DailyLoss => "max_daily_loss",
+            RiskRule::IntradayDrawdown => "max_intraday_drawdown",
+            RiskRule::PersonaDailyLoss => "max_persona_daily_loss",
+            RiskRule::LiveSessionNotional => "live_session_notional",
+        }
+    }
+}
+
+/// A rejected pre-trade check
+#[derive(Debug, Clone, PartialEq)]
+pub struct RiskViolation {
+    pub rule: RiskRule,
+    pub message: String,
+    /// 설정된 한도
+    pub limit: Option<String>,
+    /// 주문이 만든 값 (산출할 수 없으면 None)
+    pub actual: Option<String>,
+}
+
+impl RiskViolation {
+    pub(crate) fn new(rule: RiskRule, message: String) -> Self {
+        RiskViolation {
+            rule,
+            message,
+            limit: None,
+            actual: None,
+        }
+    }
+
+    pub(crate) fn values(mut self, limit: impl ToString, actual: impl ToString) -> Self {
+        self.limit = Some(limit.to_string());
+        self.actual = Some(actual.to_string());
+        self
+    }
+}
+
+impl fmt::Display for RiskViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Risk check {} failed: {}",
+            self.rule.code(),
+            self.message
+        )
+    }
+}
+
+/// Order and market data to check
+#[derive(Debug, Clone)]
+pub struct OrderCheck<'a> {
+    pub symbol: &'a str,
+    pub side: OrderSide,
+    pub quantity: Quantity,
+    /// 지정가 (시장가는 None)
+    pub limit_price: Option<Price>,
+    /// 주문 통화 (KRW, USD 등)
+    pub currency: &'a str,
+    /// 현재가 (필요 없거나 조회 실패 시 None)
+    pub last_price: Option<Price>,
+    /// 현재 보유수량 (필요 없거나 조회 실패 시 None)
+    pub position: Option<Quantity>,
+}
+
+/// Pre-trade risk engine with a per-day order counter and loss halts
+#[derive(Debug, Clone, Default)]
+pub struct RiskEngine {
+    limits: RiskLimits,
+    order_day: Option<NaiveDate>,
+    orders_today: u32,
+    /// 계좌 손실/하락폭 한도 도달 (일자, 위반 내역)
+    account_halt: Option<(NaiveDate, RiskViolation)>,
+    /// 페르소나별 손실 한도 도달
+    persona_halts: HashMap<String, (NaiveDate, RiskViolation)>,
+}
+
+impl RiskEngine {
+    pub fn new(limits: RiskLimits) -> Self {
+        RiskEngine {
+            limits,
+            ..Default::default()
+        }
+    }
+
+    pub fn limits(&self) -> &RiskLimits {
+        &self.limits
+    }
+
+    /// Replace the limits, keeping today's order count
+    pub fn set_limits(&mut self, limits: RiskLimits) {
+        self.limits = limits;
+    }
+
+    /// Whether `check` needs the last trade price
+    pub fn needs_last_price(&self) -> bool {
+        self.limits.max_price_deviation_pct.is_some() || !self.limits.max_order_notional.is_empty()
+    }
+
+    /// Whether `check` needs the current position (매수 주문만)
+    pub fn needs_position(&self, side: OrderSide) -> bool {
+        side == OrderSide::Buy && self.limits.max_position_quantity.is_some()
+    }
+
+    /// Loss limit configured for a persona (개별 설정 우선, 없으면 "*")
+    pub fn persona_loss_limit(&self, persona: &str) -> Option<Amount> {
+        let limits = &self.limits.max_persona_daily_loss;
+        limits
+            .get(persona)
+            .or_else(|| limits.get("*"))
+            .and_then(|max| Amount::from_f64(*max))
+    }
+
+    /// Whether `check_loss` needs the day's P&L for this order
+    pub fn needs_pnl(&self, side: OrderSide, persona: Option<&str>) -> bool {
+        side == OrderSide::Buy
+            && (self.limits.max_daily_loss.is_some()
+                || self.limits.max_intraday_drawdown.is_some()
+                || persona.and_then(|p| self.persona_loss_limit(p)).is_some())
+    }
+
+    /// Account halt latched for the given KST date
+    pub fn account_halt(&self, today: NaiveDate) -> Option<&RiskViolation> {
+        self.account_halt
+            .as_ref()
+            .filter(|(day, _)| *day == today)
+            .map(|(_, violation)| violation)
+    }
+
+    /// Persona halts latched for the given KST date
+    pub fn persona_halts(&self, today: NaiveDate) -> impl Iterator<Item = (&str, &RiskViolation)> {
+        self.persona_halts
+            .iter()
+            .filter(move |(_, (day, _))| *day == today)
+            .map(|(persona, (_, violation))| (persona.as_str(), violation))
+    }
+
+    /// Check the daily loss limits, latching a halt for the rest of the day once hit
+    ///
+    /// # Arguments
+    /// * `side` - 매도 주문은 항상 통과
+    /// * `account` - 계좌 당일 손익 (조회 실패 시 None)
+    /// * `persona` - 주문 페르소나와 그 당일 손익 (손익을 산출하지 못했으면 None)
+    pub fn check_loss(
+        &mut self,
+        today: NaiveDate,
+        side: OrderSide,
+        account: Option<&DailyPnl>,
+        persona: Option<(&str, Option<Amount>)>,
+    ) -> Result<(), RiskViolation> {
+        if side == OrderSide::Sell {
+            return Ok(());
+        }
+        if let Some(violation) = self.account_halt(today) {
+            return Err(violation.clone());
+        }
+        if let Some((name, _)) = persona {
+            if let Some((day, violation)) = self.persona_halts.get(name) {
+                if *day == today {
+                    return Err(violation.clone());
+                }
+            }
+        }
+
+        let limits = &self.limits;
+        if limits.max_daily_loss.is_some() || limits.max_intraday_drawdown.is_some() {
+            let pnl = account.ok_or_else(|| {
+                RiskViolation::new(
+                    RiskRule::DailyLoss,
+                    "today's P&L is unavailable".to_string(),
+                )
+            })?;
+            let max_loss = limits
+                .max_daily_loss
+                .and_then(Amount::from_f64)
+                .filter(|max| pnl.total <= -*max);
+            let breach = if let Some(max) = max_loss {
+                Some(
+                    RiskViolation::new(
+                        RiskRule::DailyLoss,
+                        format!(
+                            "daily P&L {} reached the loss limit {}",
+                            pnl.total.round_dp(0),
+                            max
+                        ),
+                    )
+                    .values(-max, pnl.total.round_dp(0)),
+                )
+            } else {
+                limits
+                    .max_intraday_drawdown
+                    .and_then(Amount::from_f64)
+                    .filter(|max| pnl.drawdown >= *max)
+                    .map(|max| {
+                        RiskViolation::new(
+                            RiskRule::IntradayDrawdown,
+                            format!(
+                                "P&L fell {} from today's peak {} (limit {})",
+                                pnl.drawdown.round_dp(0),
+                                pnl.peak.round_dp(0),
+                                max
+                            ),
+                        )
+                        .values(max, pnl.drawdown.round_dp(0))
+                    })
+            };
+            if let Some(violation) = breach {
+                self.account_halt = Some((today, violation.clone()));
+                return Err(violation);
+            }
+        }
+
+        if let Some((name, pnl)) = persona {
+            if let Some(max) = self.persona_loss_limit(name) {
+                let pnl = pnl.ok_or_else(|| {
+                    RiskViolation::new(
+                        RiskRule::PersonaDailyLoss,
+                        format!("today's P&L of persona {} is unavailable", name),
+                    )
+                })?;
+                if pnl <= -max {
+                    let violation = RiskViolation::new(
+                        RiskRule::PersonaDailyLoss,
+                        format!(
+                            "persona {} daily P&L {} reached the loss limit {}",
+                            name,
+                            pnl.round_dp(0),
+                            max
+                        ),
+                    )
+                    .values(-max, pnl.round_dp(0));
+                    self.persona_halts
+                        .insert(name.to_string(), (today, violation.clone()));
+                    return Err(violation);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Orders accepted on the given KST date
+    pub fn orders_on(&self, today: NaiveDate) -> u32 {
+        if self.order_day == Some(today) {
+            self.orders_today
+        } else {
+            0
+        }
+    }
+
+    /// Count an accepted order
+    pub fn record_order(&mut self, today: NaiveDate) {
+        if self.order_day != Some(today) {
+            self.order_day = Some(today);
+            self.orders_today = 0;
+        }
+        self.orders_today += 1;
+    }
+
+    /// Run all configured checks (목록 → 수량 → 건수 → 가격 괴리 → 금액 → 보유수량 순)
+    pub fn check(&self, order: &OrderCheck, today: NaiveDate) -> Result<(), RiskViolation> {
+        let limits = &self.limits;
+        let listed = |list: &[String]| list.iter().any(|s| s.eq_ignore_ascii_case(order.symbol));
+
+        if listed(&limits.deny_symbols) {
+            return Err(RiskViolation::new(
+                RiskRule::SymbolDenied,
+                format!("{} is on the deny list", order.symbol),
+            ));
+        }
+        if !limits.allow_symbols.is_empty() && !listed(&limits.allow_symbols) {
+            return Err(RiskViolation::new(
+                RiskRule::SymbolNotAllowed,
+                format!("{} is not on the allow list", order.symbol),
+            ));
+        }
+
+        if let Some(max) = limits.max_order_quantity {
+            if order.quantity > Quantity::from_int(max as i64) {
+                return Err(RiskViolation::new(
+                    RiskRule::MaxOrderQuantity,
+                    format!("quantity {} exceeds {}", order.quantity, max),
+                )
+                .values(max, order.quantity));
+            }
+        }
+
+        if let Some(max) = limits.max_daily_orders {
+            let count = self.orders_on(today);
+            if count >= max {
+                return Err(RiskViolation::new(
+                    RiskRule::DailyOrderCount,
+                    format!("{} orders already submitted today (limit {})", count, max),
+                )
+                .values(max, count + 1));
+            }
+        }
+
+        if let (Some(max_pct), Some(limit_price)) =
+            (limits.max_price_deviation_pct, order.limit_price)
+        {
+            let last = order.last_price.filter(|p| !p.is_zero()).ok_or_else(|| {
+                RiskViolation::new(
+                    RiskRule::PriceDeviation,
+                    format!("no reference price for {}", order.symbol),
+                )
+            })?;
+            let deviation = (limit_price.to_f64() / last.to_f64() - 1.0).abs() * 100.0;
+            if deviation > max_pct {
+                return Err(RiskViolation::new(
+                    RiskRule::PriceDeviation,
+                    format!(
+                        "limit price {} deviates {:.2}% from last {} (limit {}%)",
+                        limit_price, deviation, last, max_pct
+                    ),
+                )
+                .values(max_pct, format!("{:.2}", deviation)));
+            }
+        }
+
+        let currency_limit = limits
+            .max_order_notional
+            .iter()
+            .find(|(currency, _)| currency.eq_ignore_ascii_case(order.currency))
+            .and_then(|(_, max)| Amount::from_f64(*max));
+        if let Some(max) = currency_limit {
+            let price = order
+                .limit_price
+                .filter(|p| !p.is_zero())
+                .or(order.last_price)
+                .ok_or_else(|| {
+                    RiskViolation::new(
+                        RiskRule::MaxOrderNotional,
+                        format!("cannot price a market order for {}", order.symbol),
+                    )
+                })?;
+            let notional = price * order.quantity;
+            if notional > max {
+                return Err(RiskViolation::new(
+                    RiskRule::MaxOrderNotional,
+                    format!("notional {} {} exceeds {}", notional, order.currency, max),
+                )
+                .values(max, notional));
+            }
+        }
+
+        if let (Some(max), OrderSide::Buy) = (limits.max_position_quantity, order.side) {
+            let held = order.position.ok_or_else(|| {
+                RiskViolation::new(
+                    RiskRule::MaxPosition,
+                    format!("current position of {} is unavailable", order.symbol),
+                )
+            })?;
+            let after = held + order.quantity;
+            if after > Quantity::from_int(max as i64) {
+                return Err(RiskViolation::new(
+                    RiskRule::MaxPosition,
+                    format!("position would be {} (limit {})", after, max),
+                )
+                .values(max, after));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(quantity: i64, limit_price: Option<i64>) -> OrderCheck<'static> {
+        OrderCheck {
+            symbol: "005930",
+            side: OrderSide::Buy,
+            quantity: Quantity::from_int(quantity),
+            limit_price: limit_price.map(Price::from_int),
+            currency: "KRW",
+            last_price: Some(Price::from_int(70_000)),
+            position: Some(Quantity::from_int(100)),
+        }
+    }
+
+    #[test]
+    fn test_risk_limits_reject_orders() {
+        let limits: RiskLimits = serde_json::from_value(serde_json::json!({
+            "max_order_notional": { "krw": 10_000_000 },
+            "max_order_quantity": 1000,
+            "max_position_quantity": 200,
+            "max_price_deviation_pct": 5.0,
+            "deny_symbols": ["000660"],
+        }))
+        .unwrap();
+        let engine = RiskEngine::new(limits);
+        let today = NaiveDate::from_ymd_opt(2025, 10, 2).unwrap();
+        let rule = |check: &OrderCheck| engine.check(check, today).unwrap_err().rule;
+
+        assert!(engine.check(&order(100, Some(70_000)), today).is_ok());
+        assert_eq!(rule(&order(100_000, None)), RiskRule::MaxOrderQuantity);
+        assert_eq!(rule(&order(10, Some(80_000))), RiskRule::PriceDeviation);
+        assert_eq!(rule(&order(150, None)), RiskRule::MaxOrderNotional);
+        assert_eq!(rule(&order(101, Some(69_000))), RiskRule::MaxPosition);
+
+        let violation = engine.check(&order(150, None), today).unwrap_err();
+        assert_eq!(violation.limit.as_deref(), Some("10000000"));
+        assert_eq!(violation.actual.as_deref(), Some("10500000"));
+
+        // Sells don't grow the position; missing data fails closed
+        let mut sell = order(150, Some(70_000));
+        sell.side = OrderSide::Sell;
+        sell.quantity = Quantity::from_int(120);
+        sell.position = None;
+        assert!(engine.check(&sell, today).is_ok());
+        let mut unpriced = order(10, None);
+        unpriced.last_price = None;
+        assert_eq!(rule(&unpriced), RiskRule::MaxOrderNotional);
+
+        let mut denied = order(1, Some(70_000));
+        denied.symbol = "000660";
+        assert_eq!(rule(&denied), RiskRule::SymbolDenied);
+    }
+
+    #[test]
+    fn test_risk_allow_list_and_daily_count() {
+        let mut engine = RiskEngine::new(RiskLimits {
+            allow_symbols: vec!["005930".to_string()],
+            max_daily_orders: Some(2),
+            ..Default::default()
+        });
+        let today = NaiveDate::from_ymd_opt(2025, 10, 2).unwrap();
+        let tomorrow = NaiveDate::from_ymd_opt(2025, 10, 3).unwrap();
+        assert!(!engine.needs_last_price());
+        assert!(!engine.needs_position(OrderSide::Buy));
+
+        let mut other = order(1, None);
+        other.symbol = "035720";
+        assert_eq!(
+            engine.check(&other, today).unwrap_err().rule,
+            RiskRule::SymbolNotAllowed
+        );
+
+        engine.record_order(today);
+        assert!(engine.check(&order(1, None), today).is_ok());
+        engine.record_order(today);
+        assert_eq!(
+            engine.check(&order(1, None), today).unwrap_err().rule,
+            RiskRule::DailyOrderCount
+        );
+        assert_eq!(engine.orders_on(tomorrow), 0);
+        assert!(engine.check(&order(1, None), tomorrow).is_ok());
+    }
+
+    #[test]
+    fn test_loss_limits_latch_for_the_day() {
+        let limits: RiskLimits = serde_json::from_value(serde_json::json!({
+            "max_daily_loss": 1_000_000,
+            "max_intraday_drawdown": 1_500_000,
+            "max_persona_daily_loss": { "*": 200_000, "alpha": 500_000 },
+        }))
+        .unwrap();
+        let mut engine = RiskEngine::new(limits);
+        let today = NaiveDate::from_ymd_opt(2025, 10, 2).unwrap();
+        let tomorrow = NaiveDate::from_ymd_opt(2025, 10, 3).unwrap();
+        let krw = Amount::from_int;
+        let pnl = |total: i64, peak: i64| DailyPnl {
+            total: krw(total),
+            peak: krw(peak),
+            drawdown: krw(peak - total),
+            ..Default::default()
+        };
+        let buy = OrderSide::Buy;
+
+        assert!(engine.needs_pnl(buy, None));
+        assert!(!engine.needs_pnl(OrderSide::Sell, Some("alpha")));
+        assert_eq!(engine.persona_loss_limit("beta"), Some(krw(200_000)));
+
+        // Missing P&L fails closed without latching
+        let rule = |r: Result<(), RiskViolation>| r.unwrap_err().rule;
+        assert_eq!(
+            rule(engine.check_loss(today, buy, None, None)),
+            RiskRule::DailyLoss
+        );
+        assert!(engine
+            .check_loss(today, buy, Some(&pnl(-500_000, 0)), None)
+            .is_ok());
+
+        // Persona limits are independent of each other
+        let small = Some(&pnl(-100_000, 0));
+        assert_eq!(
+            rule(engine.check_loss(today, buy, small, Some(("beta", Some(krw(-250_000)))))),
+            RiskRule::PersonaDailyLoss
+        );
+        assert!(engine
+            .check_loss(today, buy, small, Some(("alpha", Some(krw(-250_000)))))
+            .is_ok());
+        assert!(engine
+            .check_loss(today, buy, small, Some(("beta", Some(krw(0)))))
+            .is_err());
+
+        // Drawdown from the peak halts the account until the next day
+        assert_eq!(
+            rule(engine.check_loss(today, buy, Some(&pnl(200_000, 1_800_000)), None)),
+            RiskRule::IntradayDrawdown
+        );
+        assert!(engine
+            .check_loss(today, buy, Some(&pnl(0, 0)), None)
+            .is_err());
+        assert!(engine
+            .check_loss(today, OrderSide::Sell, None, None)
+            .is_ok());
+        assert!(engine.account_halt(today).is_some());
+        assert!(engine
+            .check_loss(
+                tomorrow,
+                buy,
+                Some(&pnl(0, 0)),
+                Some(("beta", Some(krw(0))))
+            )
+            .is_ok());
+    }
+}
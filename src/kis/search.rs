@@ -0,0 +1,498 @@
+//! Instrument search over the symbol masters (종목 검색)
+//!
+//! 국내/해외 종목 테이블에서 종목코드, ISIN, 한글명, 초성("ㅅㅅㅈㅈ"), 로마자 표기("samseong"),
+//! 영문명으로 종목을 찾고 일치 정도에 따라 정렬합니다. 로마자 표기는 국어의 로마자 표기법을
+//! 음절 단위로 단순 적용한 것이라 발음 변화나 회사의 영문 브랜드명("Samsung")과는 다를 수 있습니다.
+
+use super::domestic_stock::{ProductInfo, StockInfo};
+use super::master::{Instrument, InstrumentTable, MarketWarning};
+use super::overseas_master::{OverseasInstrument, OverseasSymbolTable, SecurityType};
+use super::types::{Exchange, KrxMarket};
+use serde::Serialize;
+
+/// 검색 결과 기본 개수
+pub const DEFAULT_SEARCH_LIMIT: usize = 20;
+
+const HANGUL_BASE: u32 = 0xAC00;
+const HANGUL_LAST: u32 = 0xD7A3;
+
+const INITIALS_ROMAN: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p",
+    "h",
+];
+const MEDIALS_ROMAN: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+const FINALS_ROMAN: [&str; 28] = [
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p",
+    "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+/// 초성 (호환 자모)
+const INITIALS_JAMO: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// Decompose a precomposed Hangul syllable into (initial, medial, final) indices
+fn decompose(c: char) -> Option<(usize, usize, usize)> {
+    let code = c as u32;
+    if !(HANGUL_BASE..=HANGUL_LAST).contains(&code) {
+        return None;
+    }
+    let index = (code - HANGUL_BASE) as usize;
+    Some((index / (21 * 28), (index % (21 * 28)) / 28, index % 28))
+}
+
+/// Lowercase and drop spaces/punctuation
+pub fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric() || INITIALS_JAMO.contains(c))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Revised Romanization applied syllable by syllable (예: "삼성전자" → "samseongjeonja")
+pub fn romanize(text: &str) -> String {
+    let mut out = String::new();
+    for c in normalize(text).chars() {
+        match decompose(c) {
+            Some((initial, medial, last)) => {
+                out.push_str(INITIALS_ROMAN[initial]);
+                out.push_str(MEDIALS_ROMAN[medial]);
+                out.push_str(FINALS_ROMAN[last]);
+            }
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+/// Initial consonants of each syllable (예: "삼성전자" → "ㅅㅅㅈㅈ")
+pub fn initials(text: &str) -> String {
+    normalize(text)
+        .chars()
+        .map(|c| decompose(c).map_or(c, |(initial, _, _)| INITIALS_JAMO[initial]))
+        .collect()
+}
+
+/// Search scope
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchScope {
+    #[default]
+    All,
+    Domestic,
+    Overseas,
+}
+
+impl SearchScope {
+    pub fn from_code(code: &str) -> Option<SearchScope> {
+        match code.to_ascii_lowercase().as_str() {
+            "all" => Some(SearchScope::All),
+            "domestic" => Some(SearchScope::Domestic),
+            "overseas" => Some(SearchScope::Overseas),
+            _ => None,
+        }
+    }
+
+    pub fn includes_domestic(&self) -> bool {
+        *self != SearchScope::Overseas
+    }
+
+    pub fn includes_overseas(&self) -> bool {
+        *self != SearchScope::Domestic
+    }
+}
+
+/// Asset class of a search hit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetClass {
+    Stock,
+    Etf,
+    Etn,
+    Reit,
+    Elw,
+    Warrant,
+    Fund,
+    Index,
+    Other,
+}
+
+impl AssetClass {
+    /// From a domestic 증권그룹구분코드 (ST, EF, EN, RT, ...)
+    pub fn from_group_code(code: &str) -> AssetClass {
+        match code {
+            "ST" | "FS" | "DR" => AssetClass::Stock,
+            "EF" | "FE" => AssetClass::Etf,
+            "EN" => AssetClass::Etn,
+            "RT" => AssetClass::Reit,
+            "EW" => AssetClass::Elw,
+            "MF" | "SC" | "IF" | "BC" => AssetClass::Fund,
+            _ => AssetClass::Other,
+        }
+    }
+
+    pub fn from_security_type(kind: SecurityType) -> AssetClass {
+        match kind {
+            SecurityType::Stock => AssetClass::Stock,
+            SecurityType::Etp => AssetClass::Etf,
+            SecurityType::Warrant => AssetClass::Warrant,
+            SecurityType::Index => AssetClass::Index,
+            SecurityType::Other => AssetClass::Other,
+        }
+    }
+}
+
+/// Where a hit came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HitSource {
+    /// 종목정보파일
+    Master,
+    /// 주식기본조회/상품기본조회 API
+    Api,
+}
+
+/// Instrument search result
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InstrumentHit {
+    /// 종목코드/티커
+    pub symbol: String,
+    pub name: String,
+    pub name_en: Option<String>,
+    pub isin: Option<String>,
+    /// 시장 (KOSPI, KOSDAQ, KONEX 또는 해외 거래소코드)
+    pub market: String,
+    /// 주문 시 `extensions.exchange`에 넣을 거래소코드 (KRX, NASD 등)
+    pub exchange: String,
+    pub currency: String,
+    pub asset_class: AssetClass,
+    /// 신규 주문 가능 여부
+    pub tradable: bool,
+    /// 관리종목, 시장경고 등 주의 사항
+    pub warnings: Vec<String>,
+    pub source: HitSource,
+    /// 일치 점수 (높을수록 정확)
+    pub score: u32,
+}
+
+impl InstrumentHit {
+    pub fn from_domestic(instrument: &Instrument, score: u32) -> Self {
+        let status = &instrument.status;
+        let mut warnings = Vec::new();
+        if status.halted {
+            warnings.push("거래정지".to_string());
+        }
+        if status.liquidation {
+            warnings.push("정리매매".to_string());
+        }
+        if status.administrative {
+            warnings.push("관리종목".to_string());
+        }
+        match status.market_warning {
+            MarketWarning::None => {}
+            MarketWarning::Caution => warnings.push("투자주의".to_string()),
+            MarketWarning::Warning => warnings.push("투자경고".to_string()),
+            MarketWarning::Risk => warnings.push("투자위험".to_string()),
+        }
+        if status.short_term_overheat {
+            warnings.push("단기과열".to_string());
+        }
+
+        InstrumentHit {
+            symbol: instrument.short_code.clone(),
+            name: instrument.name.clone(),
+            name_en: None,
+            isin: Some(instrument.isin.clone()).filter(|s| !s.is_empty()),
+            market: instrument.source.market_name().to_string(),
+            exchange: "KRX".to_string(),
+            currency: "KRW".to_string(),
+            asset_class: AssetClass::from_group_code(&instrument.group_code),
+            tradable: instrument.is_tradable(),
+            warnings,
+            source: HitSource::Master,
+            score,
+        }
+    }
+
+    pub fn from_overseas(instrument: &OverseasInstrument, score: u32) -> Self {
+        InstrumentHit {
+            symbol: instrument.symbol.clone(),
+            name: if instrument.name_kr.is_empty() {
+                instrument.name_en.clone()
+            } else {
+                instrument.name_kr.clone()
+            },
+            name_en: Some(instrument.name_en.clone()).filter(|s| !s.is_empty()),
+            isin: None,
+            market: instrument.exchange.code().to_string(),
+            exchange: instrument.exchange.code().to_string(),
+            currency: instrument.currency.clone(),
+            asset_class: AssetClass::from_security_type(instrument.security_type),
+            tradable: instrument.security_type.is_tradable(),
+            warnings: Vec::new(),
+            source: HitSource::Master,
+            score,
+        }
+    }
+
+    /// From 주식기본조회 (국내)
+    pub fn from_stock_info(symbol: &str, info: &StockInfo) -> Self {
+        let market = info
+            .mket_id_cd
+            .as_deref()
+            .and_then(KrxMarket::from_code)
+            .map_or("KRX", |m| match m {
+                KrxMarket::Kospi => "KOSPI",
+                KrxMarket::Kosdaq => "KOSDAQ",
+                KrxMarket::Konex => "KONEX",
+                KrxMarket::EtfEtn => "ETF/ETN",
+                KrxMarket::Elw => "ELW",
+            });
+        let yes = |flag: &Option<String>| flag.as_deref() == Some("Y");
+        let mut warnings = Vec::new();
+        if yes(&info.tr_stop_yn) {
+            warnings.push("거래정지".to_string());
+        }
+        if yes(&info.admn_item_yn) {
+            warnings.push("관리종목".to_string());
+        }
+
+        InstrumentHit {
+            symbol: info.pdno.clone().unwrap_or_else(|| symbol.to_string()),
+            name: info.prdt_abrv_name.clone().unwrap_or_default(),
+            name_en: info.prdt_eng_name.clone().filter(|s| !s.is_empty()),
+            isin: info.std_pdno.clone().filter(|s| !s.is_empty()),
+            market: market.to_string(),
+            exchange: "KRX".to_string(),
+            currency: "KRW".to_string(),
+            asset_class: AssetClass::from_group_code(info.scty_grp_id_cd.as_deref().unwrap_or("")),
+            tradable: !yes(&info.tr_stop_yn),
+            warnings,
+            source: HitSource::Api,
+            score: 100,
+        }
+    }
+
+    /// From 상품기본조회 (해외)
+    pub fn from_product_info(symbol: &str, exchange: Exchange, info: &ProductInfo) -> Self {
+        InstrumentHit {
+            symbol: symbol.to_ascii_uppercase(),
+            name: info.prdt_name.clone().unwrap_or_default(),
+            name_en: info.prdt_eng_name.clone().filter(|s| !s.is_empty()),
+            isin: info.std_pdno.clone().filter(|s| !s.is_empty()),
+            market: exchange.code().to_string(),
+            exchange: exchange.code().to_string(),
+            currency: exchange_currency(exchange).to_string(),
+            asset_class: AssetClass::Other,
+            tradable: true,
+            warnings: Vec::new(),
+            source: HitSource::Api,
+            score: 100,
+        }
+    }
+}
+
+/// Trading currency of an exchange
+pub fn exchange_currency(exchange: Exchange) -> &'static str {
+    match exchange {
+        Exchange::SEHK => "HKD",
+        Exchange::SHAA | Exchange::SZAA => "CNY",
+        Exchange::TKSE => "JPY",
+        Exchange::HASE | Exchange::VNSE => "VND",
+        _ => "USD",
+    }
+}
+
+/// Prepared query
+struct Query {
+    raw: String,
+    normalized: String,
+    /// 초성만으로 된 질의
+    jamo_only: bool,
+}
+
+impl Query {
+    fn new(query: &str) -> Self {
+        let normalized = normalize(query);
+        let jamo_only =
+            !normalized.is_empty() && normalized.chars().all(|c| INITIALS_JAMO.contains(&c));
+        Query {
+            raw: query.trim().to_ascii_uppercase(),
+            normalized,
+            jamo_only,
+        }
+    }
+
+    /// Score a candidate (0 = no match)
+    fn score(&self, code: &str, isin: &str, name: &str, name_en: &str) -> u32 {
+        if self.normalized.is_empty() {
+            return 0;
+        }
+        if code.eq_ignore_ascii_case(&self.raw) {
+            return 100;
+        }
+        if !isin.is_empty() && isin.eq_ignore_ascii_case(&self.raw) {
+            return 95;
+        }
+
+        let q = self.normalized.as_str();
+        let text_score = |text: &str, exact: u32, prefix: u32, contains: u32| {
+            if text.is_empty() {
+                0
+            } else if text == q {
+                exact
+            } else if text.starts_with(q) {
+                prefix
+            } else if text.contains(q) {
+                contains
+            } else {
+                0
+            }
+        };
+
+        let mut best = 0;
+        if code.to_ascii_lowercase().starts_with(q) {
+            best = 80;
+        }
+        if self.jamo_only {
+            return best.max(text_score(&initials(name), 48, 45, 40));
+        }
+        best.max(text_score(&normalize(name), 75, 65, 55))
+            .max(text_score(&normalize(name_en), 70, 60, 50))
+            .max(text_score(&romanize(name), 38, 35, 30))
+    }
+}
+
+/// Search the loaded symbol masters
+pub fn search_masters(
+    domestic: &InstrumentTable,
+    overseas: &OverseasSymbolTable,
+    query: &str,
+    scope: SearchScope,
+    limit: usize,
+) -> Vec<InstrumentHit> {
+    let query = Query::new(query);
+    let mut hits = Vec::new();
+
+    if scope.includes_domestic() {
+        for instrument in domestic.iter() {
+            let score = query.score(
+                &instrument.short_code,
+                &instrument.isin,
+                &instrument.name,
+                "",
+            );
+            if score > 0 {
+                hits.push(InstrumentHit::from_domestic(instrument, score));
+            }
+        }
+    }
+    if scope.includes_overseas() {
+        for instrument in overseas.iter() {
+            let score = query.score(
+                &instrument.symbol,
+                "",
+                &instrument.name_kr,
+                &instrument.name_en,
+            );
+            if score > 0 {
+                hits.push(InstrumentHit::from_overseas(instrument, score));
+            }
+        }
+    }
+
+    sort_hits(&mut hits);
+    hits.truncate(limit);
+    hits
+}
+
+/// Order by score, tradable first, then shorter names and codes
+pub fn sort_hits(hits: &mut [InstrumentHit]) {
+    hits.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then(b.tradable.cmp(&a.tradable))
+            .then(a.name.chars().count().cmp(&b.name.chars().count()))
+            .then(a.symbol.cmp(&b.symbol))
+    });
+}
+
+/// Whether a query looks like a domestic short code (예: "005930", "0000J0")
+pub fn is_domestic_code(query: &str) -> bool {
+    let query = query.trim();
+    query.len() == 6
+        && query.bytes().all(|b| b.is_ascii_alphanumeric())
+        && query.bytes().next().is_some_and(|b| b.is_ascii_digit())
+}
+
+/// Whether a query looks like an overseas ticker (예: "AAPL", "BRK.B", "00700")
+pub fn is_overseas_ticker(query: &str) -> bool {
+    let query = query.trim();
+    !query.is_empty()
+        && query.len() <= 12
+        && query
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'.' || b == b'-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kis::master::{fixtures, MasterFile};
+
+    #[test]
+    fn test_romanize_and_initials() {
+        assert_eq!(romanize("삼성전자"), "samseongjeonja");
+        assert_eq!(romanize("카카오 뱅크"), "kakaobaengkeu");
+        assert_eq!(romanize("KODEX 200"), "kodex200");
+        assert_eq!(initials("삼성전자우"), "ㅅㅅㅈㅈㅇ");
+        assert!(is_domestic_code("005930"));
+        assert!(is_domestic_code("0000J0"));
+        assert!(!is_domestic_code("AAPL"));
+        assert!(is_overseas_ticker("BRK.B"));
+    }
+
+    #[test]
+    fn test_search_masters_ranking() {
+        // CP949 rows built via the domestic master layout
+        fn row(code: &str, name: &str, halted: bool) -> Vec<u8> {
+            let file = MasterFile::Kospi;
+            let isin = format!("KR7{}003", code);
+            let halted_offset = fixtures::halted_offset(file);
+            let mut patches = vec![(0, "ST")];
+            if halted {
+                patches.push((halted_offset, "Y"));
+            }
+            let name = encoding_rs::EUC_KR.encode(name).0;
+            let mut line = fixtures::row(file, code, &isin, &name, &patches);
+            line.push(b'\n');
+            line
+        }
+        let mut bytes = row("005930", "삼성전자", false);
+        bytes.extend(row("005935", "삼성전자우", false));
+        bytes.extend(row("028260", "삼성물산", true));
+        let mut domestic = InstrumentTable::new();
+        domestic.load(MasterFile::Kospi, &bytes);
+        let overseas = OverseasSymbolTable::new();
+
+        let hits = search_masters(&domestic, &overseas, "삼성전자", SearchScope::All, 10);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].symbol, "005930");
+        assert_eq!(hits[0].market, "KOSPI");
+
+        let hits = search_masters(&domestic, &overseas, "ㅅㅅ", SearchScope::All, 10);
+        assert_eq!(hits.len(), 3);
+        assert!(!hits[2].tradable);
+        assert_eq!(hits[2].warnings, vec!["거래정지".to_string()]);
+
+        let hits = search_masters(&domestic, &overseas, "samseong", SearchScope::All, 1);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].symbol, "005930");
+
+        let hits = search_masters(&domestic, &overseas, "KR7005935003", SearchScope::All, 10);
+        assert_eq!(hits[0].symbol, "005935");
+        assert!(search_masters(&domestic, &overseas, "삼성", SearchScope::Overseas, 10).is_empty());
+    }
+}
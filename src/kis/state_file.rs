@@ -0,0 +1,71 @@
+//! JSON state files in the host-provided state directory
+//!
+//! 킬 스위치 상태와 `client_order_id` 기록처럼 플러그인 재시작 후에도 유지해야 하는 값을
+//! `initialize()`의 `state_dir` 아래 파일 하나씩으로 저장합니다.
+
+use super::types::{KisError, KisResult};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::Path;
+
+/// Load `file` from `dir` (`None` if nothing was saved yet)
+///
+/// # Errors
+/// * 파일을 읽지 못하면 `KisError::Io`, 내용이 올바르지 않으면 `KisError::Parse`
+pub fn load<T: DeserializeOwned>(dir: &str, file: &str) -> KisResult<Option<T>> {
+    let path = Path::new(dir).join(file);
+    let raw = match std::fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(KisError::Io(format!(
+                "Failed to read {}: {}",
+                path.display(),
+                e
+            )))
+        }
+    };
+    serde_json::from_str(&raw)
+        .map(Some)
+        .map_err(|e| KisError::Parse(format!("Invalid {}: {}", path.display(), e)))
+}
+
+/// Save `value` as `file` in `dir`
+pub fn save<T: Serialize>(dir: &str, file: &str, value: &T) -> KisResult<()> {
+    let path = Path::new(dir).join(file);
+    let raw = serde_json::to_string_pretty(value)
+        .map_err(|e| KisError::Parse(format!("Failed to serialize {}: {}", file, e)))?;
+    std::fs::write(&path, raw)
+        .map_err(|e| KisError::Io(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_file_round_trip_and_errors() {
+        let dir = std::env::temp_dir().join(format!("state_file_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir_str = dir.to_str().unwrap();
+
+        assert_eq!(load::<Vec<u32>>(dir_str, "state.json").unwrap(), None);
+        save(dir_str, "state.json", &vec![1_u32, 2]).unwrap();
+        assert_eq!(
+            load::<Vec<u32>>(dir_str, "state.json").unwrap(),
+            Some(vec![1, 2])
+        );
+
+        std::fs::write(dir.join("broken.json"), "{").unwrap();
+        assert!(matches!(
+            load::<Vec<u32>>(dir_str, "broken.json"),
+            Err(KisError::Parse(_))
+        ));
+        assert!(matches!(
+            save(dir_str, "missing/state.json", &1),
+            Err(KisError::Io(_))
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,6 +1,10 @@
 //! Common types for KIS API
 
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Utc, Weekday};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
 
 /// KIS API Configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,6 +57,8 @@ pub enum KisError {
     Parse(String),
     /// Validation error
     Validation(String),
+    /// Local file I/O error (state directory)
+    Io(String),
 }
 
 impl std::fmt::Display for KisError {
@@ -63,10 +69,18 @@ impl std::fmt::Display for KisError {
             KisError::Network(msg) => write!(f, "Network error: {}", msg),
             KisError::Parse(msg) => write!(f, "Parse error: {}", msg),
             KisError::Validation(msg) => write!(f, "Validation error: {}", msg),
+            KisError::Io(msg) => write!(f, "I/O error: {}", msg),
         }
     }
 }
 
+impl KisError {
+    /// 주문 전송 후 접수 여부를 알 수 없는 오류 (재전송 전 체결내역 대사 필요)
+    pub fn is_ambiguous(&self) -> bool {
+        matches!(self, KisError::Network(_))
+    }
+}
+
 /// Result type for KIS API operations
 pub type KisResult<T> = Result<T, KisError>;
 
@@ -92,6 +106,260 @@ impl<T> KisApiResponse<T> {
     }
 }
 
+/// 고정소수점 소수 자릿수 (10^-8 단위까지 정확히 표현)
+pub const FIXED_SCALE: u32 = 8;
+const FIXED_FACTOR: i128 = 100_000_000;
+
+/// Parse a decimal string ("-12.345", "+0.5", "  70000 ") into units of 10^-8
+///
+/// 8자리를 넘는 소수부는 반올림합니다(0.5는 0에서 먼 쪽).
+fn parse_fixed(value: &str) -> Result<i128, KisError> {
+    let invalid = || KisError::Parse(format!("Invalid decimal: {:?}", value));
+
+    let trimmed = value.trim();
+    let (negative, digits) = match trimmed.as_bytes().first() {
+        Some(b'-') => (true, &trimmed[1..]),
+        Some(b'+') => (false, &trimmed[1..]),
+        _ => (false, trimmed),
+    };
+    let (int_part, frac_part) = match digits.split_once('.') {
+        Some((i, f)) => (i, f),
+        None => (digits, ""),
+    };
+    if (int_part.is_empty() && frac_part.is_empty())
+        || !int_part.bytes().all(|b| b.is_ascii_digit())
+        || !frac_part.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let mut units: i128 = 0;
+    for b in int_part.bytes() {
+        units = units
+            .checked_mul(10)
+            .and_then(|u| u.checked_add(i128::from(b - b'0')))
+            .ok_or_else(invalid)?;
+    }
+    units = units.checked_mul(FIXED_FACTOR).ok_or_else(invalid)?;
+
+    let mut scale = FIXED_FACTOR;
+    for b in frac_part.bytes().take(FIXED_SCALE as usize) {
+        scale /= 10;
+        units += i128::from(b - b'0') * scale;
+    }
+    if let Some(&b) = frac_part.as_bytes().get(FIXED_SCALE as usize) {
+        if b >= b'5' {
+            units += 1;
+        }
+    }
+
+    Ok(if negative { -units } else { units })
+}
+
+/// Round units of 10^-8 to `decimals` places, half away from zero
+fn round_fixed(units: i128, decimals: u32) -> i128 {
+    if decimals >= FIXED_SCALE {
+        return units;
+    }
+    let factor = 10_i128.pow(FIXED_SCALE - decimals);
+    let quotient = units / factor;
+    let remainder = units % factor;
+    let adjust = if remainder.abs() * 2 >= factor {
+        units.signum()
+    } else {
+        0
+    };
+    (quotient + adjust) * factor
+}
+
+/// Format units of 10^-8 with exactly `decimals` places, or trimmed when `None`
+fn format_fixed(units: i128, decimals: Option<u32>) -> String {
+    let units = match decimals {
+        Some(d) => round_fixed(units, d),
+        None => units,
+    };
+    let sign = if units < 0 { "-" } else { "" };
+    let int_part = units.abs() / FIXED_FACTOR;
+    let frac = format!("{:08}", units.abs() % FIXED_FACTOR);
+
+    let frac = match decimals {
+        Some(d) if d <= FIXED_SCALE => frac[..d as usize].to_string(),
+        Some(d) => format!("{}{}", frac, "0".repeat((d - FIXED_SCALE) as usize)),
+        None => frac.trim_end_matches('0').to_string(),
+    };
+
+    if frac.is_empty() {
+        format!("{}{}", sign, int_part)
+    } else {
+        format!("{}{}.{}", sign, int_part, frac)
+    }
+}
+
+macro_rules! fixed_point {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name(i128);
+
+        impl $name {
+            pub const ZERO: $name = $name(0);
+
+            /// Build from raw units of 10^-8
+            pub const fn from_units(units: i128) -> Self {
+                $name(units)
+            }
+
+            /// Raw units of 10^-8
+            pub const fn units(self) -> i128 {
+                self.0
+            }
+
+            /// Build from a whole number
+            pub fn from_int(value: i64) -> Self {
+                $name(i128::from(value) * FIXED_FACTOR)
+            }
+
+            /// Convert from `f64` via its shortest round-trip representation
+            pub fn from_f64(value: f64) -> Option<Self> {
+                if !value.is_finite() {
+                    return None;
+                }
+                parse_fixed(&value.to_string()).ok().map($name)
+            }
+
+            /// Lossy conversion for the plugin API
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / FIXED_FACTOR as f64
+            }
+
+            pub fn is_zero(self) -> bool {
+                self.0 == 0
+            }
+
+            pub fn is_negative(self) -> bool {
+                self.0 < 0
+            }
+
+            /// Round to `decimals` places (half away from zero)
+            pub fn round_dp(self, decimals: u32) -> Self {
+                $name(round_fixed(self.0, decimals))
+            }
+
+            /// Whole-number value, or `None` if there is a fractional part
+            pub fn to_whole(self) -> Option<i64> {
+                if self.0 % FIXED_FACTOR == 0 {
+                    i64::try_from(self.0 / FIXED_FACTOR).ok()
+                } else {
+                    None
+                }
+            }
+
+            /// Integer part (truncated toward zero)
+            pub fn trunc(self) -> i64 {
+                (self.0 / FIXED_FACTOR) as i64
+            }
+
+            /// Format with exactly `decimals` places (rounded)
+            pub fn format_dp(self, decimals: u32) -> String {
+                format_fixed(self.0, Some(decimals))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = KisError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_fixed(s).map($name)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&format_fixed(self.0, None))
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, rhs: $name) -> $name {
+                $name(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, rhs: $name) -> $name {
+                $name(self.0 - rhs.0)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name(-self.0)
+            }
+        }
+    };
+}
+
+fixed_point!(
+    /// 가격 (주문단가, 현재가 등)
+    Price
+);
+fixed_point!(
+    /// 수량 (소수점 수량 포함)
+    Quantity
+);
+fixed_point!(
+    /// 금액 (평가금액, 예수금 등)
+    Amount
+);
+fixed_point!(
+    /// 비율 (수익률, 등락률 등 % 단위)
+    Rate
+);
+
+impl Mul<Quantity> for Price {
+    type Output = Amount;
+
+    fn mul(self, rhs: Quantity) -> Amount {
+        Amount(self.0 * rhs.0 / FIXED_FACTOR)
+    }
+}
+
+impl Div<Quantity> for Amount {
+    type Output = Price;
+
+    /// Average price of `self` over `rhs` (panics on a zero quantity)
+    fn div(self, rhs: Quantity) -> Price {
+        Price(self.0 * FIXED_FACTOR / rhs.0)
+    }
+}
+
+impl Amount {
+    /// Convert a foreign-currency amount to KRW at `rate` (원/외화)
+    pub fn at_rate(self, rate: Price) -> Amount {
+        Amount(self.0 * rate.0 / FIXED_FACTOR)
+    }
+}
+
+impl Quantity {
+    /// Whole order quantity as `u32` (소수점 수량이나 범위를 넘으면 `None`)
+    pub fn to_order_qty(self) -> Option<u32> {
+        self.to_whole().and_then(|q| u32::try_from(q).ok())
+    }
+}
+
+impl Price {
+    /// Whole-won price as `u32` (원 단위 미만이나 음수이면 `None`)
+    pub fn to_krw(self) -> Option<u32> {
+        self.to_whole().and_then(|p| u32::try_from(p).ok())
+    }
+}
+
 /// Order side
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum OrderSide {
@@ -128,6 +396,20 @@ pub enum OrderType {
     PreMarket,
     /// 장후시간외
     AfterMarket,
+    /// 시간외단일가
+    AfterHoursSinglePrice,
+    /// IOC지정가
+    IocLimit,
+    /// FOK지정가
+    FokLimit,
+    /// IOC시장가
+    IocMarket,
+    /// FOK시장가
+    FokMarket,
+    /// IOC최유리
+    IocBestLimit,
+    /// FOK최유리
+    FokBestLimit,
 }
 
 impl OrderType {
@@ -141,6 +423,398 @@ impl OrderType {
             OrderType::PriorityLimit => "04",
             OrderType::PreMarket => "05",
             OrderType::AfterMarket => "06",
+            OrderType::AfterHoursSinglePrice => "07",
+            OrderType::IocLimit => "11",
+            OrderType::FokLimit => "12",
+            OrderType::IocMarket => "13",
+            OrderType::FokMarket => "14",
+            OrderType::IocBestLimit => "15",
+            OrderType::FokBestLimit => "16",
+        }
+    }
+
+    /// Parse from ORD_DVSN code
+    pub fn from_code(code: &str) -> Option<OrderType> {
+        match code {
+            "00" => Some(OrderType::Limit),
+            "01" => Some(OrderType::Market),
+            "02" => Some(OrderType::ConditionalLimit),
+            "03" => Some(OrderType::BestLimit),
+            "04" => Some(OrderType::PriorityLimit),
+            "05" => Some(OrderType::PreMarket),
+            "06" => Some(OrderType::AfterMarket),
+            "07" => Some(OrderType::AfterHoursSinglePrice),
+            "11" => Some(OrderType::IocLimit),
+            "12" => Some(OrderType::FokLimit),
+            "13" => Some(OrderType::IocMarket),
+            "14" => Some(OrderType::FokMarket),
+            "15" => Some(OrderType::IocBestLimit),
+            "16" => Some(OrderType::FokBestLimit),
+            _ => None,
+        }
+    }
+
+    /// Whether the order carries its own price (ORD_UNPR 필수)
+    pub fn requires_price(&self) -> bool {
+        matches!(
+            self,
+            OrderType::Limit
+                | OrderType::ConditionalLimit
+                | OrderType::AfterHoursSinglePrice
+                | OrderType::IocLimit
+                | OrderType::FokLimit
+        )
+    }
+
+    /// Apply a time-in-force condition (IOC/FOK) to a base order type
+    pub fn with_time_in_force(self, tif: TimeInForce) -> KisResult<OrderType> {
+        let converted = match (tif, self) {
+            (TimeInForce::Day, t) => Some(t),
+            (TimeInForce::Ioc, OrderType::Limit | OrderType::IocLimit) => Some(OrderType::IocLimit),
+            (TimeInForce::Ioc, OrderType::Market | OrderType::IocMarket) => {
+                Some(OrderType::IocMarket)
+            }
+            (TimeInForce::Ioc, OrderType::BestLimit | OrderType::IocBestLimit) => {
+                Some(OrderType::IocBestLimit)
+            }
+            (TimeInForce::Fok, OrderType::Limit | OrderType::FokLimit) => Some(OrderType::FokLimit),
+            (TimeInForce::Fok, OrderType::Market | OrderType::FokMarket) => {
+                Some(OrderType::FokMarket)
+            }
+            (TimeInForce::Fok, OrderType::BestLimit | OrderType::FokBestLimit) => {
+                Some(OrderType::FokBestLimit)
+            }
+            _ => None,
+        };
+
+        converted.ok_or_else(|| {
+            KisError::Validation(format!(
+                "{:?} cannot be combined with order type {:?} ({})",
+                tif,
+                self,
+                self.code()
+            ))
+        })
+    }
+
+    /// Whether the order type is accepted during the given KRX session
+    pub fn is_allowed_in(&self, session: KrxSession) -> bool {
+        match self {
+            OrderType::PreMarket => session == KrxSession::PreMarketClose,
+            OrderType::AfterMarket => session == KrxSession::AfterMarketClose,
+            OrderType::AfterHoursSinglePrice => session == KrxSession::AfterHoursSinglePrice,
+            OrderType::Limit | OrderType::Market | OrderType::ConditionalLimit => matches!(
+                session,
+                KrxSession::PreMarketClose
+                    | KrxSession::OpeningAuction
+                    | KrxSession::Continuous
+                    | KrxSession::ClosingAuction
+            ),
+            // 최유리/최우선 및 IOC/FOK는 접속매매 시간에만 가능
+            _ => session == KrxSession::Continuous,
+        }
+    }
+
+    /// Validate the order type against the KRX session at the given KST time
+    pub fn validate_krx_session(&self, kst: NaiveDateTime) -> KisResult<()> {
+        match KrxSession::at(kst) {
+            Some(session) if self.is_allowed_in(session) => Ok(()),
+            Some(session) => Err(KisError::Validation(format!(
+                "Order type {:?} ({}) is not accepted during the KRX {:?} session",
+                self,
+                self.code(),
+                session
+            ))),
+            None => Err(KisError::Validation(format!(
+                "KRX is not accepting orders now (order type {:?}, {})",
+                self,
+                self.code()
+            ))),
+        }
+    }
+}
+
+/// 호가단위 산정용 국내 시장구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KrxMarket {
+    /// 유가증권시장
+    Kospi,
+    /// 코스닥
+    Kosdaq,
+    /// 코넥스
+    Konex,
+    /// ETF/ETN (2,000원 미만 1원, 이상 5원)
+    EtfEtn,
+    /// ELW (가격대와 무관하게 5원)
+    Elw,
+}
+
+impl KrxMarket {
+    /// Parse from a market name ("KOSPI", "KOSDAQ", "KONEX", "ETF", "ETN", "ELW", case-insensitive)
+    pub fn from_code(code: &str) -> Option<KrxMarket> {
+        match code.to_ascii_uppercase().as_str() {
+            "KOSPI" | "STK" => Some(KrxMarket::Kospi),
+            "KOSDAQ" | "KSQ" => Some(KrxMarket::Kosdaq),
+            "KONEX" | "KNX" => Some(KrxMarket::Konex),
+            "ETF" | "ETN" => Some(KrxMarket::EtfEtn),
+            "ELW" => Some(KrxMarket::Elw),
+            _ => None,
+        }
+    }
+
+    /// 호가가격단위 (2023.01 개편 기준, 유가증권/코스닥/코넥스 공통, ETF/ETN은 2단계, ELW는 5원)
+    pub fn tick_size(&self, price: u32) -> u32 {
+        match self {
+            KrxMarket::EtfEtn => return if price < 2_000 { 1 } else { 5 },
+            KrxMarket::Elw => return 5,
+            _ => {}
+        }
+        match price {
+            0..=1_999 => 1,
+            2_000..=4_999 => 5,
+            5_000..=19_999 => 10,
+            20_000..=49_999 => 50,
+            50_000..=199_999 => 100,
+            200_000..=499_999 => 500,
+            _ => 1_000,
+        }
+    }
+
+    /// 가격제한폭 (%)
+    pub fn price_limit_percent(&self) -> u32 {
+        match self {
+            KrxMarket::Konex => 15,
+            _ => 30,
+        }
+    }
+
+    /// Whether the price sits on a valid tick
+    pub fn is_on_tick(&self, price: u32) -> bool {
+        price.is_multiple_of(self.tick_size(price))
+    }
+
+    /// Round to the nearest valid tick (ties round up)
+    ///
+    /// 가격대 경계(2,000원, 5,000원 등)는 상위 호가단위의 배수이므로 반올림으로
+    /// 경계를 넘어도 유효한 호가가 됩니다.
+    pub fn round_to_tick(&self, price: u32) -> u32 {
+        let tick = self.tick_size(price);
+        (price + tick / 2) / tick * tick
+    }
+
+    /// Daily (lower, upper) limit from the base price (기준가), snapped inward to valid ticks
+    pub fn price_limits(&self, base_price: u32) -> (u32, u32) {
+        let delta = u64::from(base_price) * u64::from(self.price_limit_percent()) / 100;
+        let upper = (u64::from(base_price) + delta) as u32;
+        let lower = (u64::from(base_price) - delta) as u32;
+
+        let upper = upper - upper % self.tick_size(upper);
+        let lower_tick = self.tick_size(lower);
+        let lower = if self.is_on_tick(lower) {
+            lower
+        } else {
+            lower + lower_tick - lower % lower_tick
+        };
+        (lower.max(1), upper)
+    }
+}
+
+/// 주문 유효조건
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeInForce {
+    /// 당일 유효
+    Day,
+    /// 즉시체결 후 잔량취소 (Immediate or Cancel)
+    Ioc,
+    /// 전량체결 아니면 전량취소 (Fill or Kill)
+    Fok,
+}
+
+impl TimeInForce {
+    /// Parse from "day" / "ioc" / "fok" (case-insensitive)
+    pub fn from_code(code: &str) -> Option<TimeInForce> {
+        match code.to_ascii_lowercase().as_str() {
+            "day" => Some(TimeInForce::Day),
+            "ioc" => Some(TimeInForce::Ioc),
+            "fok" => Some(TimeInForce::Fok),
+            _ => None,
+        }
+    }
+}
+
+/// 한국거래소 주문접수 세션 (KST, 평일 기준, 휴장일 미반영)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KrxSession {
+    /// 장전시간외 종가 (08:30~08:40), 시가 동시호가 접수 병행
+    PreMarketClose,
+    /// 시가 동시호가 (08:40~09:00)
+    OpeningAuction,
+    /// 정규장 접속매매 (09:00~15:20)
+    Continuous,
+    /// 종가 동시호가 (15:20~15:30)
+    ClosingAuction,
+    /// 장후시간외 종가 (15:40~16:00)
+    AfterMarketClose,
+    /// 시간외단일가 (16:00~18:00)
+    AfterHoursSinglePrice,
+}
+
+impl KrxSession {
+    /// KRX session at the given KST time
+    pub fn at(kst: NaiveDateTime) -> Option<KrxSession> {
+        if kst.weekday().number_from_monday() > 5 {
+            return None;
+        }
+        let minute = kst.hour() * 60 + kst.minute();
+        match minute {
+            m if (8 * 60 + 30..8 * 60 + 40).contains(&m) => Some(KrxSession::PreMarketClose),
+            m if (8 * 60 + 40..9 * 60).contains(&m) => Some(KrxSession::OpeningAuction),
+            m if (9 * 60..15 * 60 + 20).contains(&m) => Some(KrxSession::Continuous),
+            m if (15 * 60 + 20..15 * 60 + 30).contains(&m) => Some(KrxSession::ClosingAuction),
+            m if (15 * 60 + 40..16 * 60).contains(&m) => Some(KrxSession::AfterMarketClose),
+            m if (16 * 60..18 * 60).contains(&m) => Some(KrxSession::AfterHoursSinglePrice),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            KrxSession::PreMarketClose => "pre_market_close",
+            KrxSession::OpeningAuction => "opening_auction",
+            KrxSession::Continuous => "continuous",
+            KrxSession::ClosingAuction => "closing_auction",
+            KrxSession::AfterMarketClose => "after_market_close",
+            KrxSession::AfterHoursSinglePrice => "after_hours_single_price",
+        }
+    }
+}
+
+/// 국내주식 거래소구분 (EXCG_ID_DVSN_CD)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DomesticExchange {
+    /// 한국거래소
+    Krx,
+    /// 넥스트레이드 (대체거래소)
+    Nxt,
+    /// 최선집행 (Smart Order Routing)
+    Sor,
+}
+
+impl DomesticExchange {
+    /// EXCG_ID_DVSN_CD 값
+    pub fn code(&self) -> &'static str {
+        match self {
+            DomesticExchange::Krx => "KRX",
+            DomesticExchange::Nxt => "NXT",
+            DomesticExchange::Sor => "SOR",
+        }
+    }
+
+    /// 시세 조회용 FID_COND_MRKT_DIV_CODE (J:KRX, NX:NXT, UN:통합)
+    pub fn market_div_code(&self) -> &'static str {
+        match self {
+            DomesticExchange::Krx => "J",
+            DomesticExchange::Nxt => "NX",
+            DomesticExchange::Sor => "UN",
+        }
+    }
+
+    /// Parse from EXCG_ID_DVSN_CD (case-insensitive)
+    pub fn from_code(code: &str) -> Option<DomesticExchange> {
+        match code.to_ascii_uppercase().as_str() {
+            "KRX" => Some(DomesticExchange::Krx),
+            "NXT" => Some(DomesticExchange::Nxt),
+            "SOR" => Some(DomesticExchange::Sor),
+            _ => None,
+        }
+    }
+
+    /// Resolve the venue actually used at the given KST time
+    ///
+    /// SOR은 KRX와 NXT가 함께 열리는 메인마켓에서만 동작하므로,
+    /// NXT 프리/애프터마켓 시간에는 NXT로 전송합니다.
+    pub fn resolve_at(&self, kst: NaiveDateTime) -> DomesticExchange {
+        match (self, NxtSession::at(kst)) {
+            (DomesticExchange::Sor, Some(NxtSession::PreMarket))
+            | (DomesticExchange::Sor, Some(NxtSession::AfterMarket)) => DomesticExchange::Nxt,
+            _ => *self,
+        }
+    }
+
+    /// Validate an order type for this venue at the given KST time
+    ///
+    /// KRX는 세션별 허용 주문구분을 검사합니다. NXT/SOR은 KRX 시간외 주문구분(05, 06, 07)을
+    /// 받지 않으며, NXT 프리/애프터마켓은 지정가만 가능합니다.
+    pub fn validate_order(&self, order_type: OrderType, kst: NaiveDateTime) -> KisResult<()> {
+        if *self == DomesticExchange::Krx {
+            return order_type.validate_krx_session(kst);
+        }
+
+        if matches!(
+            order_type,
+            OrderType::PreMarket | OrderType::AfterMarket | OrderType::AfterHoursSinglePrice
+        ) {
+            return Err(KisError::Validation(format!(
+                "Order type {:?} ({}) is KRX-only and cannot be routed to {}",
+                order_type,
+                order_type.code(),
+                self.code()
+            )));
+        }
+
+        match (self, NxtSession::at(kst)) {
+            (_, None) => Err(KisError::Validation(format!(
+                "{} is closed (NXT sessions: 08:00~08:50, 09:00~15:20, 15:30~20:00 KST)",
+                self.code()
+            ))),
+            (DomesticExchange::Sor, Some(session)) if session != NxtSession::Main => {
+                Err(KisError::Validation(format!(
+                    "SOR is only available in the main market; route to NXT during {:?}",
+                    session
+                )))
+            }
+            (_, Some(session)) if session != NxtSession::Main && order_type != OrderType::Limit => {
+                Err(KisError::Validation(format!(
+                    "Only limit orders are accepted in the NXT {:?} session",
+                    session
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// 넥스트레이드 거래세션
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NxtSession {
+    /// 프리마켓 (08:00~08:50)
+    PreMarket,
+    /// 메인마켓 (09:00~15:20)
+    Main,
+    /// 애프터마켓 (15:30~20:00)
+    AfterMarket,
+}
+
+impl NxtSession {
+    /// NXT session at the given KST time (평일 기준, 휴장일 미반영)
+    pub fn at(kst: NaiveDateTime) -> Option<NxtSession> {
+        if kst.weekday().number_from_monday() > 5 {
+            return None;
+        }
+        let minute = kst.hour() * 60 + kst.minute();
+        match minute {
+            m if (8 * 60..8 * 60 + 50).contains(&m) => Some(NxtSession::PreMarket),
+            m if (9 * 60..15 * 60 + 20).contains(&m) => Some(NxtSession::Main),
+            m if (15 * 60 + 30..20 * 60).contains(&m) => Some(NxtSession::AfterMarket),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            NxtSession::PreMarket => "pre_market",
+            NxtSession::Main => "main",
+            NxtSession::AfterMarket => "after_market",
         }
     }
 }
@@ -162,6 +836,10 @@ pub enum Exchange {
     // 베트남
     HASE,
     VNSE,
+    // 미국 주간거래
+    BAQ, // 나스닥 주간
+    BAY, // 뉴욕 주간
+    BAA, // 아멕스 주간
 }
 
 impl Exchange {
@@ -176,13 +854,416 @@ impl Exchange {
             Exchange::TKSE => "TKSE",
             Exchange::HASE => "HASE",
             Exchange::VNSE => "VNSE",
+            Exchange::BAQ => "BAQ",
+            Exchange::BAY => "BAY",
+            Exchange::BAA => "BAA",
+        }
+    }
+
+    /// Exchange code for the order body (OVRS_EXCG_CD)
+    ///
+    /// 주간거래 주문도 정규장 거래소코드(NASD/NYSE/AMEX)로 전송합니다.
+    pub fn order_code(&self) -> &'static str {
+        self.regular().code()
+    }
+
+    /// Exchange code for quotation APIs (EXCD)
+    pub fn quote_code(&self) -> &'static str {
+        match self {
+            Exchange::NYSE => "NYS",
+            Exchange::NASDAQ => "NAS",
+            Exchange::AMEX => "AMS",
+            Exchange::SEHK => "HKS",
+            Exchange::SHAA => "SHS",
+            Exchange::SZAA => "SZS",
+            Exchange::TKSE => "TSE",
+            Exchange::HASE => "HNX",
+            Exchange::VNSE => "HSX",
+            Exchange::BAQ => "BAQ",
+            Exchange::BAY => "BAY",
+            Exchange::BAA => "BAA",
         }
     }
 
-    /// Check if this is a US exchange
+    /// Parse a KIS exchange code (e.g. "NASD")
+    pub fn from_code(code: &str) -> Option<Exchange> {
+        match code.to_ascii_uppercase().as_str() {
+            "NYSE" => Some(Exchange::NYSE),
+            "NASD" | "NASDAQ" => Some(Exchange::NASDAQ),
+            "AMEX" => Some(Exchange::AMEX),
+            "SEHK" => Some(Exchange::SEHK),
+            "SHAA" => Some(Exchange::SHAA),
+            "SZAA" => Some(Exchange::SZAA),
+            "TKSE" => Some(Exchange::TKSE),
+            "HASE" => Some(Exchange::HASE),
+            "VNSE" => Some(Exchange::VNSE),
+            "BAQ" => Some(Exchange::BAQ),
+            "BAY" => Some(Exchange::BAY),
+            "BAA" => Some(Exchange::BAA),
+            _ => None,
+        }
+    }
+
+    /// Parse a quotation exchange code (EXCD, e.g. "NAS")
+    pub fn from_quote_code(code: &str) -> Option<Exchange> {
+        match code.to_ascii_uppercase().as_str() {
+            "NYS" => Some(Exchange::NYSE),
+            "NAS" => Some(Exchange::NASDAQ),
+            "AMS" => Some(Exchange::AMEX),
+            "HKS" => Some(Exchange::SEHK),
+            "SHS" => Some(Exchange::SHAA),
+            "SZS" => Some(Exchange::SZAA),
+            "TSE" => Some(Exchange::TKSE),
+            "HNX" => Some(Exchange::HASE),
+            "HSX" => Some(Exchange::VNSE),
+            "BAQ" => Some(Exchange::BAQ),
+            "BAY" => Some(Exchange::BAY),
+            "BAA" => Some(Exchange::BAA),
+            _ => None,
+        }
+    }
+
+    /// Check if this is a US exchange (including daytime sessions)
     pub fn is_us(&self) -> bool {
-        matches!(self, Exchange::NYSE | Exchange::NASDAQ | Exchange::AMEX)
+        matches!(
+            self,
+            Exchange::NYSE
+                | Exchange::NASDAQ
+                | Exchange::AMEX
+                | Exchange::BAQ
+                | Exchange::BAY
+                | Exchange::BAA
+        )
+    }
+
+    /// Check if this is a US daytime session (주간거래)
+    pub fn is_daytime(&self) -> bool {
+        matches!(self, Exchange::BAQ | Exchange::BAY | Exchange::BAA)
+    }
+
+    /// Regular-session exchange for a daytime session
+    pub fn regular(&self) -> Exchange {
+        match self {
+            Exchange::BAQ => Exchange::NASDAQ,
+            Exchange::BAY => Exchange::NYSE,
+            Exchange::BAA => Exchange::AMEX,
+            other => *other,
+        }
+    }
+
+    /// Daytime-session exchange for a US exchange
+    pub fn daytime(&self) -> Option<Exchange> {
+        match self.regular() {
+            Exchange::NASDAQ => Some(Exchange::BAQ),
+            Exchange::NYSE => Some(Exchange::BAY),
+            Exchange::AMEX => Some(Exchange::BAA),
+            _ => None,
+        }
+    }
+
+    /// Select the US session to route to at the given KST time
+    ///
+    /// 주간거래 시간(평일 KST 10:00~17:00, 서머타임 적용 여부와 무관하게 열려 있는 구간)에는
+    /// 주간거래 거래소를, 그 외에는 정규장 거래소를 반환합니다. 미국 외 거래소는 그대로 반환합니다.
+    pub fn for_session_at(&self, kst: NaiveDateTime) -> Exchange {
+        let daytime = match self.daytime() {
+            Some(d) => d,
+            None => return *self,
+        };
+
+        if daytime.is_open_at(kst) {
+            daytime
+        } else {
+            self.regular()
+        }
+    }
+
+    /// 상품유형코드 (PRDT_TYPE_CD)
+    pub fn product_type_code(&self) -> &'static str {
+        match self {
+            Exchange::NASDAQ | Exchange::BAQ => "512",
+            Exchange::NYSE | Exchange::BAY => "513",
+            Exchange::AMEX | Exchange::BAA => "529",
+            Exchange::SEHK => "501",
+            Exchange::SHAA => "551",
+            Exchange::SZAA => "552",
+            Exchange::TKSE => "515",
+            Exchange::HASE => "507",
+            Exchange::VNSE => "508",
+        }
+    }
+
+    /// 주문단가 소수 자릿수 (미국 1달러 미만 4자리, 홍콩 3자리, 일본/베트남 정수)
+    pub fn price_decimals(&self, price: Price) -> u32 {
+        match self {
+            Exchange::NYSE
+            | Exchange::NASDAQ
+            | Exchange::AMEX
+            | Exchange::BAQ
+            | Exchange::BAY
+            | Exchange::BAA => {
+                if price < Price::from_int(1) {
+                    4
+                } else {
+                    2
+                }
+            }
+            Exchange::SEHK => 3,
+            Exchange::SHAA | Exchange::SZAA => 2,
+            Exchange::TKSE | Exchange::HASE | Exchange::VNSE => 0,
+        }
+    }
+
+    /// Round a limit price to the exchange's price precision
+    pub fn round_price(&self, price: Price) -> Price {
+        price.round_dp(self.price_decimals(price))
+    }
+
+    /// Format an order price for OVRS_ORD_UNPR / FT_ORD_UNPR3
+    ///
+    /// # Errors
+    /// * 거래소 주문단가 자릿수보다 소수 자릿수가 많으면 `KisError::Validation` (`round_price`로
+    ///   먼저 보정)
+    pub fn format_price(&self, price: Price) -> KisResult<String> {
+        if price.is_zero() {
+            return Ok("0".to_string());
+        }
+        let decimals = self.price_decimals(price);
+        if price.round_dp(decimals) != price {
+            return Err(KisError::Validation(format!(
+                "Price {} has more than {} decimal places for {}",
+                price,
+                decimals,
+                self.code()
+            )));
+        }
+        Ok(price.format_dp(decimals))
     }
+
+    /// Regular session in local exchange time as (open, close, lunch break) minutes after midnight
+    ///
+    /// 미국 주간거래는 전일 20:00(ET)에 개장하므로 open이 음수입니다.
+    fn local_hours(&self) -> (i64, i64, Option<(i64, i64)>) {
+        match self {
+            Exchange::NYSE | Exchange::NASDAQ | Exchange::AMEX => (9 * 60 + 30, 16 * 60, None),
+            Exchange::BAQ | Exchange::BAY | Exchange::BAA => (-4 * 60, 4 * 60, None),
+            Exchange::SEHK => (9 * 60 + 30, 16 * 60, Some((12 * 60, 13 * 60))),
+            Exchange::SHAA | Exchange::SZAA => {
+                (9 * 60 + 30, 15 * 60, Some((11 * 60 + 30, 13 * 60)))
+            }
+            Exchange::TKSE => (9 * 60, 15 * 60 + 30, Some((11 * 60 + 30, 12 * 60 + 30))),
+            Exchange::HASE => (9 * 60, 15 * 60, Some((11 * 60 + 30, 13 * 60))),
+            Exchange::VNSE => (9 * 60, 14 * 60 + 45, Some((11 * 60 + 30, 13 * 60))),
+        }
+    }
+
+    /// UTC offset of the exchange's local time on a trade date
+    ///
+    /// 미국은 3월 둘째 일요일부터 11월 첫째 일요일 전까지 서머타임(UTC-4)을 적용합니다.
+    pub fn utc_offset_hours(&self, trade_date: NaiveDate) -> i64 {
+        match self {
+            Exchange::NYSE
+            | Exchange::NASDAQ
+            | Exchange::AMEX
+            | Exchange::BAQ
+            | Exchange::BAY
+            | Exchange::BAA => {
+                if is_us_dst(trade_date) {
+                    -4
+                } else {
+                    -5
+                }
+            }
+            Exchange::SEHK | Exchange::SHAA | Exchange::SZAA => 8,
+            Exchange::TKSE => 9,
+            Exchange::HASE | Exchange::VNSE => 7,
+        }
+    }
+
+    /// Full-day market holidays (YYYYMMDD); 주간거래는 미국 정규장 휴장일을 따릅니다
+    fn holidays(&self) -> &'static [u32] {
+        match self {
+            Exchange::NYSE
+            | Exchange::NASDAQ
+            | Exchange::AMEX
+            | Exchange::BAQ
+            | Exchange::BAY
+            | Exchange::BAA => US_HOLIDAYS,
+            Exchange::SEHK => HK_HOLIDAYS,
+            Exchange::SHAA | Exchange::SZAA => CN_HOLIDAYS,
+            Exchange::TKSE => JP_HOLIDAYS,
+            Exchange::HASE | Exchange::VNSE => VN_HOLIDAYS,
+        }
+    }
+
+    /// Whether the local trade date is a listed market holiday
+    pub fn is_holiday(&self, trade_date: NaiveDate) -> bool {
+        self.holidays().contains(&yyyymmdd(trade_date))
+    }
+
+    /// Whether the exchange trades on the local date (평일이고 휴장일이 아님)
+    pub fn is_trading_day(&self, trade_date: NaiveDate) -> bool {
+        trade_date.weekday().number_from_monday() <= 5 && !self.is_holiday(trade_date)
+    }
+
+    /// Local trade date whose session covers (or is nearest to) the given KST time
+    ///
+    /// 주간거래는 전일 저녁에 개장하므로 KST 날짜와 같은 미국 거래일로 봅니다.
+    pub fn trade_date_at(&self, kst: NaiveDateTime) -> NaiveDate {
+        if self.is_daytime() {
+            return kst.date();
+        }
+        (kst + Duration::hours(self.utc_offset_hours(kst.date()) - 9)).date()
+    }
+
+    /// Regular session for a local trade date, in KST (휴장일/주말은 None)
+    pub fn session_on(&self, trade_date: NaiveDate) -> Option<ExchangeSession> {
+        if !self.is_trading_day(trade_date) {
+            return None;
+        }
+
+        let (open, mut close, lunch) = self.local_hours();
+        let early_close =
+            self.is_us() && !self.is_daytime() && US_EARLY_CLOSES.contains(&yyyymmdd(trade_date));
+        if early_close {
+            close = 13 * 60;
+        }
+
+        // local minutes → KST
+        let shift = 9 - self.utc_offset_hours(trade_date);
+        let midnight = trade_date.and_hms_opt(0, 0, 0)?;
+        let kst = |minute: i64| midnight + Duration::minutes(minute + shift * 60);
+
+        Some(ExchangeSession {
+            trade_date,
+            open: kst(open),
+            close: kst(close),
+            lunch: lunch.map(|(start, end)| (kst(start), kst(end))),
+            early_close,
+        })
+    }
+
+    /// Session open at the given KST time (점심시간 포함)
+    pub fn session_at(&self, kst: NaiveDateTime) -> Option<ExchangeSession> {
+        (-1..=1)
+            .filter_map(|offset| self.session_on(kst.date() + Duration::days(offset)))
+            .find(|session| session.contains(kst))
+    }
+
+    /// First session opening after the given KST time
+    pub fn next_session_after(&self, kst: NaiveDateTime) -> Option<ExchangeSession> {
+        (-1..=MAX_SESSION_LOOKAHEAD_DAYS)
+            .filter_map(|offset| self.session_on(kst.date() + Duration::days(offset)))
+            .find(|session| session.open > kst)
+    }
+
+    /// Check whether the exchange is in its regular session at the given KST time
+    ///
+    /// 서머타임과 휴장일을 반영하며, 점심시간은 장중으로 봅니다 (주문 접수 가능).
+    pub fn is_open_at(&self, kst: NaiveDateTime) -> bool {
+        self.session_at(kst).is_some()
+    }
+}
+
+/// One regular trading session of an overseas exchange, in KST
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExchangeSession {
+    /// 현지 거래일
+    pub trade_date: NaiveDate,
+    /// 개장 시각 (KST)
+    pub open: NaiveDateTime,
+    /// 마감 시각 (KST, 조기폐장 반영)
+    pub close: NaiveDateTime,
+    /// 점심 휴장 (KST)
+    pub lunch: Option<(NaiveDateTime, NaiveDateTime)>,
+    /// 미국 조기폐장일 (13:00 ET 마감)
+    pub early_close: bool,
+}
+
+impl ExchangeSession {
+    pub fn contains(&self, kst: NaiveDateTime) -> bool {
+        kst >= self.open && kst < self.close
+    }
+
+    /// Whether the KST time falls in the lunch break
+    pub fn is_break_at(&self, kst: NaiveDateTime) -> bool {
+        self.lunch
+            .is_some_and(|(start, end)| kst >= start && kst < end)
+    }
+}
+
+/// 다음 세션 탐색 한도 (일)
+const MAX_SESSION_LOOKAHEAD_DAYS: i64 = 14;
+
+// 거래소 휴장일 (YYYYMMDD). 2025~2026년분이며(미국은 2027년 포함), 범위 밖의 날짜는 주말만
+// 휴장으로 처리합니다. 매년 거래소 공지에 맞춰 갱신해야 합니다.
+
+/// NYSE/NASDAQ/AMEX
+const US_HOLIDAYS: &[u32] = &[
+    20250101, 20250109, 20250120, 20250217, 20250418, 20250526, 20250619, 20250704, 20250901,
+    20251127, 20251225, //
+    20260101, 20260119, 20260216, 20260403, 20260525, 20260619, 20260703, 20260907, 20261126,
+    20261225, //
+    20270101, 20270118, 20270215, 20270326, 20270531, 20270618, 20270705, 20270906, 20271125,
+    20271224,
+];
+
+/// 미국 조기폐장일 (13:00 ET)
+const US_EARLY_CLOSES: &[u32] = &[20250703, 20251128, 20251224, 20261127, 20261224, 20271126];
+
+/// HKEX
+const HK_HOLIDAYS: &[u32] = &[
+    20250101, 20250129, 20250130, 20250131, 20250404, 20250418, 20250421, 20250501, 20250505,
+    20250701, 20251001, 20251007, 20251029, 20251225, 20251226, //
+    20260101, 20260217, 20260218, 20260219, 20260403, 20260406, 20260407, 20260501, 20260525,
+    20260619, 20260701, 20261001, 20261019, 20261225,
+];
+
+/// 상해/심천
+const CN_HOLIDAYS: &[u32] = &[
+    20250101, 20250128, 20250129, 20250130, 20250131, 20250203, 20250204, 20250404, 20250501,
+    20250502, 20250505, 20250602, 20251001, 20251002, 20251003, 20251006, 20251007,
+    20251008, //
+    20260101, 20260102, 20260216, 20260217, 20260218, 20260219, 20260220, 20260223, 20260406,
+    20260501, 20260504, 20260505, 20260619, 20260925, 20261001, 20261002, 20261005, 20261006,
+    20261007,
+];
+
+/// 도쿄
+const JP_HOLIDAYS: &[u32] = &[
+    20250101, 20250102, 20250103, 20250113, 20250211, 20250224, 20250320, 20250429, 20250505,
+    20250506, 20250721, 20250811, 20250915, 20250923, 20251013, 20251103, 20251124,
+    20251231, //
+    20260101, 20260102, 20260112, 20260211, 20260223, 20260320, 20260429, 20260504, 20260505,
+    20260506, 20260720, 20260811, 20260921, 20260922, 20260923, 20261012, 20261103, 20261123,
+    20261231,
+];
+
+/// 하노이/호치민
+const VN_HOLIDAYS: &[u32] = &[
+    20250101, 20250127, 20250128, 20250129, 20250130, 20250131, 20250407, 20250430, 20250501,
+    20250502, 20250901, 20250902, //
+    20260101, 20260216, 20260217, 20260218, 20260219, 20260220, 20260427, 20260430, 20260501,
+    20260901, 20260902,
+];
+
+fn yyyymmdd(date: NaiveDate) -> u32 {
+    date.year() as u32 * 10_000 + date.month() * 100 + date.day()
+}
+
+/// US daylight saving time on a local date (3월 둘째 일요일 ~ 11월 첫째 일요일 전일)
+fn is_us_dst(date: NaiveDate) -> bool {
+    let start = NaiveDate::from_weekday_of_month_opt(date.year(), 3, Weekday::Sun, 2);
+    let end = NaiveDate::from_weekday_of_month_opt(date.year(), 11, Weekday::Sun, 1);
+    match (start, end) {
+        (Some(start), Some(end)) => date >= start && date < end,
+        _ => false,
+    }
+}
+
+/// Current time in KST (UTC+9)
+pub fn kst_now() -> NaiveDateTime {
+    (Utc::now() + Duration::hours(9)).naive_utc()
 }
 
 /// Position information
@@ -220,3 +1301,86 @@ pub struct OrderResult {
     /// Message
     pub message: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_point_parse_and_format() {
+        let price: Price = "123.4500".parse().unwrap();
+        assert_eq!(price.to_string(), "123.45");
+        assert_eq!(price.format_dp(3), "123.450");
+        assert_eq!(price.format_dp(1), "123.5");
+        assert_eq!(price.format_dp(0), "123");
+
+        assert_eq!(" +70000 ".parse::<Price>().unwrap(), Price::from_int(70000));
+        assert_eq!("-0.5".parse::<Amount>().unwrap().to_string(), "-0.5");
+        assert_eq!(".25".parse::<Quantity>().unwrap().to_string(), "0.25");
+        assert_eq!(
+            "0.123456785".parse::<Price>().unwrap().to_string(),
+            "0.12345679"
+        );
+        assert!("".parse::<Price>().is_err());
+        assert!("1.2.3".parse::<Price>().is_err());
+        assert!("1e5".parse::<Price>().is_err());
+        assert!(crate::kis::field::KisField::<Price>::parse("").is_blank());
+
+        assert_eq!(Price::from_f64(0.1).unwrap().units(), 10_000_000);
+        assert_eq!(Price::from_f64(0.1 + 0.2).unwrap().format_dp(2), "0.30");
+        assert_eq!(Price::from_f64(f64::NAN), None);
+
+        assert_eq!(Price::from_int(70000).to_krw(), Some(70000));
+        assert_eq!("70000.5".parse::<Price>().unwrap().to_krw(), None);
+        assert_eq!(
+            "10.0000".parse::<Quantity>().unwrap().to_order_qty(),
+            Some(10)
+        );
+
+        let amount = "12.5".parse::<Price>().unwrap() * Quantity::from_int(3);
+        assert_eq!(amount.to_string(), "37.5");
+        assert_eq!((amount - Amount::from_int(40)).to_string(), "-2.5");
+        assert_eq!((amount / Quantity::from_int(3)).to_string(), "12.5");
+        assert_eq!(
+            amount.at_rate("1400.5".parse().unwrap()).to_string(),
+            "52518.75"
+        );
+    }
+
+    #[test]
+    fn test_exchange_price_format() {
+        let price = |s: &str| s.parse::<Price>().unwrap();
+
+        assert_eq!(
+            Exchange::NASDAQ.format_price(price("187.1")).unwrap(),
+            "187.10"
+        );
+        assert_eq!(
+            Exchange::NASDAQ.format_price(price("0.1234")).unwrap(),
+            "0.1234"
+        );
+        assert_eq!(
+            Exchange::SEHK.format_price(price("3.215")).unwrap(),
+            "3.215"
+        );
+        assert_eq!(Exchange::TKSE.format_price(price("2500")).unwrap(), "2500");
+        assert_eq!(Exchange::NYSE.format_price(Price::ZERO).unwrap(), "0");
+
+        // 자릿수를 넘는 지정가는 반올림하지 않고 거부
+        assert!(matches!(
+            Exchange::NASDAQ.format_price(price("187.125")),
+            Err(KisError::Validation(_))
+        ));
+        assert!(Exchange::NASDAQ.format_price(price("0.12345")).is_err());
+        assert!(Exchange::TKSE.format_price(price("2500.5")).is_err());
+
+        assert_eq!(
+            Exchange::NASDAQ.round_price(price("187.125")),
+            price("187.13")
+        );
+        assert_eq!(
+            Exchange::NASDAQ.round_price(price("0.12345")),
+            price("0.1235")
+        );
+    }
+}
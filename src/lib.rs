@@ -11,11 +11,32 @@ mod kis;
 
 use chrono::Utc;
 use lazy_static::lazy_static;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::slice;
 use std::sync::Mutex;
 
-use kis::types::KisConfig;
+use kis::cache::{CacheCategory, Freshness};
+use kis::calendar::TradingCalendar;
+use kis::domestic_stock::{BalanceItem, CreditType, ReservationPeriod};
+use kis::field::FieldReport;
+use kis::interlock::{verify_arming_token, LiveGuard, LiveInterlock};
+use kis::kill_switch::{reservation_window, KillSwitchState, SweepReport};
+use kis::master::{DerivativeTable, InstrumentTable, MasterFile};
+use kis::overseas_master::{master_exchange, OverseasSymbolTable};
+use kis::overseas_stock::{OverseasOrderType, ACCOUNT_EXCHANGES};
+use kis::pnl::{persona_pnl, DailyPnl, Fill, Holding, OverseasHolding, PnlTracker};
+use kis::reconcile::{
+    find_submitted, parse_order_time, ClientOrder, ClientOrderBook, OrderCandidate, OrderIntent,
+};
+use kis::risk::{OrderCheck, RiskEngine, RiskLimits, RiskViolation};
+use kis::search::{
+    exchange_currency, is_domestic_code, is_overseas_ticker, search_masters, sort_hits,
+    InstrumentHit, SearchScope, DEFAULT_SEARCH_LIMIT,
+};
+use kis::types::{
+    kst_now, Amount, DomesticExchange, Exchange, KisConfig, KisError, KrxMarket, Price, Quantity,
+    TimeInForce,
+};
 use kis::KisClient;
 use models::order::{Order, OrderSide, OrderStatus, OrderType};
 use models::portfolio::{AccountBalance, AccountSummary, Position};
@@ -32,6 +53,24 @@ struct BrokerState {
     is_paper: bool,
     orders: HashMap<String, Order>,
     next_order_id: u64,
+    /// 호스트가 지정한 client_order_id → 주문 결과
+    client_orders: ClientOrderBook<Order>,
+    /// 종목정보파일로 구성한 국내 종목 테이블 (주식, ETF/ETN, ELW)
+    instruments: InstrumentTable,
+    /// 선물옵션 종목정보파일로 구성한 파생상품 테이블
+    derivatives: DerivativeTable,
+    /// 해외 종목정보파일로 구성한 티커 → 거래소 테이블
+    overseas_symbols: OverseasSymbolTable,
+    /// 주문 전 리스크 한도
+    risk: RiskEngine,
+    /// 실전투자 세션 인터록 (모의투자는 None)
+    interlock: Option<LiveInterlock>,
+    /// 당일 최고 손익
+    pnl: PnlTracker,
+    /// 킬 스위치 상태 (재무장 전까지 신규 주문 차단)
+    kill_switch: KillSwitchState,
+    /// 킬 스위치 상태를 보존할 디렉터리
+    state_dir: Option<String>,
 }
 
 impl BrokerState {
@@ -42,10 +81,22 @@ impl BrokerState {
             is_paper: true,
             orders: HashMap::new(),
             next_order_id: 1,
+            client_orders: ClientOrderBook::default(),
+            instruments: InstrumentTable::new(),
+            derivatives: DerivativeTable::new(),
+            overseas_symbols: OverseasSymbolTable::new(),
+            risk: RiskEngine::default(),
+            interlock: None,
+            pnl: PnlTracker::new(),
+            kill_switch: KillSwitchState::default(),
+            state_dir: None,
         }
     }
 }
 
+/// Seconds after an unknown submission before a retry may resend it
+const RESEND_GRACE_SECS: i64 = 10;
+
 lazy_static! {
     static ref STATE: Mutex<BrokerState> = Mutex::new(BrokerState::new());
 }
@@ -115,15 +166,129 @@ pub extern "C" fn initialize(ptr: i32, len: i32) -> u64 {
         }));
     }
 
+    // Live trading must be armed explicitly with a token bound to the account:
+    // {"is_paper": false, "arming_token": "<hex HMAC-SHA256(account_no)>", "live_guard": {...}}
+    // keyed with the host-held "arming_secret", which must differ from app_secret
+    let interlock = if is_paper {
+        None
+    } else {
+        let token = config_json.get("arming_token").and_then(|v| v.as_str());
+        let secret = config_json
+            .get("arming_secret")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+        if secret == app_secret {
+            return serialize_response(&serde_json::json!({
+                "success": false,
+                "error": "arming_secret must be kept separately from app_secret"
+            }));
+        }
+        if let Err(e) = verify_arming_token(&account_no, secret, token) {
+            return serialize_response(&serde_json::json!({
+                "success": false,
+                "error": e.to_string()
+            }));
+        }
+        let guard = match config_json.get("live_guard") {
+            Some(value) => match serde_json::from_value::<LiveGuard>(value.clone()) {
+                Ok(guard) => guard,
+                Err(e) => {
+                    return serialize_response(&serde_json::json!({
+                        "success": false,
+                        "error": format!("Invalid live_guard configuration: {}", e)
+                    }))
+                }
+            },
+            None => LiveGuard::default(),
+        };
+        Some(LiveInterlock::new(guard))
+    };
+
     // Create KIS configuration
     let kis_config = KisConfig::new(app_key, app_secret, account_no.clone(), is_paper);
 
     // Create KIS client
     let client = KisClient::new(kis_config);
 
+    // Optional cache TTL overrides: {"cache_ttl_ms": {"account": 3000, "quote": 1000, ...}}
+    if let Some(ttls) = config_json.get("cache_ttl_ms").and_then(|v| v.as_object()) {
+        for (name, value) in ttls {
+            match (CacheCategory::from_code(name), value.as_u64()) {
+                (Some(category), Some(ttl_ms)) => client.set_cache_ttl_ms(category, ttl_ms),
+                _ => eprintln!("[broker-korea] Ignoring cache_ttl_ms.{}", name),
+            }
+        }
+    }
+
+    // Optional pre-trade risk limits: {"risk": {"max_order_quantity": 1000, ...}}
+    let limits = match config_json.get("risk") {
+        Some(value) => match serde_json::from_value::<RiskLimits>(value.clone()) {
+            Ok(limits) => limits,
+            Err(e) => {
+                return serialize_response(&serde_json::json!({
+                    "success": false,
+                    "error": format!("Invalid risk configuration: {}", e)
+                }))
+            }
+        },
+        None => RiskLimits::default(),
+    };
+    // Re-initializing keeps today's order count
+    state.risk.set_limits(limits);
+
+    // Optional directory persisting the kill switch: {"state_dir": "/data/broker-korea"}.
+    // An engaged switch is never cleared by (re-)initializing; only `kill_switch` rearm does.
+    let state_dir = config_json
+        .get("state_dir")
+        .and_then(|v| v.as_str())
+        .filter(|dir| !dir.is_empty())
+        .map(str::to_string);
+    if let Some(dir) = state_dir.as_deref() {
+        match KillSwitchState::load(dir) {
+            Ok(Some(saved)) if saved.engaged => state.kill_switch = saved,
+            Ok(_) => {
+                if state.kill_switch.engaged {
+                    if let Err(e) = state.kill_switch.save(dir) {
+                        eprintln!("[broker-korea] Failed to persist kill switch: {}", e);
+                    }
+                }
+            }
+            Err(e) => {
+                return serialize_response(&serde_json::json!({
+                    "success": false,
+                    "error": format!("Failed to load kill switch state: {}", e)
+                }))
+            }
+        }
+    }
+    // client_order_id별 결과도 같은 디렉터리에 보존 (전송 후 24시간이 지난 기록은 삭제)
+    if let Some(dir) = state_dir.as_deref() {
+        match ClientOrderBook::load(dir) {
+            Ok(Some(mut saved)) => {
+                let now = kst_now();
+                saved.expire(now);
+                state.client_orders.expire(now);
+                saved
+                    .orders
+                    .extend(std::mem::take(&mut state.client_orders.orders));
+                state.client_orders = saved;
+            }
+            Ok(None) => {}
+            Err(e) => {
+                return serialize_response(&serde_json::json!({
+                    "success": false,
+                    "error": format!("Failed to load client orders: {}", e)
+                }))
+            }
+        }
+    }
+    state.state_dir = state_dir;
+
     state.client = Some(client);
     state.account_no = account_no;
     state.is_paper = is_paper;
+    // A new session starts a new warm-up window
+    state.interlock = interlock;
 
     serialize_response(&serde_json::json!({
         "success": true,
@@ -135,6 +300,7 @@ pub extern "C" fn initialize(ptr: i32, len: i32) -> u64 {
 #[no_mangle]
 pub extern "C" fn get_accounts(ptr: i32, len: i32) -> u64 {
     let _req: GetAccountsRequest = parse_request(ptr, len);
+    let freshness = parse_freshness(ptr, len);
 
     let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
 
@@ -146,26 +312,16 @@ pub extern "C" fn get_accounts(ptr: i32, len: i32) -> u64 {
             });
         }
     };
+    client.set_freshness(freshness);
 
     // Fetch real balance from KIS API
-    let (balance, positions) = match client.domestic_balance() {
+    let (mut balance, positions) = match client.domestic_balance() {
         Ok((items, summary)) => {
             // Convert KIS balance to our format
-            let total_equity: f64 = summary
-                .tot_evlu_amt
-                .as_ref()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.0);
-            let available_cash: f64 = summary
-                .ord_psbl_cash
-                .as_ref()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.0);
-            let deposit: f64 = summary
-                .dnca_tot_amt
-                .as_ref()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.0);
+            log_invalid_fields("balance summary", &summary);
+            let total_equity = summary.tot_evlu_amt.or_zero().to_f64();
+            let available_cash = summary.ord_psbl_cash.or_zero().to_f64();
+            let deposit = summary.dnca_tot_amt.or_zero().to_f64();
 
             let balance = AccountBalance {
                 currency: "KRW".to_string(),
@@ -176,26 +332,7 @@ pub extern "C" fn get_accounts(ptr: i32, len: i32) -> u64 {
             };
 
             // Convert positions
-            let positions: Vec<Position> = items
-                .iter()
-                .filter_map(|item| {
-                    let symbol = item.pdno.as_ref()?.clone();
-                    let quantity: f64 = item.hldg_qty.as_ref()?.parse().ok()?;
-                    let avg_price: f64 = item.pchs_avg_pric.as_ref()?.parse().ok()?;
-                    let current_price: f64 = item.prpr.as_ref()?.parse().ok()?;
-                    let pnl: f64 = item.evlu_pfls_amt.as_ref()?.parse().ok()?;
-                    let pnl_rate: f64 = item.evlu_pfls_rt.as_ref()?.parse().ok()?;
-
-                    Some(Position {
-                        symbol_id: symbol,
-                        quantity,
-                        average_price: avg_price,
-                        current_price,
-                        unrealized_pnl: pnl,
-                        unrealized_pnl_percent: pnl_rate,
-                    })
-                })
-                .collect();
+            let positions: Vec<Position> = items.iter().filter_map(to_position).collect();
 
             (balance, positions)
         }
@@ -215,6 +352,52 @@ pub extern "C" fn get_accounts(ptr: i32, len: i32) -> u64 {
         }
     };
 
+    // Consolidate overseas holdings and foreign-currency deposits in KRW
+    let mut extensions = HashMap::new();
+    match client.overseas_present_balance() {
+        Ok(overseas) => {
+            log_invalid_fields("overseas balance summary", &overseas.summary);
+            let overseas_equity = overseas.summary.evlu_amt_smtl_amt.or_zero().to_f64();
+            let foreign_cash = overseas.summary.frcr_evlu_tot.or_zero().to_f64();
+
+            balance.total_equity += overseas_equity + foreign_cash;
+
+            let currencies: Vec<serde_json::Value> = overseas
+                .currencies
+                .iter()
+                .filter_map(|c| {
+                    let currency = c.crcy_cd.as_ref()?.clone();
+                    log_invalid_fields(&currency, c);
+                    let deposit = c.frcr_dncl_amt_2.or_zero().to_f64();
+                    let exchange_rate = c.frst_bltn_exrt.or_zero().to_f64();
+
+                    Some(serde_json::json!({
+                        "currency": currency,
+                        "deposit": deposit,
+                        "exchange_rate": exchange_rate,
+                        "deposit_krw": deposit * exchange_rate,
+                    }))
+                })
+                .collect();
+
+            extensions.insert(
+                "overseas_equity_krw".to_string(),
+                serde_json::json!(overseas_equity),
+            );
+            extensions.insert(
+                "foreign_cash_krw".to_string(),
+                serde_json::json!(foreign_cash),
+            );
+            extensions.insert(
+                "currencies".to_string(),
+                serde_json::Value::Array(currencies),
+            );
+        }
+        Err(e) => {
+            eprintln!("[broker-korea] Failed to fetch overseas balance: {}", e);
+        }
+    }
+
     let account = AccountSummary {
         id: state.account_no.clone(),
         name: format!(
@@ -226,7 +409,11 @@ pub extern "C" fn get_accounts(ptr: i32, len: i32) -> u64 {
         balance,
         positions,
         updated_at: Utc::now(),
-        extensions: None,
+        extensions: if extensions.is_empty() {
+            None
+        } else {
+            Some(extensions)
+        },
     };
 
     let response = GetAccountsResponse {
@@ -240,6 +427,7 @@ pub extern "C" fn get_accounts(ptr: i32, len: i32) -> u64 {
 #[no_mangle]
 pub extern "C" fn get_positions(ptr: i32, len: i32) -> u64 {
     let req: GetPositionsRequest = parse_request(ptr, len);
+    let freshness = parse_freshness(ptr, len);
 
     let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
 
@@ -257,29 +445,11 @@ pub extern "C" fn get_positions(ptr: i32, len: i32) -> u64 {
             return serialize_response(&GetPositionsResponse { positions: vec![] });
         }
     };
+    client.set_freshness(freshness);
 
     // Fetch positions from KIS API
     let positions = match client.domestic_balance() {
-        Ok((items, _summary)) => items
-            .iter()
-            .filter_map(|item| {
-                let symbol = item.pdno.as_ref()?.clone();
-                let quantity: f64 = item.hldg_qty.as_ref()?.parse().ok()?;
-                let avg_price: f64 = item.pchs_avg_pric.as_ref()?.parse().ok()?;
-                let current_price: f64 = item.prpr.as_ref()?.parse().ok()?;
-                let pnl: f64 = item.evlu_pfls_amt.as_ref()?.parse().ok()?;
-                let pnl_rate: f64 = item.evlu_pfls_rt.as_ref()?.parse().ok()?;
-
-                Some(Position {
-                    symbol_id: symbol,
-                    quantity,
-                    average_price: avg_price,
-                    current_price,
-                    unrealized_pnl: pnl,
-                    unrealized_pnl_percent: pnl_rate,
-                })
-            })
-            .collect(),
+        Ok((items, _summary)) => items.iter().filter_map(to_position).collect(),
         Err(e) => {
             eprintln!("[broker-korea] Failed to fetch positions: {}", e);
             vec![]
@@ -297,134 +467,2339 @@ pub extern "C" fn submit_order(ptr: i32, len: i32) -> u64 {
     let req: SubmitOrderRequest = parse_request(ptr, len);
     let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
 
-    let client = match state.client.as_mut() {
-        Some(c) => c,
+    let order = match order_extension_str(&req, "client_order_id") {
+        Some(client_order_id) => submit_with_client_id(&mut state, &req, client_order_id),
         None => {
-            return serialize_response(&SubmitOrderResponse {
-                order: create_error_order(&req, "Plugin not initialized"),
-            });
+            let (mut order, _) = submit_reconciled(&mut state, &req, None);
+            tag_environment(&mut state, &mut order);
+            order
         }
     };
 
-    // Extract order details
-    let symbol = &req.order.symbol_id;
-    let quantity = req.order.quantity as u32;
-    let side = &req.order.side;
-    let order_type = &req.order.order_type;
-    let limit_price = req.order.limit_price.unwrap_or(0.0) as u32;
+    serialize_response(&SubmitOrderResponse { order })
+}
 
-    // Map order type to KIS order type
-    let kis_order_type = match order_type {
-        OrderType::Market => kis::types::OrderType::Market,
-        OrderType::Limit => kis::types::OrderType::Limit,
-        _ => kis::types::OrderType::Limit, // Default to limit for unsupported types
+/// Submit at most once per `client_order_id`
+///
+/// 같은 id로 다시 요청하면 처음 반환한 주문을 `replayed: true`로 돌려줍니다. 전송 결과를 알 수
+/// 없었던 주문은 당일 주문내역에서 다시 찾고, 없을 때만 재전송합니다.
+fn submit_with_client_id(
+    state: &mut BrokerState,
+    req: &SubmitOrderRequest,
+    client_order_id: &str,
+) -> Order {
+    state.client_orders.expire(kst_now());
+    let pending = match state.client_orders.orders.get(client_order_id) {
+        Some(ClientOrder::Settled { order, .. }) | Some(ClientOrder::Unknown { order, .. })
+            if !same_order(order, req) =>
+        {
+            return create_error_order(
+                req,
+                &format!(
+                    "Order rejected: client_order_id {} was already used for a different order",
+                    client_order_id
+                ),
+            );
+        }
+        Some(ClientOrder::Settled { order, .. }) => {
+            // 이후 취소/체결로 갱신된 주문이 있으면 그 상태를 반환
+            let mut order = state
+                .orders
+                .get(&order.id)
+                .cloned()
+                .unwrap_or_else(|| order.clone());
+            order
+                .extensions
+                .get_or_insert_with(HashMap::new)
+                .insert("replayed".to_string(), serde_json::Value::Bool(true));
+            return order;
+        }
+        Some(ClientOrder::Unknown { sent_at, .. }) => Some(*sent_at),
+        None => None,
     };
 
-    // Submit order to KIS API
-    let result = match side {
-        OrderSide::Buy => client.domestic_buy(symbol, quantity, limit_price, kis_order_type),
-        OrderSide::Sell => client.domestic_sell(symbol, quantity, limit_price, kis_order_type),
+    let (mut order, sent_at) = submit_reconciled(state, req, pending);
+    tag_environment(state, &mut order);
+    order.extensions.get_or_insert_with(HashMap::new).insert(
+        "client_order_id".to_string(),
+        serde_json::Value::String(client_order_id.to_string()),
+    );
+    let entry = if is_ambiguous(&order) {
+        ClientOrder::Unknown {
+            sent_at,
+            order: order.clone(),
+        }
+    } else {
+        ClientOrder::Settled {
+            sent_at,
+            order: order.clone(),
+        }
     };
+    state
+        .client_orders
+        .orders
+        .insert(client_order_id.to_string(), entry);
+    if let Some(dir) = state.state_dir.as_deref() {
+        if let Err(e) = state.client_orders.save(dir) {
+            eprintln!("[broker-korea] Failed to persist client orders: {}", e);
+        }
+    }
+    order
+}
 
-    match result {
-        Ok(kis_response) => {
-            // Generate local order ID combining KIS order number
-            let order_id = kis_response
-                .odno
-                .unwrap_or_else(|| format!("kr_{}", state.next_order_id));
-            state.next_order_id += 1;
-
-            let order = Order {
-                id: order_id.clone(),
-                request: req.order.clone(),
-                status: OrderStatus::Submitted,
-                created_at: Utc::now(),
-                updated_at: Utc::now(),
-                average_filled_price: None,
-                filled_quantity: 0.0,
-                extensions: Some({
-                    let mut map = HashMap::new();
-                    if let Some(time) = kis_response.ord_tmd {
-                        map.insert(
-                            "kis_order_time".to_string(),
-                            serde_json::Value::String(time),
-                        );
-                    }
-                    map
-                }),
-                persona_id: req.order.persona_id.clone(),
-            };
+/// Whether a repeated request describes the same order as the stored one
+fn same_order(stored: &Order, req: &SubmitOrderRequest) -> bool {
+    let key = |order: &models::order::OrderRequest| {
+        serde_json::json!([
+            order.symbol_id,
+            order.side,
+            order.order_type,
+            order.quantity,
+            order.limit_price,
+        ])
+    };
+    key(&stored.request) == key(&req.order)
+}
+
+/// Place an order, reconciling against today's orders when the outcome is unknown
+///
+/// 결과를 알 수 없는 주문은 자동으로 다시 전송하지 않습니다. 같은 `client_order_id`로 재요청되어
+/// (`pending`) 다시 대사해도 주문내역에 없을 때만 재전송합니다.
+///
+/// # Arguments
+/// * `pending` - 이전 요청의 전송 결과를 알 수 없었으면 그 전송 시각
+///
+/// # Returns
+/// * 주문과 대사 기준 전송 시각
+fn submit_reconciled(
+    state: &mut BrokerState,
+    req: &SubmitOrderRequest,
+    pending: Option<chrono::NaiveDateTime>,
+) -> (Order, chrono::NaiveDateTime) {
+    if let Some(sent_at) = pending {
+        match reconcile_order(state, req, sent_at) {
+            Ok(Some(order_no)) => return (adopt_order(state, req, &order_no), sent_at),
+            // 주문내역 반영이 늦을 수 있어 전송 직후의 재요청은 재전송하지 않음
+            Ok(None) if kst_now() - sent_at < chrono::Duration::seconds(RESEND_GRACE_SECS) => {
+                return (unknown_order(req, None), sent_at);
+            }
+            Ok(None) => {
+                eprintln!("[broker-korea] No matching order found on retry, resending");
+            }
+            Err(e) => return (unknown_order(req, Some(e)), sent_at),
+        }
+    }
+
+    let sent_at = kst_now();
+    let order = place_order(state, req);
+    if !is_ambiguous(&order) {
+        return (order, sent_at);
+    }
+    eprintln!("[broker-korea] Order outcome unknown, reconciling against today's orders");
+    let order = match reconcile_order(state, req, sent_at) {
+        Ok(Some(order_no)) => adopt_order(state, req, &order_no),
+        Ok(None) => unknown_order(req, None),
+        Err(e) => unknown_order(req, Some(e)),
+    };
+    (order, sent_at)
+}
+
+/// Record the order KIS already accepted for an ambiguous submission
+fn adopt_order(state: &mut BrokerState, req: &SubmitOrderRequest, order_no: &str) -> Order {
+    eprintln!("[broker-korea] Reconciled order {}", order_no);
+    let mut extensions = HashMap::new();
+    extensions.insert("reconciled".to_string(), serde_json::Value::Bool(true));
+    if let Some(exchange) = order_extension_str(req, "exchange") {
+        extensions.insert(
+            "exchange".to_string(),
+            serde_json::Value::String(exchange.to_string()),
+        );
+    }
+    record_order(state, req, Some(order_no.to_string()), extensions)
+}
+
+/// Pending order flagged `ambiguous` whose acceptance by KIS could not be confirmed
+fn unknown_order(req: &SubmitOrderRequest, reconcile_error: Option<String>) -> Order {
+    let error = match &reconcile_error {
+        Some(e) => format!("Order outcome unknown: reconciliation failed: {}", e),
+        None => "Order outcome unknown: not found in today's orders".to_string(),
+    };
+    eprintln!("[broker-korea] {}", error);
+    let mut order = create_error_order(req, &error);
+    order.status = OrderStatus::Pending;
+    if let Some(extensions) = order.extensions.as_mut() {
+        extensions.insert("ambiguous".to_string(), serde_json::Value::Bool(true));
+        if let Some(e) = reconcile_error {
+            extensions.insert("reconcile_error".to_string(), serde_json::Value::String(e));
+        }
+    }
+    order
+}
 
-            state.orders.insert(order_id, order.clone());
+/// Find the order number KIS assigned to a submission whose outcome is unknown
+///
+/// 같은 종목/방향/수량/가격으로 전송 시각 이후 접수되었고 아직 다른 요청에 대응되지 않은 주문 중
+/// 가장 이른 주문을 찾습니다. 호가단위로 보정한 국내 지정가(`round_to_tick`)와 해외 시장가는
+/// 가격을 비교하지 않으며, 해외 지정가는 실제로 전송한 단가와 비교합니다.
+fn reconcile_order(
+    state: &mut BrokerState,
+    req: &SubmitOrderRequest,
+    sent_at: chrono::NaiveDateTime,
+) -> Result<Option<String>, String> {
+    let exchange = order_extension_str(req, "exchange");
+    let venue = resolve_venue(state, &req.order.symbol_id, exchange)?;
+    let quantity = Quantity::from_int(order_quantity(req)?.into());
+    let price = order_price(req)?;
+    let side = match req.order.side {
+        OrderSide::Buy => kis::types::OrderSide::Buy,
+        OrderSide::Sell => kis::types::OrderSide::Sell,
+    };
 
-            let response = SubmitOrderResponse { order };
-            serialize_response(&response)
+    // 해외 지정가는 전송한 단가(`round_to_tick`이면 반올림한 값)와 비교
+    let limit_price = match venue {
+        Venue::Domestic(_) if order_extension_bool(req, "round_to_tick") => None,
+        Venue::Domestic(_) => Some(price),
+        Venue::Overseas(exchange) => {
+            let round = order_extension_bool(req, "round_to_tick");
+            let wire = map_overseas_order_type(req)?
+                .wire_price(exchange, price, round)
+                .map_err(|e| e.to_string())?;
+            Some(wire).filter(|p| !p.is_zero())
         }
-        Err(e) => {
-            eprintln!("[broker-korea] Order failed: {}", e);
-            serialize_response(&SubmitOrderResponse {
-                order: create_error_order(&req, &format!("Order failed: {}", e)),
+    };
+
+    // 주문내역은 캐시 없이 조회 (조회가 실패해도 신선도 힌트는 복원)
+    let client = state.client.as_mut().ok_or("Plugin not initialized")?;
+    let candidates = client
+        .with_freshness(Freshness::Refresh, |client| {
+            order_candidates(client, venue, sent_at)
+        })
+        .map_err(|e| e.to_string())?;
+
+    let intent = OrderIntent {
+        symbol: &req.order.symbol_id,
+        side,
+        quantity,
+        limit_price,
+        sent_at,
+    };
+    let known: HashSet<String> = state
+        .orders
+        .keys()
+        .map(|id| normalize_order_no(id))
+        .collect();
+    Ok(find_submitted(&intent, &candidates, &known).map(|c| c.order_no.clone()))
+}
+
+/// Today's orders on the venue as reconciliation candidates (모든 페이지)
+fn order_candidates(
+    client: &mut KisClient,
+    venue: Venue,
+    sent_at: chrono::NaiveDateTime,
+) -> Result<Vec<OrderCandidate>, KisError> {
+    match venue {
+        Venue::Domestic(_) => Ok(client
+            .domestic_orders_on(sent_at.date())?
+            .into_iter()
+            .filter_map(|item| {
+                let date = item.ord_dt.get().unwrap_or(sent_at.date());
+                Some(OrderCandidate {
+                    side: item.side()?,
+                    order_no: item.odno?,
+                    symbol: item.pdno?,
+                    quantity: item.ord_qty.get()?,
+                    price: item.ord_unpr.get(),
+                    time: parse_order_time(date, item.ord_tmd.as_deref()),
+                })
             })
+            .collect()),
+        Venue::Overseas(exchange) => {
+            // 주문일자는 KST 일자와 거래소 현지 거래일이 다를 수 있어 둘 다 조회
+            let mut dates = vec![sent_at.date(), exchange.trade_date_at(sent_at)];
+            dates.dedup();
+            let mut candidates = Vec::new();
+            for date in dates {
+                let items = client.overseas_orders_on(&date.format("%Y%m%d").to_string())?;
+                candidates.extend(items.into_iter().filter_map(|item| {
+                    // 국내주문일자/당사주문시각(KST)이 없으면 조회일자와 주문시각으로 대신함
+                    let time = match (item.dmst_ord_dt.get(), item.thco_ord_tmd.as_deref()) {
+                        (Some(kst_date), Some(kst_time)) => {
+                            parse_order_time(kst_date, Some(kst_time))
+                        }
+                        _ => parse_order_time(date, item.ord_tmd.as_deref()),
+                    };
+                    Some(OrderCandidate {
+                        side: item.side()?,
+                        order_no: item.odno?,
+                        symbol: item.pdno?,
+                        quantity: item.ft_ord_qty.get()?,
+                        price: item.ft_ord_unpr3.get(),
+                        time,
+                    })
+                }));
+            }
+            Ok(candidates)
         }
     }
 }
 
-// --- Helper Functions ---
+/// Whether a failed order may still have reached KIS
+fn is_ambiguous(order: &Order) -> bool {
+    order
+        .extensions
+        .as_ref()
+        .and_then(|ext| ext.get("ambiguous"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
 
-fn parse_request<T: serde::de::DeserializeOwned>(ptr: i32, len: i32) -> T {
-    let slice = unsafe { slice::from_raw_parts(ptr as *const u8, len as usize) };
-    serde_json::from_slice(slice).expect("Failed to parse request")
+/// Run the pre-trade gates and route the order to the domestic or overseas endpoints
+fn place_order(state: &mut BrokerState, req: &SubmitOrderRequest) -> Order {
+    match state.client.as_ref() {
+        // 주문 전 점검(주문가능금액, 상하한가)은 기본 TTL 내 캐시만 사용
+        Some(client) => client.set_freshness(Freshness::Default),
+        None => return create_error_order(req, "Plugin not initialized"),
+    }
+
+    if state.kill_switch.engaged {
+        let mut order = create_error_order(req, "Order rejected: kill switch is engaged");
+        if let Some(extensions) = order.extensions.as_mut() {
+            extensions.insert("kill_switch".to_string(), serde_json::Value::Bool(true));
+        }
+        return order;
+    }
+
+    // Overseas exchange codes (e.g. "NASD") go to the overseas endpoints; KRX/NXT/SOR select
+    // the domestic venue. Without an `exchange` extension the loaded symbol masters decide.
+    let exchange = order_extension_str(req, "exchange");
+    let venue = match resolve_venue(state, &req.order.symbol_id, exchange) {
+        Ok(venue) => venue,
+        Err(msg) => return create_error_order(req, &format!("Order rejected: {}", msg)),
+    };
+
+    let checked = check_risk(state, req, venue)
+        .and_then(|()| check_daily_loss(state, req))
+        .and_then(|()| check_interlock(state, req, venue));
+    let live_notional = match checked {
+        Ok(notional) => notional,
+        Err(violation) => {
+            eprintln!("[broker-korea] Order rejected: {}", violation);
+            return create_risk_rejection(req, &violation);
+        }
+    };
+
+    let order = match venue {
+        Venue::Domestic(venue) => submit_domestic_order(state, req, venue),
+        Venue::Overseas(exchange) => match resolve_us_session(req, exchange, state.is_paper) {
+            Ok(exchange) => submit_overseas_order(state, req, exchange),
+            Err(msg) => create_error_order(req, &format!("Order rejected: {}", msg)),
+        },
+    };
+    // 결과를 알 수 없는 주문도 접수된 것으로 보고 한도에 반영
+    if !matches!(order.status, OrderStatus::Rejected) || is_ambiguous(&order) {
+        state.risk.record_order(kst_now().date());
+        if let (Some(interlock), Some((currency, notional))) =
+            (state.interlock.as_mut(), live_notional)
+        {
+            interlock.record(currency, notional);
+        }
+    }
+
+    order
 }
 
-fn serialize_response<T: serde::Serialize>(response: &T) -> u64 {
-    let res_bytes = serde_json::to_vec(response).expect("Failed to serialize response");
+/// Request for `load_master`
+#[derive(Debug, serde::Deserialize)]
+struct LoadMasterRequest {
+    /// "kospi", "kosdaq", "konex", "elw", "fo_idx", "fo_stk", "nasmst", "hksmst" 등 (또는 파일명)
+    file: String,
+    /// 마스터파일 원본 바이트 (base64)
+    data: String,
+}
 
-    let out_len = res_bytes.len() as i32;
-    let out_ptr = alloc(out_len);
+#[derive(Debug, Default, serde::Serialize)]
+struct LoadMasterResponse {
+    success: bool,
+    loaded: usize,
+    skipped: usize,
+    total: usize,
+    error: Option<String>,
+}
 
-    unsafe {
-        std::ptr::copy_nonoverlapping(res_bytes.as_ptr(), out_ptr as *mut u8, out_len as usize);
+/// Load a KIS domestic or overseas symbol master file (종목정보파일) supplied by the host
+#[no_mangle]
+pub extern "C" fn load_master(ptr: i32, len: i32) -> u64 {
+    use base64::Engine;
+
+    let req: LoadMasterRequest = parse_request(ptr, len);
+    let failed = |error: String| LoadMasterResponse {
+        error: Some(error),
+        ..Default::default()
+    };
+
+    let bytes = match base64::engine::general_purpose::STANDARD.decode(req.data.trim()) {
+        Ok(bytes) => bytes,
+        Err(e) => return serialize_response(&failed(format!("Invalid base64 data: {}", e))),
+    };
+
+    let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    let (loaded, skipped, total) =
+        match (MasterFile::from_code(&req.file), master_exchange(&req.file)) {
+            (Some(file), _) if file.is_derivatives() => {
+                let parsed = state.derivatives.load(file, &bytes);
+                let (loaded, skipped) = (parsed.instruments.len(), parsed.skipped);
+                (loaded, skipped, state.derivatives.len())
+            }
+            (Some(file), _) => {
+                let parsed = state.instruments.load(file, &bytes);
+                let (loaded, skipped) = (parsed.instruments.len(), parsed.skipped);
+                (loaded, skipped, state.instruments.len())
+            }
+            (None, Some(exchange)) => {
+                let parsed = state.overseas_symbols.load(exchange, &bytes);
+                let (loaded, skipped) = (parsed.instruments.len(), parsed.skipped);
+                (loaded, skipped, state.overseas_symbols.len())
+            }
+            (None, None) => {
+                return serialize_response(&failed(format!("Unknown master file: {}", req.file)))
+            }
+        };
+    for (line, reason) in skipped.iter().take(5) {
+        eprintln!(
+            "[broker-korea] {} line {} skipped: {}",
+            req.file, line, reason
+        );
     }
 
-    ((out_ptr as u64) << 32) | (out_len as u64)
+    serialize_response(&LoadMasterResponse {
+        success: true,
+        loaded,
+        skipped: skipped.len(),
+        total,
+        error: None,
+    })
 }
 
-fn create_error_account(error: &str) -> AccountSummary {
-    AccountSummary {
-        id: "error".to_string(),
-        name: format!("Error: {}", error),
-        broker_id: "broker-korea".to_string(),
-        is_paper: true,
-        balance: AccountBalance {
-            currency: "KRW".to_string(),
-            total_equity: 0.0,
-            available_cash: 0.0,
-            buying_power: 0.0,
-            locked_cash: 0.0,
+#[derive(Debug, serde::Deserialize)]
+struct SearchInstrumentsRequest {
+    query: String,
+    #[serde(default)]
+    limit: Option<usize>,
+    /// "all" (기본), "domestic", "overseas"
+    #[serde(default)]
+    scope: Option<String>,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+struct SearchInstrumentsResponse {
+    instruments: Vec<InstrumentHit>,
+    error: Option<String>,
+}
+
+/// Search instruments by code, ISIN, Korean/romanized name or English name
+///
+/// 종목정보파일 테이블을 우선 검색하고, 테이블에 없는 종목코드/티커는 실전투자에서
+/// 주식기본조회/상품기본조회 API로 확인합니다.
+#[no_mangle]
+pub extern "C" fn search_instruments(ptr: i32, len: i32) -> u64 {
+    let req: SearchInstrumentsRequest = parse_request(ptr, len);
+    let freshness = parse_freshness(ptr, len);
+
+    let scope = match req.scope.as_deref().map(SearchScope::from_code) {
+        None => SearchScope::All,
+        Some(Some(scope)) => scope,
+        Some(None) => {
+            return serialize_response(&SearchInstrumentsResponse {
+                instruments: vec![],
+                error: Some(format!(
+                    "Unknown search scope: {}",
+                    req.scope.unwrap_or_default()
+                )),
+            })
+        }
+    };
+    let limit = req.limit.unwrap_or(DEFAULT_SEARCH_LIMIT).clamp(1, 100);
+    let query = req.query.trim();
+
+    let mut guard = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    let state = &mut *guard;
+    let mut hits = search_masters(
+        &state.instruments,
+        &state.overseas_symbols,
+        query,
+        scope,
+        limit,
+    );
+
+    // Exact codes the masters don't cover (or masters not loaded) go to the KIS lookup APIs
+    let has_exact = hits
+        .iter()
+        .any(|hit| hit.symbol.eq_ignore_ascii_case(query));
+    if let Some(client) = state
+        .client
+        .as_mut()
+        .filter(|_| !has_exact && !state.is_paper)
+    {
+        client.set_freshness(freshness);
+        if scope.includes_domestic() && is_domestic_code(query) {
+            match client.domestic_search_stock_info(query) {
+                Ok(Some(info)) => hits.push(InstrumentHit::from_stock_info(query, &info)),
+                Ok(None) => {}
+                Err(e) => eprintln!("[broker-korea] Stock info lookup failed: {}", e),
+            }
+        }
+        // 해외는 미국 거래소만 조회 (티커가 거래소 간 중복되지 않음)
+        let has_letter = query.bytes().any(|b| b.is_ascii_alphabetic());
+        if scope.includes_overseas() && is_overseas_ticker(query) && has_letter {
+            for exchange in [Exchange::NASDAQ, Exchange::NYSE, Exchange::AMEX] {
+                match client.search_info(query, exchange.product_type_code()) {
+                    Ok(Some(info)) => {
+                        hits.push(InstrumentHit::from_product_info(query, exchange, &info));
+                        break;
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        eprintln!("[broker-korea] Product info lookup failed: {}", e);
+                        break;
+                    }
+                }
+            }
+        }
+        sort_hits(&mut hits);
+        hits.truncate(limit);
+    }
+
+    serialize_response(&SearchInstrumentsResponse {
+        instruments: hits,
+        error: None,
+    })
+}
+
+/// Symbol entry for `get_quotes`
+#[derive(Debug, serde::Deserialize)]
+struct QuoteSymbol {
+    symbol_id: String,
+    /// KRX/NXT/SOR (국내, 기본 KRX) 또는 해외 거래소코드 (NASD, NYSE, SEHK 등)
+    #[serde(default)]
+    exchange: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct GetQuotesRequest {
+    symbols: Vec<QuoteSymbol>,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+struct Quote {
+    symbol_id: String,
+    exchange: String,
+    name: Option<String>,
+    price: f64,
+    change: f64,
+    change_percent: f64,
+    open: f64,
+    high: f64,
+    low: f64,
+    volume: f64,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct QuoteError {
+    symbol_id: String,
+    error: String,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+struct GetQuotesResponse {
+    quotes: Vec<Quote>,
+    errors: Vec<QuoteError>,
+}
+
+/// Get quotes for mixed domestic/overseas symbols
+///
+/// 국내 종목은 관심종목 복수시세로 30종목씩 일괄 조회하고, 해외 종목은 거래소별로 조회합니다.
+#[no_mangle]
+pub extern "C" fn get_quotes(ptr: i32, len: i32) -> u64 {
+    let req: GetQuotesRequest = parse_request(ptr, len);
+    let freshness = parse_freshness(ptr, len);
+    let mut guard = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    let state = &mut *guard;
+
+    let mut response = GetQuotesResponse::default();
+    let mut domestic: Vec<(&str, DomesticExchange)> = Vec::new();
+    let mut overseas: Vec<(Exchange, &str)> = Vec::new();
+
+    for entry in &req.symbols {
+        match resolve_venue(state, &entry.symbol_id, entry.exchange.as_deref()) {
+            Ok(Venue::Domestic(venue)) => domestic.push((entry.symbol_id.as_str(), venue)),
+            Ok(Venue::Overseas(exchange)) => overseas.push((exchange, entry.symbol_id.as_str())),
+            Err(error) => response.errors.push(QuoteError {
+                symbol_id: entry.symbol_id.clone(),
+                error,
+            }),
+        }
+    }
+
+    let client = match state.client.as_mut() {
+        Some(c) => c,
+        None => return error_response::<GetQuotesResponse>("Plugin not initialized"),
+    };
+    client.set_freshness(freshness);
+
+    if !domestic.is_empty() {
+        match client.domestic_multi_price(&domestic) {
+            Ok(items) => {
+                let venues: HashMap<&str, DomesticExchange> = domestic.iter().copied().collect();
+                for item in items {
+                    let symbol = match item.inter_shrn_iscd.clone() {
+                        Some(symbol) => symbol,
+                        None => continue,
+                    };
+                    log_invalid_fields(&symbol, &item);
+                    let venue = venues
+                        .get(symbol.as_str())
+                        .copied()
+                        .unwrap_or(DomesticExchange::Krx);
+                    response.quotes.push(Quote {
+                        exchange: venue.code().to_string(),
+                        // 모의투자 대체 조회는 종목명이 없으므로 종목 테이블에서 보완
+                        name: item
+                            .inter_kor_isnm
+                            .clone()
+                            .or_else(|| state.instruments.get(&symbol).map(|i| i.name.clone())),
+                        price: item.inter2_prpr.or_zero().to_f64(),
+                        change: item.inter2_prdy_vrss.or_zero().to_f64(),
+                        change_percent: item.prdy_ctrt.or_zero().to_f64(),
+                        open: item.inter2_oprc.or_zero().to_f64(),
+                        high: item.inter2_hgpr.or_zero().to_f64(),
+                        low: item.inter2_lwpr.or_zero().to_f64(),
+                        volume: item.acml_vol.or_zero().to_f64(),
+                        symbol_id: symbol,
+                    });
+                }
+            }
+            Err(e) => {
+                eprintln!("[broker-korea] Failed to fetch domestic quotes: {}", e);
+                response
+                    .errors
+                    .extend(domestic.iter().map(|(symbol, _)| QuoteError {
+                        symbol_id: symbol.to_string(),
+                        error: e.to_string(),
+                    }));
+            }
+        }
+    }
+
+    // 해외주식은 복수시세 API가 없어 거래소별로 묶어 순차 조회
+    overseas.sort_by_key(|(exchange, _)| exchange.code());
+    for (exchange, symbol) in overseas {
+        match client.overseas_price(exchange, symbol) {
+            Ok(quote) => {
+                log_invalid_fields(symbol, &quote);
+                response.quotes.push(Quote {
+                    symbol_id: symbol.to_string(),
+                    exchange: exchange.code().to_string(),
+                    name: state
+                        .overseas_symbols
+                        .get(symbol, exchange)
+                        .map(|i| i.name_kr.clone()),
+                    price: quote.last.or_zero().to_f64(),
+                    change: quote.diff.or_zero().to_f64(),
+                    change_percent: quote.rate.or_zero().to_f64(),
+                    open: quote.open.or_zero().to_f64(),
+                    high: quote.high.or_zero().to_f64(),
+                    low: quote.low.or_zero().to_f64(),
+                    volume: quote.tvol.or_zero().to_f64(),
+                });
+            }
+            Err(e) => response.errors.push(QuoteError {
+                symbol_id: symbol.to_string(),
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    serialize_response(&response)
+}
+
+/// Request for `kill_switch`
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(default)]
+struct KillSwitchRequest {
+    /// "engage", "rearm", "status" (기본값)
+    action: String,
+    /// 발동 사유
+    reason: Option<String>,
+    /// 발동 시 선물옵션 미결제 포지션을 시장가로 청산
+    flatten_futures: bool,
+    /// 재무장 확인 문자열 ("REARM")
+    confirm: String,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+struct KillSwitchResponse {
+    engaged: bool,
+    engaged_at: Option<String>,
+    reason: Option<String>,
+    /// 상태가 `state_dir`에 저장되었는지 여부
+    persisted: bool,
+    /// 상품군별 일괄 취소/청산 결과 (engage 시)
+    sweeps: Vec<SweepReport>,
+    error: Option<String>,
+}
+
+/// Engage, re-arm or inspect the account-wide kill switch
+///
+/// engage는 신규 주문을 차단한 뒤 모든 상품군의 미체결 주문을 취소하며, 이미 발동 중이어도
+/// 취소를 다시 시도합니다. 재무장(rearm)은 `confirm: "REARM"`이 있어야 합니다.
+#[no_mangle]
+pub extern "C" fn kill_switch(ptr: i32, len: i32) -> u64 {
+    let slice = unsafe { slice::from_raw_parts(ptr as *const u8, len.max(0) as usize) };
+    let req: KillSwitchRequest = match serde_json::from_slice(slice) {
+        Ok(req) => req,
+        Err(_) if slice.iter().all(u8::is_ascii_whitespace) => KillSwitchRequest::default(),
+        Err(e) => {
+            return serialize_response(&KillSwitchResponse {
+                error: Some(format!("Invalid request: {}", e)),
+                ..Default::default()
+            })
+        }
+    };
+
+    let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    let state = &mut *state;
+    let mut error = None;
+    let mut sweeps = Vec::new();
+
+    let changed = match req.action.as_str() {
+        "" | "status" => false,
+        "engage" => {
+            let now = kst_now();
+            let newly = state.kill_switch.engage(now, req.reason.clone());
+            if newly {
+                eprintln!("[broker-korea] Kill switch engaged: {:?}", req.reason);
+            }
+            match state.client.as_mut() {
+                Some(client) => sweeps = client.kill_switch_sweep(now.date(), req.flatten_futures),
+                None => {
+                    error = Some("Plugin not initialized; no orders were cancelled".to_string())
+                }
+            }
+            newly
+        }
+        "rearm" => match state.kill_switch.rearm(&req.confirm) {
+            Ok(()) => {
+                eprintln!("[broker-korea] Kill switch re-armed");
+                true
+            }
+            Err(e) => {
+                error = Some(e.to_string());
+                false
+            }
         },
-        positions: vec![],
-        updated_at: Utc::now(),
-        extensions: None,
+        other => {
+            error = Some(format!("Unknown action: {}", other));
+            false
+        }
+    };
+
+    let persisted = match state.state_dir.as_deref() {
+        Some(dir) if changed => match state.kill_switch.save(dir) {
+            Ok(()) => true,
+            Err(e) => {
+                error.get_or_insert_with(|| e.to_string());
+                false
+            }
+        },
+        Some(_) => true,
+        None => false,
+    };
+
+    serialize_response(&KillSwitchResponse {
+        engaged: state.kill_switch.engaged,
+        engaged_at: state.kill_switch.engaged_at.clone(),
+        reason: state.kill_switch.reason.clone(),
+        persisted,
+        sweeps,
+        error,
+    })
+}
+
+/// Account P&L for `get_daily_pnl` (KRW, see `DailyPnl`)
+#[derive(Debug, serde::Serialize)]
+struct AccountPnl {
+    date: String,
+    realized: f64,
+    unrealized: f64,
+    overseas: f64,
+    total: f64,
+    peak: f64,
+    drawdown: f64,
+}
+
+impl From<&DailyPnl> for AccountPnl {
+    fn from(pnl: &DailyPnl) -> Self {
+        Self {
+            date: pnl.date.clone(),
+            realized: pnl.realized.to_f64(),
+            unrealized: pnl.unrealized.to_f64(),
+            overseas: pnl.overseas.to_f64(),
+            total: pnl.total.to_f64(),
+            peak: pnl.peak.to_f64(),
+            drawdown: pnl.drawdown.to_f64(),
+        }
     }
 }
 
-fn create_error_order(req: &SubmitOrderRequest, error: &str) -> Order {
-    Order {
-        id: format!("error_{}", Utc::now().timestamp_millis()),
-        request: req.order.clone(),
-        status: OrderStatus::Rejected,
-        created_at: Utc::now(),
-        updated_at: Utc::now(),
-        average_filled_price: None,
-        filled_quantity: 0.0,
-        extensions: Some({
-            let mut map = HashMap::new();
-            map.insert(
-                "error".to_string(),
-                serde_json::Value::String(error.to_string()),
-            );
-            map
-        }),
-        persona_id: req.order.persona_id.clone(),
+#[derive(Debug, Default, serde::Serialize)]
+struct GetDailyPnlResponse {
+    date: String,
+    /// 계좌 당일 손익 (KRW, 해외주식 실현/평가손익 포함)
+    account: Option<AccountPnl>,
+    /// 페르소나별 당일 국내 체결 평가손익 (현재가를 확보하지 못하면 null)
+    personas: HashMap<String, Option<f64>>,
+    /// 계좌 손실/하락폭 한도 도달로 매수 주문(국내/해외)이 차단되었는지 여부
+    halted: bool,
+    halt_reason: Option<String>,
+    /// 손실 한도에 도달한 페르소나와 사유
+    halted_personas: HashMap<String, String>,
+    error: Option<String>,
+}
+
+/// Get today's account and persona P&L with the loss-limit halt status
+#[no_mangle]
+pub extern "C" fn get_daily_pnl(ptr: i32, len: i32) -> u64 {
+    let freshness = parse_freshness(ptr, len);
+    let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    match state.client.as_ref() {
+        Some(client) => client.set_freshness(freshness),
+        None => return error_response::<GetDailyPnlResponse>("Plugin not initialized"),
+    }
+
+    let today = kst_now().date();
+    let mut response = GetDailyPnlResponse {
+        date: today.format("%Y-%m-%d").to_string(),
+        ..Default::default()
+    };
+    match daily_pnl(&mut state, today) {
+        Ok((account, personas)) => {
+            response.account = Some(AccountPnl::from(&account));
+            response.personas = personas
+                .into_iter()
+                .map(|(persona, pnl)| (persona, pnl.map(Amount::to_f64)))
+                .collect();
+        }
+        Err(e) => response.error = Some(e),
+    }
+
+    if let Some(violation) = state.risk.account_halt(today) {
+        response.halted = true;
+        response.halt_reason = Some(violation.to_string());
+    }
+    response.halted_personas = state
+        .risk
+        .persona_halts(today)
+        .map(|(persona, violation)| (persona.to_string(), violation.to_string()))
+        .collect();
+
+    serialize_response(&response)
+}
+
+/// Request for `get_market_status` (date defaults to today in KST)
+#[derive(Debug, Default, serde::Deserialize)]
+struct GetMarketStatusRequest {
+    /// YYYY-MM-DD 또는 YYYYMMDD (해외 거래소는 현지 거래일)
+    date: Option<String>,
+    /// 해외 거래소 코드 (NASD, SEHK 등). 생략하거나 KRX/NXT/SOR이면 국내 시장
+    exchange: Option<String>,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+struct GetMarketStatusResponse {
+    exchange: String,
+    date: String,
+    kst_time: String,
+    is_trading_day: bool,
+    /// "kis" (휴장일조회), "fallback" (주말/고정 공휴일 규칙), "builtin" (해외 내장 휴장일)
+    calendar_source: String,
+    phase: String,
+    krx_session: Option<String>,
+    nxt_session: Option<String>,
+    /// 해외 거래소 세션 개장/마감 시각 (KST)
+    session_open: Option<String>,
+    session_close: Option<String>,
+    next_trading_day: Option<String>,
+    error: Option<String>,
+}
+
+/// Get the market status: trading day, current session and next trading day
+///
+/// 국내는 다른 날짜를 지정하면 해당 일자의 개장 여부만 의미가 있으며, 세션은 현재 KST 시각
+/// 기준으로 계산합니다. 초기화 전에는 휴장일조회 없이 대체 규칙(`calendar_source: "fallback"`)으로
+/// 판단합니다. 해외 거래소는 서머타임과 내장 휴장일을 반영한 KST 개장/마감 시각을
+/// 함께 반환합니다.
+#[no_mangle]
+pub extern "C" fn get_market_status(ptr: i32, len: i32) -> u64 {
+    // The request body is optional; an empty or `{}` body means today
+    let slice = unsafe { slice::from_raw_parts(ptr as *const u8, len.max(0) as usize) };
+    let req: GetMarketStatusRequest = serde_json::from_slice(slice).unwrap_or_default();
+    let invalid = |error: String| {
+        serialize_response(&GetMarketStatusResponse {
+            error: Some(error),
+            ..Default::default()
+        })
+    };
+
+    let now = kst_now();
+    let date = match req.date.as_deref() {
+        None => None,
+        Some(raw) => match kis::field::KisField::<chrono::NaiveDate>::parse(raw).get() {
+            Some(date) => Some(date),
+            None => return invalid(format!("Invalid date: {}", raw)),
+        },
+    };
+
+    if let Some(code) = req
+        .exchange
+        .as_deref()
+        .filter(|code| DomesticExchange::from_code(code).is_none())
+    {
+        return match Exchange::from_code(code) {
+            Some(exchange) => serialize_response(&overseas_market_status(exchange, now, date)),
+            None => invalid(format!("Unknown exchange: {}", code)),
+        };
+    }
+
+    // Before initialize there is no holiday inquiry; the local rules still give the sessions
+    let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    let unchecked;
+    let calendar = match state.client.as_mut() {
+        Some(client) => client.trading_calendar(now),
+        None => {
+            unchecked = TradingCalendar::new();
+            &unchecked
+        }
+    };
+
+    let kst = date.map_or(now, |d| d.and_time(now.time()));
+    let status = calendar.status_at(kst);
+    serialize_response(&GetMarketStatusResponse {
+        exchange: "KRX".to_string(),
+        date: kst.format("%Y-%m-%d").to_string(),
+        kst_time: kst.format("%H:%M:%S").to_string(),
+        is_trading_day: status.is_open_day,
+        calendar_source: status.source.code().to_string(),
+        phase: status.phase.code().to_string(),
+        krx_session: status.krx_session.map(|s| s.code().to_string()),
+        nxt_session: status.nxt_session.map(|s| s.code().to_string()),
+        next_trading_day: status
+            .next_open_day
+            .map(|d| d.format("%Y-%m-%d").to_string()),
+        ..Default::default()
+    })
+}
+
+/// Status of an overseas exchange for a local trade date (default: the one covering `now`)
+fn overseas_market_status(
+    exchange: Exchange,
+    now: chrono::NaiveDateTime,
+    date: Option<chrono::NaiveDate>,
+) -> GetMarketStatusResponse {
+    let trade_date = date.unwrap_or_else(|| exchange.trade_date_at(now));
+    let session = exchange.session_on(trade_date);
+    let phase = match session {
+        None if exchange.is_holiday(trade_date) => "holiday",
+        Some(s) if s.is_break_at(now) => "lunch_break",
+        Some(s) if s.contains(now) => "regular",
+        Some(s) if now < s.open => "pre_open",
+        _ => "closed",
+    };
+    let next = exchange.next_session_after(session.map_or(now, |s| s.open.max(now)));
+
+    GetMarketStatusResponse {
+        exchange: exchange.code().to_string(),
+        date: trade_date.format("%Y-%m-%d").to_string(),
+        kst_time: now.format("%H:%M:%S").to_string(),
+        is_trading_day: session.is_some(),
+        calendar_source: "builtin".to_string(),
+        phase: phase.to_string(),
+        session_open: session.map(|s| format_kst(s.open)),
+        session_close: session.map(|s| format_kst(s.close)),
+        next_trading_day: next.map(|s| s.trade_date.format("%Y-%m-%d").to_string()),
+        ..Default::default()
+    }
+}
+
+/// Request for `get_reservations` (기간 생략 시 오늘 기준 앞뒤 30일)
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(default)]
+struct GetReservationsRequest {
+    /// 조회시작일자 (YYYYMMDD)
+    start_date: Option<String>,
+    /// 조회종료일자 (YYYYMMDD)
+    end_date: Option<String>,
+    /// 취소된 예약도 포함
+    include_cancelled: bool,
+}
+
+/// Domestic reservation order (주식예약주문) as returned by `get_reservations`
+#[derive(Debug, Default, serde::Serialize)]
+struct Reservation {
+    /// `submit_order`가 반환한 `Order.id`와 같은 `rsvn_<예약주문순번>`
+    id: String,
+    symbol_id: String,
+    name: Option<String>,
+    /// "buy" 또는 "sell"
+    side: String,
+    /// 주문구분 (ORD_DVSN)
+    order_type: Option<String>,
+    quantity: Option<f64>,
+    price: Option<f64>,
+    filled_quantity: Option<f64>,
+    /// 예약주문일자 (YYYY-MM-DD)
+    order_date: Option<String>,
+    /// 기간예약 종료일자 (YYYY-MM-DD)
+    end_date: Option<String>,
+    /// 전송된 주문의 KIS 주문번호
+    kis_order_no: Option<String>,
+    /// 처리결과
+    status: Option<String>,
+    reject_reason: Option<String>,
+    cancelled: bool,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+struct GetReservationsResponse {
+    reservations: Vec<Reservation>,
+    error: Option<String>,
+}
+
+/// List domestic reservation orders (주식예약주문조회, 실전 전용)
+///
+/// 장 마감 후 접수한 예약주문을 확인하는 용도로, 기본적으로 취소되지 않은 예약만 반환합니다.
+#[no_mangle]
+pub extern "C" fn get_reservations(ptr: i32, len: i32) -> u64 {
+    // The request body is optional; an empty or `{}` body uses the default window
+    let slice = unsafe { slice::from_raw_parts(ptr as *const u8, len.max(0) as usize) };
+    let req: GetReservationsRequest = serde_json::from_slice(slice).unwrap_or_default();
+
+    let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    let client = match state.client.as_mut() {
+        Some(c) => c,
+        None => return error_response::<GetReservationsResponse>("Plugin not initialized"),
+    };
+
+    let (default_start, default_end) = reservation_window(kst_now().date());
+    let start = req.start_date.unwrap_or(default_start);
+    let end = req.end_date.unwrap_or(default_end);
+    let items = match client.domestic_reservation_list(&start, &end) {
+        Ok(items) => items,
+        Err(e) => {
+            return serialize_response(&GetReservationsResponse {
+                reservations: vec![],
+                error: Some(e.to_string()),
+            })
+        }
+    };
+
+    let format_date =
+        |date: Option<chrono::NaiveDate>| date.map(|d| d.format("%Y-%m-%d").to_string());
+    let reservations = items
+        .iter()
+        .filter(|item| req.include_cancelled || !item.is_cancelled())
+        .filter_map(|item| {
+            let id = item.reservation_id()?;
+            log_invalid_fields(&id, item);
+            Some(Reservation {
+                id,
+                symbol_id: item.pdno.clone().unwrap_or_default(),
+                name: item.kor_item_shtn_name.clone(),
+                side: match item.sll_buy_dvsn_cd.as_deref() {
+                    Some("01") => "sell".to_string(),
+                    _ => "buy".to_string(),
+                },
+                order_type: item.ord_dvsn_cd.clone(),
+                quantity: item.ord_rsvn_qty.get().map(|q| q.to_f64()),
+                price: item.ord_rsvn_unpr.get().map(|p| p.to_f64()),
+                filled_quantity: item.tot_ccld_qty.get().map(|q| q.to_f64()),
+                order_date: format_date(item.rsvn_ord_ord_dt.get()),
+                end_date: format_date(item.rsvn_end_dt.get()),
+                kis_order_no: item.odno.clone().filter(|no| !no.trim().is_empty()),
+                status: item.prcs_rslt.clone(),
+                reject_reason: item.rjct_rson2.clone().filter(|r| !r.trim().is_empty()),
+                cancelled: item.is_cancelled(),
+            })
+        })
+        .collect();
+
+    serialize_response(&GetReservationsResponse {
+        reservations,
+        error: None,
+    })
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CancelReservationRequest {
+    /// `rsvn_<예약주문순번>` 또는 예약주문순번
+    reservation_id: String,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+struct CancelReservationResponse {
+    success: bool,
+    reservation_id: String,
+    error: Option<String>,
+}
+
+/// Cancel a domestic reservation order before it is sent at the next open (실전 전용)
+#[no_mangle]
+pub extern "C" fn cancel_reservation(ptr: i32, len: i32) -> u64 {
+    let req: CancelReservationRequest = parse_request(ptr, len);
+    let reservation_id = req.reservation_id.trim();
+    let seq = reservation_id
+        .strip_prefix("rsvn_")
+        .unwrap_or(reservation_id);
+    let failed = |error: String| {
+        serialize_response(&CancelReservationResponse {
+            success: false,
+            reservation_id: reservation_id.to_string(),
+            error: Some(error),
+        })
+    };
+    if seq.is_empty() || !seq.bytes().all(|b| b.is_ascii_digit()) {
+        return failed(format!("Invalid reservation id: {}", req.reservation_id));
+    }
+
+    let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    let client = match state.client.as_mut() {
+        Some(c) => c,
+        None => return failed("Plugin not initialized".to_string()),
+    };
+
+    match client.domestic_cancel_reservation(seq) {
+        Ok(_) => serialize_response(&CancelReservationResponse {
+            success: true,
+            reservation_id: format!("rsvn_{}", seq),
+            error: None,
+        }),
+        Err(e) => {
+            eprintln!("[broker-korea] Reservation cancel failed: {}", e);
+            failed(e.to_string())
+        }
+    }
+}
+
+/// Submit a domestic stock cash order
+fn submit_domestic_order(
+    state: &mut BrokerState,
+    req: &SubmitOrderRequest,
+    exchange: DomesticExchange,
+) -> Order {
+    let client = match state.client.as_mut() {
+        Some(c) => c,
+        None => return create_error_order(req, "Plugin not initialized"),
+    };
+
+    // Extract order details
+    let symbol = &req.order.symbol_id;
+    let side = &req.order.side;
+    let quantity = match order_quantity(req) {
+        Ok(q) => q,
+        Err(msg) => return create_error_order(req, &format!("Order rejected: {}", msg)),
+    };
+    // KRW prices are whole won; fractional prices are rejected rather than truncated
+    let mut limit_price = match order_price(req).map(|p| p.to_krw()) {
+        Ok(Some(p)) => p,
+        Ok(None) => {
+            return create_error_order(
+                req,
+                "Order rejected: domestic prices must be whole KRW amounts",
+            )
+        }
+        Err(msg) => return create_error_order(req, &format!("Order rejected: {}", msg)),
+    };
+
+    // Map order type and time-in-force to KIS ORD_DVSN
+    let kis_order_type = match map_domestic_order_type(req) {
+        Ok(t) => t,
+        Err(msg) => {
+            eprintln!("[broker-korea] Order rejected: {}", msg);
+            return create_error_order(req, &format!("Order rejected: {}", msg));
+        }
+    };
+    if kis_order_type.requires_price() && limit_price == 0 {
+        return create_error_order(
+            req,
+            &format!(
+                "Order rejected: limit price is required for ORD_DVSN {}",
+                kis_order_type.code()
+            ),
+        );
+    }
+
+    // Validate against the loaded symbol master (skipped until a master file is loaded)
+    let listed_market = if state.instruments.is_empty() {
+        None
+    } else {
+        match state.instruments.validate_tradable(symbol) {
+            Ok(instrument) => Some(instrument.market),
+            Err(e) => {
+                eprintln!("[broker-korea] Order rejected: {}", e);
+                return create_error_order(req, &format!("Order rejected: {}", e));
+            }
+        }
+    };
+
+    // Check tick size and daily price limits; `round_to_tick` snaps off-tick prices.
+    // Reservations are priced against the next session's base price, so only the tick applies.
+    if kis_order_type.requires_price() {
+        let market = order_extension_str(req, "market")
+            .and_then(KrxMarket::from_code)
+            .or(listed_market);
+        let round = order_extension_bool(req, "round_to_tick");
+        let checked = if order_extension_bool(req, "reservation") {
+            client.domestic_check_tick(symbol, limit_price, market, round)
+        } else {
+            client.domestic_check_price(symbol, limit_price, market, round)
+        };
+        match checked {
+            Ok(price) => limit_price = price,
+            Err(KisError::Validation(msg)) => {
+                eprintln!("[broker-korea] Order rejected: {}", msg);
+                return create_error_order(req, &format!("Order rejected: {}", msg));
+            }
+            Err(e) => {
+                eprintln!("[broker-korea] Price check skipped: {}", e);
+            }
+        }
+    }
+
+    let kis_side = match side {
+        OrderSide::Buy => kis::types::OrderSide::Buy,
+        OrderSide::Sell => kis::types::OrderSide::Sell,
+    };
+
+    // SOR only runs in the main market; NXT pre/after sessions take the order directly
+    let exchange = exchange.resolve_at(kst_now());
+    let funding = order_extension_str(req, "funding").unwrap_or("cash");
+    if exchange != DomesticExchange::Krx
+        && (order_extension_bool(req, "reservation") || funding == "credit")
+    {
+        return create_error_order(
+            req,
+            &format!(
+                "Reservation and credit orders are KRX-only (requested {})",
+                exchange.code()
+            ),
+        );
+    }
+
+    // Queue a reservation order (주식예약주문) for the next open
+    if order_extension_bool(req, "reservation") {
+        let period = match order_extension_str(req, "reservation_end_date") {
+            Some(date) => ReservationPeriod::Until(date.to_string()),
+            None => ReservationPeriod::NextDay,
+        };
+        return match client.domestic_reserve_order(
+            symbol,
+            kis_side,
+            quantity,
+            limit_price,
+            kis_order_type,
+            &period,
+        ) {
+            Ok(reservation) => {
+                let mut extensions = HashMap::new();
+                extensions.insert("reservation".to_string(), serde_json::Value::Bool(true));
+                if let ReservationPeriod::Until(date) = &period {
+                    extensions.insert(
+                        "reservation_end_date".to_string(),
+                        serde_json::Value::String(date.clone()),
+                    );
+                }
+                let reservation_id = reservation.rsvn_ord_seq.map(|seq| format!("rsvn_{}", seq));
+                record_order(state, req, reservation_id, extensions)
+            }
+            Err(e) => {
+                eprintln!("[broker-korea] Reservation order failed: {}", e);
+                create_error_order(req, &format!("Reservation order failed: {}", e))
+            }
+        };
+    }
+
+    // Orders on a KRX holiday would only be rejected by the exchange; use `reservation` instead
+    let now = kst_now();
+    if let Err(e) = client.trading_calendar(now).check_open_day(now.date()) {
+        eprintln!("[broker-korea] Order rejected: {}", e);
+        return create_error_order(req, &format!("Order rejected: {}", e));
+    }
+
+    // Credit (신용) orders: `funding: "credit"` with optional `credit_type` and `loan_date`
+    match funding {
+        "cash" => {}
+        "credit" => {
+            let credit_type = match order_extension_str(req, "credit_type") {
+                Some(code) => match CreditType::from_code(code) {
+                    Some(t) if t.side() == kis_side => t,
+                    Some(_) => {
+                        return create_error_order(
+                            req,
+                            &format!("Credit type {} does not match order side", code),
+                        )
+                    }
+                    None => {
+                        return create_error_order(req, &format!("Unknown credit type: {}", code))
+                    }
+                },
+                None => CreditType::default_for(kis_side),
+            };
+            let loan_date = order_extension_str(req, "loan_date");
+            return match client.domestic_credit_order(
+                credit_type,
+                symbol,
+                quantity,
+                limit_price,
+                kis_order_type,
+                loan_date,
+            ) {
+                Ok(kis_response) => {
+                    let mut extensions = HashMap::new();
+                    extensions.insert(
+                        "funding".to_string(),
+                        serde_json::Value::String("credit".to_string()),
+                    );
+                    extensions.insert(
+                        "credit_type".to_string(),
+                        serde_json::Value::String(credit_type.code().to_string()),
+                    );
+                    if let Some(time) = kis_response.ord_tmd {
+                        extensions.insert(
+                            "kis_order_time".to_string(),
+                            serde_json::Value::String(time),
+                        );
+                    }
+                    record_order(state, req, kis_response.odno, extensions)
+                }
+                Err(e) => {
+                    eprintln!("[broker-korea] Credit order failed: {}", e);
+                    create_failed_order(req, "Credit order failed", &e)
+                }
+            };
+        }
+        other => return create_error_order(req, &format!("Unknown funding type: {}", other)),
+    }
+
+    // Reject up front when 매수가능/매도가능 quantity is insufficient
+    match client.domestic_check_order_quantity(
+        symbol,
+        kis_side,
+        quantity,
+        limit_price,
+        kis_order_type,
+    ) {
+        Ok(()) => {}
+        Err(KisError::Validation(msg)) => {
+            eprintln!("[broker-korea] Order rejected: {}", msg);
+            return create_error_order(req, &format!("Order rejected: {}", msg));
+        }
+        Err(e) => {
+            // The order endpoint still enforces balances; don't block on a failed inquiry
+            eprintln!("[broker-korea] Order quantity check skipped: {}", e);
+        }
+    }
+
+    // Submit order to KIS API
+    let result = client.domestic_order(
+        kis_side,
+        symbol,
+        quantity,
+        limit_price,
+        kis_order_type,
+        exchange,
+    );
+
+    match result {
+        Ok(kis_response) => {
+            let mut extensions = HashMap::new();
+            extensions.insert(
+                "exchange".to_string(),
+                serde_json::Value::String(exchange.code().to_string()),
+            );
+            if let Some(time) = kis_response.ord_tmd {
+                extensions.insert(
+                    "kis_order_time".to_string(),
+                    serde_json::Value::String(time),
+                );
+            }
+            record_order(state, req, kis_response.odno, extensions)
+        }
+        Err(e) => {
+            eprintln!("[broker-korea] Order failed: {}", e);
+            create_failed_order(req, "Order failed", &e)
+        }
+    }
+}
+
+/// Submit an overseas stock order, checking buying power first for buys
+fn submit_overseas_order(
+    state: &mut BrokerState,
+    req: &SubmitOrderRequest,
+    exchange: Exchange,
+) -> Order {
+    let is_paper = state.is_paper;
+    let client = match state.client.as_mut() {
+        Some(c) => c,
+        None => return create_error_order(req, "Plugin not initialized"),
+    };
+
+    let symbol = &req.order.symbol_id;
+    let (quantity, price) = match (order_quantity(req), order_price(req)) {
+        (Ok(q), Ok(p)) => (q, p),
+        (Err(msg), _) | (_, Err(msg)) => {
+            return create_error_order(req, &format!("Order rejected: {}", msg))
+        }
+    };
+    let side = match req.order.side {
+        OrderSide::Buy => kis::types::OrderSide::Buy,
+        OrderSide::Sell => kis::types::OrderSide::Sell,
+    };
+
+    let order_type = match map_overseas_order_type(req) {
+        Ok(t) => t,
+        Err(msg) => {
+            eprintln!("[broker-korea] Order rejected: {}", msg);
+            return create_error_order(req, &format!("Order rejected: {}", msg));
+        }
+    };
+    if let Err(e) = order_type.validate(exchange, side, is_paper) {
+        eprintln!("[broker-korea] Order rejected: {}", e);
+        return create_error_order(req, &format!("Order rejected: {}", e));
+    }
+
+    // Limit prices finer than the exchange quotes are rejected; `round_to_tick` rounds them
+    let round = order_extension_bool(req, "round_to_tick");
+    let price = match order_type.wire_price(exchange, price, round) {
+        Ok(price) => price,
+        Err(e) => {
+            eprintln!("[broker-korea] Order rejected: {}", e);
+            return create_error_order(req, &format!("Order rejected: {}", e));
+        }
+    };
+
+    // Lot size (매매단위) from the overseas symbol master, when loaded
+    let listing = state.overseas_symbols.get(symbol, exchange);
+    if let Some(instrument) = listing {
+        let is_buy = side == kis::types::OrderSide::Buy;
+        if let Err(e) = instrument.validate_quantity(quantity, is_buy) {
+            eprintln!("[broker-korea] Order rejected: {}", e);
+            return create_error_order(req, &format!("Order rejected: {}", e));
+        }
+    }
+    let currency = listing.map(|i| i.currency.clone());
+
+    // Pre-trade check against 매수가능금액 (includes 통합증거금 FX)
+    if side == kis::types::OrderSide::Buy {
+        let check_price = if !price.is_zero() {
+            price
+        } else {
+            client
+                .overseas_price(exchange, symbol)
+                .ok()
+                .and_then(|p| p.last.get())
+                .unwrap_or(Price::ZERO)
+        };
+
+        match client.overseas_check_buying_power(exchange, symbol, quantity, check_price) {
+            Ok(()) => {}
+            Err(KisError::Validation(msg)) => {
+                eprintln!("[broker-korea] Order rejected: {}", msg);
+                return create_error_order(req, &format!("Order rejected: {}", msg));
+            }
+            Err(e) => {
+                // The order endpoint still enforces buying power; don't block on a failed inquiry
+                eprintln!("[broker-korea] Buying power check skipped: {}", e);
+            }
+        }
+    }
+
+    // Book a reservation order (예약주문) for the next open instead of failing
+    let now = kst_now();
+    let next_session = exchange.next_session_after(now);
+    if order_extension_bool(req, "reserve_if_closed") && !exchange.is_open_at(now) {
+        return match client.overseas_reserve_order(
+            exchange,
+            symbol,
+            side,
+            quantity,
+            price,
+            Some(order_type),
+        ) {
+            Ok(reservation) => {
+                let mut extensions = HashMap::new();
+                extensions.insert(
+                    "exchange".to_string(),
+                    serde_json::Value::String(exchange.regular().code().to_string()),
+                );
+                extensions.insert("reservation".to_string(), serde_json::Value::Bool(true));
+                if let Some(date) = reservation.rsvn_ord_rcit_dt {
+                    extensions.insert(
+                        "kis_reservation_date".to_string(),
+                        serde_json::Value::String(date),
+                    );
+                }
+                if let Some(session) = next_session {
+                    extensions.insert(
+                        "next_open_kst".to_string(),
+                        serde_json::Value::String(format_kst(session.open)),
+                    );
+                }
+                record_order(state, req, reservation.ovrs_rsvn_odno, extensions)
+            }
+            Err(e) => {
+                eprintln!("[broker-korea] Reservation order failed: {}", e);
+                create_error_order(req, &format!("Reservation order failed: {}", e))
+            }
+        };
+    }
+
+    // Orders on an exchange holiday would only be rejected by KIS; use `reserve_if_closed` instead
+    let trade_date = exchange.trade_date_at(now);
+    if exchange.is_holiday(trade_date) {
+        let next = next_session
+            .map(|s| format!(", next session opens {} KST", format_kst(s.open)))
+            .unwrap_or_default();
+        return create_error_order(
+            req,
+            &format!(
+                "Order rejected: {} is closed for a holiday on {}{}",
+                exchange.code(),
+                trade_date.format("%Y-%m-%d"),
+                next
+            ),
+        );
+    }
+
+    match client.overseas_order(exchange, symbol, side, quantity, price, Some(order_type)) {
+        Ok(kis_response) => {
+            let mut extensions = HashMap::new();
+            extensions.insert(
+                "exchange".to_string(),
+                serde_json::Value::String(exchange.code().to_string()),
+            );
+            extensions.insert(
+                "kis_ord_dvsn".to_string(),
+                serde_json::Value::String(order_type.code().to_string()),
+            );
+            if let Some(currency) = currency {
+                extensions.insert("currency".to_string(), serde_json::Value::String(currency));
+            }
+            if let Some(time) = kis_response.ord_tmd {
+                extensions.insert(
+                    "kis_order_time".to_string(),
+                    serde_json::Value::String(time),
+                );
+            }
+            record_order(state, req, kis_response.odno, extensions)
+        }
+        Err(e) => {
+            eprintln!("[broker-korea] Order failed: {}", e);
+            create_failed_order(req, "Order failed", &e)
+        }
+    }
+}
+
+/// Market a symbol is routed to
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Venue {
+    Domestic(DomesticExchange),
+    Overseas(Exchange),
+}
+
+/// Resolve the venue from an explicit exchange code or the loaded symbol masters
+///
+/// 거래소를 지정하지 않으면 국내 단축코드 형태(숫자로 시작하는 6자리)와 국내 종목 테이블에 있는
+/// 종목은 KRX로 보내고(상해/심천 종목은 거래소 지정 필요), 그 밖에는 해외 종목 테이블에서 거래소를
+/// 찾습니다. 어느 쪽에서도 찾지 못한 티커는 API 호출 없이 거부합니다. 해외 거래소를 지정했는데 해당
+/// 거래소 마스터에 없는 티커도 거부합니다.
+fn resolve_venue(
+    state: &BrokerState,
+    symbol: &str,
+    exchange: Option<&str>,
+) -> Result<Venue, String> {
+    let code = match exchange {
+        Some(code) => code,
+        None => {
+            if is_domestic_code(symbol) || state.instruments.get(symbol).is_some() {
+                return Ok(Venue::Domestic(DomesticExchange::Krx));
+            }
+            if state.overseas_symbols.listings(symbol).is_empty() {
+                return Err(format!(
+                    "Cannot resolve the exchange for {}: set extensions.exchange or load the overseas symbol master",
+                    symbol
+                ));
+            }
+            return state
+                .overseas_symbols
+                .resolve(symbol)
+                .map(|instrument| Venue::Overseas(instrument.exchange))
+                .map_err(|e| e.to_string());
+        }
+    };
+
+    match (DomesticExchange::from_code(code), Exchange::from_code(code)) {
+        (Some(venue), _) => Ok(Venue::Domestic(venue)),
+        (None, Some(exchange)) => state
+            .overseas_symbols
+            .check_exchange(symbol, exchange)
+            .map(|_| Venue::Overseas(exchange))
+            .map_err(|e| e.to_string()),
+        (None, None) => Err(format!("Unknown exchange code: {}", code)),
+    }
+}
+
+/// Run the pre-trade risk checks for an order
+///
+/// 현재가와 보유수량은 해당 한도가 설정된 경우에만 조회합니다 (조회 캐시 사용). 수량/가격 형식
+/// 오류는 주문 경로에서 구체적인 메시지로 거부하므로 여기서는 통과시킵니다.
+fn check_risk(
+    state: &mut BrokerState,
+    req: &SubmitOrderRequest,
+    venue: Venue,
+) -> Result<(), RiskViolation> {
+    let (quantity, limit_price) = match (order_quantity(req), order_price(req)) {
+        (Ok(q), Ok(p)) => (
+            Quantity::from_int(i64::from(q)),
+            Some(p).filter(|p| !p.is_zero()),
+        ),
+        _ => return Ok(()),
+    };
+    let side = match req.order.side {
+        OrderSide::Buy => kis::types::OrderSide::Buy,
+        OrderSide::Sell => kis::types::OrderSide::Sell,
+    };
+    let symbol = req.order.symbol_id.as_str();
+    let engine = &state.risk;
+    let client = match state.client.as_mut() {
+        Some(c) => c,
+        None => return Ok(()),
+    };
+
+    let last_price = if engine.needs_last_price() {
+        last_trade_price(client, venue, symbol)
+    } else {
+        None
+    };
+    let position = if !engine.needs_position(side) {
+        None
+    } else {
+        match venue {
+            // 보유수량을 파싱하지 못한 행이 있으면 포지션을 알 수 없는 것으로 처리
+            Venue::Domestic(_) => client.domestic_balance().ok().and_then(|(items, _)| {
+                items
+                    .iter()
+                    .filter(|item| item.pdno.as_deref() == Some(symbol))
+                    .try_fold(Quantity::ZERO, |total, item| {
+                        log_invalid_fields(symbol, item);
+                        held_quantity(&item.hldg_qty).map(|q| total + q)
+                    })
+            }),
+            Venue::Overseas(_) => client.overseas_balance().ok().and_then(|items| {
+                items
+                    .iter()
+                    .filter(|item| item.ovrs_pdno.as_deref() == Some(symbol))
+                    .try_fold(Quantity::ZERO, |total, item| {
+                        log_invalid_fields(symbol, item);
+                        held_quantity(&item.ovrs_cblc_qty).map(|q| total + q)
+                    })
+            }),
+        }
+    };
+
+    let currency = venue_currency(venue);
+    engine.check(
+        &OrderCheck {
+            symbol,
+            side,
+            quantity,
+            limit_price,
+            currency,
+            last_price,
+            position,
+        },
+        kst_now().date(),
+    )
+}
+
+/// Apply the live-session warm-up cap
+///
+/// # Returns
+/// * 접수 시 인터록에 기록할 (통화, 주문금액) (모의투자는 None)
+fn check_interlock(
+    state: &mut BrokerState,
+    req: &SubmitOrderRequest,
+    venue: Venue,
+) -> Result<Option<(&'static str, Option<Amount>)>, RiskViolation> {
+    let Some(interlock) = state.interlock.as_ref() else {
+        return Ok(None);
+    };
+    let currency = venue_currency(venue);
+    if !interlock.in_warmup() {
+        return Ok(Some((currency, None)));
+    }
+
+    // 시장가 주문은 현재가로 금액을 산출
+    let notional = order_quantity(req).ok().and_then(|quantity| {
+        let price = order_price(req).ok().filter(|p| !p.is_zero()).or_else(|| {
+            let client = state.client.as_mut()?;
+            last_trade_price(client, venue, &req.order.symbol_id)
+        })?;
+        Some(price * Quantity::from_int(i64::from(quantity)))
+    });
+    interlock.check(currency, notional)?;
+    Ok(Some((currency, notional)))
+}
+
+/// Last trade price of a symbol on the order's venue
+fn last_trade_price(client: &mut KisClient, venue: Venue, symbol: &str) -> Option<Price> {
+    match venue {
+        Venue::Domestic(_) => client
+            .domestic_current_price(symbol)
+            .ok()
+            .and_then(|p| p.stck_prpr.get()),
+        Venue::Overseas(exchange) => client
+            .overseas_price(exchange, symbol)
+            .ok()
+            .and_then(|p| p.last.get()),
+    }
+}
+
+/// Held quantity of a balance row (blank = 0, unparsable = unknown)
+fn held_quantity(field: &kis::field::KisField<Quantity>) -> Option<Quantity> {
+    if field.is_invalid() {
+        None
+    } else {
+        Some(field.or_zero())
+    }
+}
+
+fn venue_currency(venue: Venue) -> &'static str {
+    match venue {
+        Venue::Domestic(_) => "KRW",
+        Venue::Overseas(exchange) => exchange_currency(exchange),
+    }
+}
+
+/// Reject risk-increasing (매수) orders once a daily loss limit is hit
+///
+/// 계좌 손익은 국내주식과 해외주식의 실현/평가손익을 합산하며, 한도에 도달하면 국내/해외 매수를 모두
+/// 차단합니다. 페르소나 손익은 국내 체결만으로 계산합니다.
+fn check_daily_loss(
+    state: &mut BrokerState,
+    req: &SubmitOrderRequest,
+) -> Result<(), RiskViolation> {
+    let side = match req.order.side {
+        OrderSide::Buy => kis::types::OrderSide::Buy,
+        OrderSide::Sell => kis::types::OrderSide::Sell,
+    };
+    let persona = req.order.persona_id.as_deref();
+    if !state.risk.needs_pnl(side, persona) {
+        return Ok(());
+    }
+
+    let today = kst_now().date();
+    let (account, personas) = match daily_pnl(state, today) {
+        Ok((account, personas)) => (Some(account), Some(personas)),
+        Err(e) => {
+            eprintln!("[broker-korea] Failed to compute daily P&L: {}", e);
+            (None, None)
+        }
+    };
+    // 당일 체결이 없는 페르소나의 손익은 0
+    let persona = persona.map(|p| {
+        let pnl = personas
+            .as_ref()
+            .and_then(|all| all.get(p).copied().unwrap_or(Some(Amount::ZERO)));
+        (p, pnl)
+    });
+    state
+        .risk
+        .check_loss(today, side, account.as_ref(), persona)
+}
+
+/// Today's account and persona P&L from the domestic balance and executions
+/// plus the overseas present balance and executions
+fn daily_pnl(
+    state: &mut BrokerState,
+    today: chrono::NaiveDate,
+) -> Result<(DailyPnl, HashMap<String, Option<Amount>>), String> {
+    let client = state.client.as_mut().ok_or("Plugin not initialized")?;
+    let (items, _) = client.domestic_balance().map_err(|e| e.to_string())?;
+    let executions = client
+        .domestic_executions(today)
+        .map_err(|e| e.to_string())?;
+
+    let fills: Vec<Fill> = executions
+        .iter()
+        .filter_map(|item| {
+            Some(Fill {
+                order_no: normalize_order_no(item.odno.as_deref()?),
+                symbol: item.pdno.clone()?,
+                side: item.side()?,
+                quantity: item.tot_ccld_qty.get()?,
+                price: item.avg_prvs.get()?,
+            })
+        })
+        .collect();
+    let mut holdings: Vec<Holding> = items
+        .iter()
+        .filter_map(|item| {
+            Some(Holding {
+                symbol: item.pdno.clone()?,
+                quantity: item.hldg_qty.or_zero(),
+                prev_close: item.prev_close()?,
+                last: item.prpr.get()?,
+            })
+        })
+        .collect();
+    // 당일 전량 매도로 잔고에서 빠진 종목은 현재가 조회로 전일종가를 구함
+    for fill in &fills {
+        if holdings.iter().any(|h| h.symbol == fill.symbol) {
+            continue;
+        }
+        let quote = match client.domestic_current_price(&fill.symbol) {
+            Ok(quote) => quote,
+            Err(e) => {
+                eprintln!(
+                    "[broker-korea] Failed to fetch previous close of {}: {}",
+                    fill.symbol, e
+                );
+                continue;
+            }
+        };
+        log_invalid_fields(&fill.symbol, &quote);
+        if let (Some(last), Some(change)) = (quote.stck_prpr.get(), quote.prdy_vrss.get()) {
+            holdings.push(Holding {
+                symbol: fill.symbol.clone(),
+                quantity: Quantity::ZERO,
+                prev_close: last - change,
+                last,
+            });
+        }
+    }
+    let (overseas, overseas_fills) = overseas_positions(client, kst_now())?;
+    let account = state
+        .pnl
+        .update(today, &holdings, &fills, &overseas, &overseas_fills)
+        .map_err(|e| e.to_string())?;
+
+    // Orders placed through this plugin carry their persona; marks are the current prices
+    let owners: HashMap<String, String> = state
+        .orders
+        .iter()
+        .filter_map(|(id, order)| Some((normalize_order_no(id), order.persona_id.clone()?)))
+        .collect();
+    let marks: HashMap<String, Price> = holdings
+        .iter()
+        .filter(|h| !h.last.is_zero())
+        .map(|h| (h.symbol.clone(), h.last))
+        .collect();
+
+    Ok((account, persona_pnl(&fills, &owners, &marks)))
+}
+
+/// Overseas holdings and today's fills (foreign currency) with their KRW exchange rates
+///
+/// 전일종가는 현재가 시세의 `base`, 체결은 거래소별 현지 거래일의 주문체결내역에서 가져옵니다. 당일
+/// 전량 매도로 잔고에서 빠진 종목은 수량 0으로 추가합니다. 보유 또는 체결 종목의 시세, 환율, 체결
+/// 내역을 읽지 못하면 손익을 산출할 수 없으므로 오류를 반환합니다.
+fn overseas_positions(
+    client: &mut KisClient,
+    now: chrono::NaiveDateTime,
+) -> Result<(Vec<OverseasHolding>, Vec<Fill>), String> {
+    let balance = client
+        .overseas_present_balance()
+        .map_err(|e| e.to_string())?;
+    let rates: HashMap<&str, Price> = balance
+        .currencies
+        .iter()
+        .filter_map(|c| Some((c.crcy_cd.as_deref()?, c.frst_bltn_exrt.get()?)))
+        .filter(|(_, rate)| !rate.is_zero())
+        .collect();
+
+    let fills = overseas_fills(client, now)?;
+    let mut holdings = Vec::new();
+    for item in &balance.items {
+        let Some(symbol) = item.pdno.as_deref() else {
+            continue;
+        };
+        log_invalid_fields(symbol, item);
+        let quantity = held_quantity(&item.cblc_qty13)
+            .ok_or_else(|| format!("Unreadable overseas quantity of {}", symbol))?;
+        let exchange = item
+            .ovrs_excg_cd
+            .as_deref()
+            .and_then(Exchange::from_code)
+            .ok_or_else(|| format!("Unknown overseas exchange of {}", symbol))?
+            .regular();
+        let key = format!("{}:{}", exchange.code(), symbol);
+        if quantity.is_zero() && !fills.iter().any(|f| f.symbol == key) {
+            continue;
+        }
+        let exchange_rate = item.bass_exrt.get().filter(|r| !r.is_zero()).or_else(|| {
+            rates
+                .get(item.buy_crcy_cd.as_deref().unwrap_or_default())
+                .copied()
+        });
+        let (Some(last), Some(exchange_rate)) = (item.ovrs_now_pric1.get(), exchange_rate) else {
+            return Err(format!("No overseas price or exchange rate for {}", symbol));
+        };
+        let (prev_close, _) = overseas_closes(client, exchange, symbol)?;
+        holdings.push(OverseasHolding {
+            symbol: key,
+            quantity,
+            prev_close,
+            last,
+            exchange_rate,
+        });
+    }
+
+    // 당일 전량 매도로 잔고에서 빠진 종목은 시세와 통화별 환율로 채움
+    for fill in &fills {
+        if holdings.iter().any(|h| h.symbol == fill.symbol) {
+            continue;
+        }
+        let (code, symbol) = fill.symbol.split_once(':').unwrap_or_default();
+        let exchange = Exchange::from_code(code)
+            .ok_or_else(|| format!("Unknown overseas exchange of {}", fill.symbol))?;
+        let exchange_rate = rates
+            .get(exchange_currency(exchange))
+            .copied()
+            .ok_or_else(|| format!("No exchange rate for {}", fill.symbol))?;
+        let (prev_close, last) = overseas_closes(client, exchange, symbol)?;
+        holdings.push(OverseasHolding {
+            symbol: fill.symbol.clone(),
+            quantity: Quantity::ZERO,
+            prev_close,
+            last,
+            exchange_rate,
+        });
+    }
+    Ok((holdings, fills))
+}
+
+/// (전일종가, 현재가) of an overseas symbol from its quote
+fn overseas_closes(
+    client: &mut KisClient,
+    exchange: Exchange,
+    symbol: &str,
+) -> Result<(Price, Price), String> {
+    let quote = client
+        .overseas_price(exchange, symbol)
+        .map_err(|e| format!("Failed to fetch quote of {}: {}", symbol, e))?;
+    log_invalid_fields(symbol, &quote);
+    match (quote.base.get().filter(|p| !p.is_zero()), quote.last.get()) {
+        (Some(prev_close), Some(last)) => Ok((prev_close, last)),
+        _ => Err(format!(
+            "No previous close for {}:{}",
+            exchange.code(),
+            symbol
+        )),
+    }
+}
+
+/// Overseas executions of each exchange's current local trade date
+///
+/// 주문체결내역의 주문일자는 현지 일자이므로 거래소별 현지 거래일로 조회해 전일종가 이후의 체결만
+/// 남깁니다.
+fn overseas_fills(client: &mut KisClient, now: chrono::NaiveDateTime) -> Result<Vec<Fill>, String> {
+    let mut dates: Vec<chrono::NaiveDate> = ACCOUNT_EXCHANGES
+        .iter()
+        .map(|e| e.trade_date_at(now))
+        .collect();
+    dates.sort();
+    dates.dedup();
+
+    let mut fills = Vec::new();
+    for date in dates {
+        let date = date.format("%Y%m%d").to_string();
+        let items = client
+            .overseas_orders_on(&date)
+            .map_err(|e| format!("Failed to fetch overseas executions: {}", e))?;
+        for item in items {
+            let Some(exchange) = item
+                .ovrs_excg_cd
+                .as_deref()
+                .and_then(Exchange::from_code)
+                .map(|e| e.regular())
+            else {
+                continue;
+            };
+            let local_date = exchange.trade_date_at(now).format("%Y%m%d").to_string();
+            if item.ord_dt.as_deref() != Some(local_date.as_str()) {
+                continue;
+            }
+            let order_no = item.odno.as_deref().unwrap_or_default();
+            log_invalid_fields(order_no, &item);
+            let quantity = held_quantity(&item.ft_ccld_qty)
+                .ok_or_else(|| format!("Unreadable executed quantity of {}", order_no))?;
+            if quantity.is_zero() {
+                continue;
+            }
+            let (Some(symbol), Some(side), Some(price)) =
+                (item.pdno.as_deref(), item.side(), item.ft_ccld_unpr3.get())
+            else {
+                return Err(format!("Unreadable overseas execution {}", order_no));
+            };
+            fills.push(Fill {
+                order_no: normalize_order_no(order_no),
+                symbol: format!("{}:{}", exchange.code(), symbol),
+                side,
+                quantity,
+                price,
+            });
+        }
+    }
+    Ok(fills)
+}
+
+/// KIS order numbers are zero-padded inconsistently across endpoints
+fn normalize_order_no(order_no: &str) -> String {
+    order_no.trim_start_matches('0').to_string()
+}
+
+/// Pick the US trading session for an overseas order
+///
+/// The `session` extension may force "regular" or "daytime"; otherwise live
+/// orders for US exchanges go to the daytime session (주간거래) during KST
+/// daytime hours. Explicit daytime codes (BAQ/BAY/BAA) are kept as-is.
+fn resolve_us_session(
+    req: &SubmitOrderRequest,
+    exchange: Exchange,
+    is_paper: bool,
+) -> Result<Exchange, String> {
+    match order_extension_str(req, "session") {
+        Some("regular") => Ok(exchange.regular()),
+        Some("daytime") => exchange
+            .daytime()
+            .ok_or_else(|| format!("{} has no daytime session", exchange.code())),
+        Some("auto") | None => {
+            if exchange.is_daytime() || is_paper {
+                Ok(exchange)
+            } else {
+                Ok(exchange.for_session_at(kst_now()))
+            }
+        }
+        Some(other) => Err(format!("Unknown session: {}", other)),
+    }
+}
+
+/// Convert a KIS balance row to a plugin position
+///
+/// 숫자 필드 일부가 비어 있거나 파싱에 실패해도 종목코드와 보유수량이 있으면 포지션을 유지합니다.
+fn to_position(item: &BalanceItem) -> Option<Position> {
+    let symbol = item.pdno.as_ref()?.clone();
+    log_invalid_fields(&symbol, item);
+    let quantity = item.hldg_qty.get()?;
+
+    Some(Position {
+        symbol_id: symbol,
+        quantity: quantity.to_f64(),
+        average_price: item.pchs_avg_pric.or_zero().to_f64(),
+        current_price: item.prpr.or_zero().to_f64(),
+        unrealized_pnl: item.evlu_pfls_amt.or_zero().to_f64(),
+        unrealized_pnl_percent: item.evlu_pfls_rt.or_zero().to_f64(),
+    })
+}
+
+/// Log fields that failed to parse (lenient mode keeps the row)
+fn log_invalid_fields(context: &str, row: &impl FieldReport) {
+    for (field, raw) in row.invalid_fields() {
+        eprintln!(
+            "[broker-korea] {}: could not parse {} = {:?}",
+            context, field, raw
+        );
+    }
+}
+
+/// Order quantity as a whole number of shares
+fn order_quantity(req: &SubmitOrderRequest) -> Result<u32, String> {
+    Quantity::from_f64(req.order.quantity)
+        .and_then(|q| q.to_order_qty())
+        .filter(|&q| q > 0)
+        .ok_or_else(|| format!("Invalid order quantity: {}", req.order.quantity))
+}
+
+/// Limit price as a fixed-point value (0 when absent)
+fn order_price(req: &SubmitOrderRequest) -> Result<Price, String> {
+    let value = req.order.limit_price.unwrap_or(0.0);
+    Price::from_f64(value)
+        .filter(|p| !p.is_negative())
+        .ok_or_else(|| format!("Invalid limit price: {}", value))
+}
+
+/// Map the plugin order type (plus `ord_dvsn` / `time_in_force` extensions) to a domestic ORD_DVSN
+///
+/// `MarketOnClose` becomes 장후시간외 종가(06) and `LimitOnClose` becomes 시간외단일가(07).
+fn map_domestic_order_type(req: &SubmitOrderRequest) -> Result<kis::types::OrderType, String> {
+    let base = match order_extension_str(req, "ord_dvsn") {
+        Some(code) => kis::types::OrderType::from_code(code)
+            .ok_or_else(|| format!("Unknown domestic ORD_DVSN code: {}", code))?,
+        None => match &req.order.order_type {
+            OrderType::Market => kis::types::OrderType::Market,
+            OrderType::Limit => kis::types::OrderType::Limit,
+            OrderType::MarketOnClose => kis::types::OrderType::AfterMarket,
+            OrderType::LimitOnClose => kis::types::OrderType::AfterHoursSinglePrice,
+            other => {
+                return Err(format!(
+                    "Order type {:?} is not supported for domestic stocks",
+                    other
+                ))
+            }
+        },
+    };
+
+    let tif = match order_extension_str(req, "time_in_force") {
+        Some(code) => TimeInForce::from_code(code)
+            .ok_or_else(|| format!("Unknown time_in_force: {}", code))?,
+        None => TimeInForce::Day,
+    };
+
+    base.with_time_in_force(tif).map_err(|e| e.to_string())
+}
+
+/// Map the plugin order type to a KIS overseas ORD_DVSN
+///
+/// An explicit `ord_dvsn` extension (e.g. "31" for MOO) takes precedence.
+fn map_overseas_order_type(req: &SubmitOrderRequest) -> Result<OverseasOrderType, String> {
+    overseas_order_type(&req.order.order_type, order_extension_str(req, "ord_dvsn"))
+}
+
+/// ORD_DVSN for a plugin order type (거래소/매수매도 허용 여부는 `OverseasOrderType::validate`)
+///
+/// `MarketOnClose`는 MOC(33), `LimitOnClose`는 LOC(34)로 보냅니다. MOO(31)/LOO(32)는 대응하는
+/// 주문유형이 없어 `ord_dvsn`으로 지정합니다.
+fn overseas_order_type(
+    order_type: &OrderType,
+    ord_dvsn: Option<&str>,
+) -> Result<OverseasOrderType, String> {
+    if let Some(code) = ord_dvsn {
+        return OverseasOrderType::from_code(code)
+            .ok_or_else(|| format!("Unknown overseas ORD_DVSN code: {}", code));
+    }
+
+    match order_type {
+        OrderType::Limit => Ok(OverseasOrderType::Limit),
+        OrderType::MarketOnClose => Ok(OverseasOrderType::MarketOnClose),
+        OrderType::LimitOnClose => Ok(OverseasOrderType::LimitOnClose),
+        OrderType::Market => Err(
+            "Market orders are not supported for overseas stocks; use a limit order".to_string(),
+        ),
+        other => Err(format!(
+            "Unsupported order type for overseas stocks: {:?}",
+            other
+        )),
+    }
+}
+
+/// Store an accepted order in local state and return it
+fn record_order(
+    state: &mut BrokerState,
+    req: &SubmitOrderRequest,
+    kis_order_no: Option<String>,
+    extensions: HashMap<String, serde_json::Value>,
+) -> Order {
+    // Use the KIS order number, falling back to a local sequence
+    let order_id = kis_order_no.unwrap_or_else(|| format!("kr_{}", state.next_order_id));
+    state.next_order_id += 1;
+
+    let order = Order {
+        id: order_id.clone(),
+        request: req.order.clone(),
+        status: OrderStatus::Submitted,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        average_filled_price: None,
+        filled_quantity: 0.0,
+        extensions: Some(extensions),
+        persona_id: req.order.persona_id.clone(),
+    };
+
+    state.orders.insert(order_id, order.clone());
+    order
+}
+
+// --- Helper Functions ---
+
+/// Format a KST timestamp for host-facing fields
+fn format_kst(kst: chrono::NaiveDateTime) -> String {
+    kst.format("%Y-%m-%dT%H:%M:%S+09:00").to_string()
+}
+
+/// Read a string-valued extension from the order request
+fn order_extension_str<'a>(req: &'a SubmitOrderRequest, key: &str) -> Option<&'a str> {
+    req.order.extensions.as_ref()?.get(key)?.as_str()
+}
+
+/// Read a boolean extension from the order request (missing = false)
+fn order_extension_bool(req: &SubmitOrderRequest, key: &str) -> bool {
+    req.order
+        .extensions
+        .as_ref()
+        .and_then(|ext| ext.get(key))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+fn parse_request<T: serde::de::DeserializeOwned>(ptr: i32, len: i32) -> T {
+    let slice = unsafe { slice::from_raw_parts(ptr as *const u8, len as usize) };
+    serde_json::from_slice(slice).expect("Failed to parse request")
+}
+
+/// Optional freshness hint carried alongside read requests
+#[derive(Debug, Default, serde::Deserialize)]
+struct FreshnessHint {
+    /// 허용 가능한 캐시 데이터 나이 (ms, 0 = 새로 조회)
+    #[serde(default)]
+    max_age_ms: Option<u64>,
+    /// true면 캐시를 무시하고 새로 조회
+    #[serde(default)]
+    refresh: bool,
+}
+
+fn parse_freshness(ptr: i32, len: i32) -> Freshness {
+    let slice = unsafe { slice::from_raw_parts(ptr as *const u8, len as usize) };
+    serde_json::from_slice::<FreshnessHint>(slice)
+        .map(|hint| Freshness::from_hint(hint.max_age_ms, hint.refresh))
+        .unwrap_or_default()
+}
+
+fn serialize_response<T: serde::Serialize>(response: &T) -> u64 {
+    let res_bytes = serde_json::to_vec(response).expect("Failed to serialize response");
+
+    let out_len = res_bytes.len() as i32;
+    let out_ptr = alloc(out_len);
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(res_bytes.as_ptr(), out_ptr as *mut u8, out_len as usize);
+    }
+
+    ((out_ptr as u64) << 32) | (out_len as u64)
+}
+
+fn create_error_account(error: &str) -> AccountSummary {
+    AccountSummary {
+        id: "error".to_string(),
+        name: format!("Error: {}", error),
+        broker_id: "broker-korea".to_string(),
+        is_paper: true,
+        balance: AccountBalance {
+            currency: "KRW".to_string(),
+            total_equity: 0.0,
+            available_cash: 0.0,
+            buying_power: 0.0,
+            locked_cash: 0.0,
+        },
+        positions: vec![],
+        updated_at: Utc::now(),
+        extensions: None,
+    }
+}
+
+/// Tag an order with its trading environment ("paper" / "live")
+fn tag_environment(state: &mut BrokerState, order: &mut Order) {
+    let environment = if state.is_paper { "paper" } else { "live" };
+    order.extensions.get_or_insert_with(HashMap::new).insert(
+        "environment".to_string(),
+        serde_json::Value::String(environment.to_string()),
+    );
+    if let Some(stored) = state.orders.get_mut(&order.id) {
+        *stored = order.clone();
+    }
+}
+
+/// Rejected order carrying the breached risk rule in its extensions
+fn create_risk_rejection(req: &SubmitOrderRequest, violation: &RiskViolation) -> Order {
+    let mut order = create_error_order(req, &format!("Order rejected: {}", violation));
+    if let Some(extensions) = order.extensions.as_mut() {
+        let fields = [
+            ("risk_rule", Some(violation.rule.code().to_string())),
+            ("risk_limit", violation.limit.clone()),
+            ("risk_value", violation.actual.clone()),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value {
+                extensions.insert(key.to_string(), serde_json::Value::String(value));
+            }
+        }
+    }
+    order
+}
+
+/// Rejected order for a failed order call, flagged `ambiguous` when KIS may have accepted it
+fn create_failed_order(req: &SubmitOrderRequest, context: &str, error: &KisError) -> Order {
+    let mut order = create_error_order(req, &format!("{}: {}", context, error));
+    if error.is_ambiguous() {
+        if let Some(extensions) = order.extensions.as_mut() {
+            extensions.insert("ambiguous".to_string(), serde_json::Value::Bool(true));
+        }
+    }
+    order
+}
+
+fn create_error_order(req: &SubmitOrderRequest, error: &str) -> Order {
+    Order {
+        id: format!("error_{}", Utc::now().timestamp_millis()),
+        request: req.order.clone(),
+        status: OrderStatus::Rejected,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        average_filled_price: None,
+        filled_quantity: 0.0,
+        extensions: Some({
+            let mut map = HashMap::new();
+            map.insert(
+                "error".to_string(),
+                serde_json::Value::String(error.to_string()),
+            );
+            map
+        }),
+        persona_id: req.order.persona_id.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kis::types::OrderSide as KisSide;
+
+    #[test]
+    fn test_overseas_order_type_mapping() {
+        let loc = overseas_order_type(&OrderType::LimitOnClose, None).unwrap();
+        assert_eq!(loc.code(), "34");
+        // LOC는 미국 정규장 매수/매도 모두 허용, 주간거래와 아시아 거래소는 불가
+        assert!(loc.validate(Exchange::NASDAQ, KisSide::Buy, false).is_ok());
+        assert!(loc.validate(Exchange::NYSE, KisSide::Sell, false).is_ok());
+        assert!(loc.validate(Exchange::BAQ, KisSide::Buy, false).is_err());
+        assert!(loc.validate(Exchange::TKSE, KisSide::Buy, false).is_err());
+        assert!(loc.validate(Exchange::NASDAQ, KisSide::Buy, true).is_err());
+
+        // MOC는 미국 매도만
+        let moc = overseas_order_type(&OrderType::MarketOnClose, None).unwrap();
+        assert_eq!(moc.code(), "33");
+        assert!(moc.validate(Exchange::AMEX, KisSide::Sell, false).is_ok());
+        assert!(moc.validate(Exchange::AMEX, KisSide::Buy, false).is_err());
+
+        assert_eq!(
+            overseas_order_type(&OrderType::Limit, None).unwrap().code(),
+            "00"
+        );
+        assert!(overseas_order_type(&OrderType::Market, None).is_err());
+
+        // ord_dvsn 확장이 주문유형보다 우선 (MOO는 매도만)
+        let moo = overseas_order_type(&OrderType::Limit, Some("31")).unwrap();
+        assert_eq!(moo, OverseasOrderType::MarketOnOpen);
+        assert!(moo.validate(Exchange::NASDAQ, KisSide::Buy, false).is_err());
+        assert!(moo.validate(Exchange::NASDAQ, KisSide::Sell, false).is_ok());
+        assert!(overseas_order_type(&OrderType::Limit, Some("99")).is_err());
     }
 }